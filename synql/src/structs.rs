@@ -15,6 +15,34 @@ pub use external_function_ref::ExternalFunctionRef;
 mod external_function;
 pub use external_function::ExternalFunction;
 mod errors;
-pub use errors::Error;
+pub use errors::{CheckConstraintErrorKind, Error};
 mod toml_dependency;
 pub use toml_dependency::TomlDependency;
+mod generated_workspace;
+pub use generated_workspace::GeneratedWorkspace;
+mod generation_report;
+pub use generation_report::{FileStatistics, GenerationReport};
+mod generation_warning;
+pub use generation_warning::GenerationWarning;
+mod table_selector;
+pub use table_selector::TableSelector;
+mod schema_naming;
+pub use schema_naming::SchemaNaming;
+mod temporal_backend;
+pub use temporal_backend::TemporalBackend;
+mod default_naming_strategy;
+pub use default_naming_strategy::DefaultNamingStrategy;
+mod naming_overrides;
+pub use naming_overrides::NamingOverrides;
+mod directives;
+pub use directives::Directives;
+mod postgres_type;
+pub use postgres_type::PostgresType;
+mod postgres_enum;
+pub use postgres_enum::PostgresEnum;
+mod postgres_domain;
+pub use postgres_domain::PostgresDomain;
+mod postgres_composite;
+pub use postgres_composite::PostgresComposite;
+mod user_defined_types;
+pub use user_defined_types::UserDefinedTypes;
@@ -0,0 +1,11 @@
+//! Submodule defining the `DefaultNamingStrategy` struct, which derives the
+//! names of the generated code with the default SynQL naming.
+
+use crate::traits::NamingStrategy;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+/// Naming strategy deriving snake-cased crate and field names and singular
+/// camel-cased struct names from the SQL names.
+pub struct DefaultNamingStrategy;
+
+impl NamingStrategy for DefaultNamingStrategy {}
@@ -0,0 +1,177 @@
+//! Submodule defining the `Directives` struct, describing the `@synql(...)`
+//! annotations found in the comments of the tables and columns.
+
+use quote::ToTokens;
+use syn::LitStr;
+
+/// Marker opening a directive annotation in a comment.
+const DIRECTIVE_MARKER: &str = "@synql(";
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+/// Generation directives declared with `@synql(...)` annotations in the
+/// comment of a table or column, such as:
+///
+/// ```sql
+/// COMMENT ON TABLE data IS 'Measured data points. @synql(rename = "Datum", derive(Hash))';
+/// COMMENT ON COLUMN users.password IS 'Hashed password. @synql(sensitive)';
+/// ```
+///
+/// The following directives are supported:
+///
+/// * `rename = "..."` - The name of the struct of a table, or of the field of a
+///   column.
+/// * `type = "..."` - The Rust type of a column, which must implement the
+///   diesel traits of the SQL type of the column alongside the same traits as
+///   the type it replaces.
+/// * `skip` - The table, alongside the tables depending on it, or the column is
+///   not generated.
+/// * `derive(...)` - Additional derives of the struct of a table.
+/// * `sensitive` - The field of a column is redacted from the `Debug`
+///   implementation of the struct and is not serialized, deserializing to its
+///   default value instead, so that the column must be nullable or of a type
+///   implementing `Default`.
+///
+/// The annotations are stripped from the documentation of the generated code.
+pub struct Directives {
+    /// The name of the generated struct or field.
+    rename: Option<String>,
+    /// The Rust type of the column.
+    rust_type: Option<String>,
+    /// Whether the table or column is not generated.
+    skip: bool,
+    /// Additional derives of the struct of the table.
+    derives: Vec<String>,
+    /// Whether the column holds sensitive data.
+    sensitive: bool,
+}
+
+impl Directives {
+    /// Parses the directives of the provided comment, returning them alongside
+    /// the comment stripped of the annotations.
+    ///
+    /// # Arguments
+    ///
+    /// * `comment` - The comment of the table or column.
+    ///
+    /// # Errors
+    ///
+    /// Returns a description of the error if an annotation is malformed or
+    /// contains an unknown directive.
+    pub(crate) fn parse(comment: &str) -> Result<(Self, String), String> {
+        let mut directives = Self::default();
+        let mut documentation = String::new();
+        let mut rest = comment;
+        while let Some(start) = rest.find(DIRECTIVE_MARKER) {
+            documentation.push_str(&rest[..start]);
+            let arguments = &rest[start + DIRECTIVE_MARKER.len()..];
+            let end = closing_parenthesis(arguments)
+                .ok_or_else(|| format!("the `{DIRECTIVE_MARKER}` annotation is not closed"))?;
+            directives.parse_arguments(&arguments[..end])?;
+            rest = &arguments[end + 1..];
+        }
+        documentation.push_str(rest);
+        Ok((directives, documentation.trim().to_owned()))
+    }
+
+    /// Parses the comma-separated arguments of an annotation.
+    fn parse_arguments(&mut self, arguments: &str) -> Result<(), String> {
+        let parser = syn::meta::parser(|meta| {
+            if meta.path.is_ident("rename") {
+                self.rename = Some(meta.value()?.parse::<LitStr>()?.value());
+            } else if meta.path.is_ident("type") {
+                let rust_type = meta.value()?.parse::<LitStr>()?;
+                syn::parse_str::<syn::Type>(&rust_type.value())
+                    .map_err(|error| meta.error(format!("invalid type: {error}")))?;
+                self.rust_type = Some(rust_type.value());
+            } else if meta.path.is_ident("skip") {
+                self.skip = true;
+            } else if meta.path.is_ident("sensitive") {
+                self.sensitive = true;
+            } else if meta.path.is_ident("derive") {
+                meta.parse_nested_meta(|derive| {
+                    self.derives.push(derive.path.to_token_stream().to_string().replace(' ', ""));
+                    Ok(())
+                })?;
+            } else {
+                return Err(meta.error(format!(
+                    "unknown directive `{}`",
+                    meta.path.to_token_stream().to_string().replace(' ', "")
+                )));
+            }
+            Ok(())
+        });
+        syn::parse::Parser::parse_str(parser, arguments).map_err(|error| error.to_string())
+    }
+
+    /// Returns the name of the generated struct or field, if renamed.
+    #[must_use]
+    pub fn rename(&self) -> Option<&str> {
+        self.rename.as_deref()
+    }
+
+    /// Returns the Rust type of the column, if overridden.
+    #[must_use]
+    pub fn rust_type(&self) -> Option<syn::Type> {
+        self.rust_type.as_deref().and_then(|rust_type| syn::parse_str(rust_type).ok())
+    }
+
+    /// Returns whether the table or column is not generated.
+    #[must_use]
+    pub fn is_skipped(&self) -> bool {
+        self.skip
+    }
+
+    /// Returns the additional derives of the struct of the table.
+    #[must_use]
+    pub fn derives(&self) -> Vec<syn::Path> {
+        self.derives.iter().filter_map(|derive| syn::parse_str(derive).ok()).collect()
+    }
+
+    /// Returns whether the column holds sensitive data.
+    #[must_use]
+    pub fn is_sensitive(&self) -> bool {
+        self.sensitive
+    }
+
+    /// Returns the directives which only apply to columns, if any.
+    pub(crate) fn column_only_directives(&self) -> Vec<&'static str> {
+        let mut directives = Vec::new();
+        if self.rust_type.is_some() {
+            directives.push("type");
+        }
+        if self.sensitive {
+            directives.push("sensitive");
+        }
+        directives
+    }
+
+    /// Returns the directives which only apply to tables, if any.
+    pub(crate) fn table_only_directives(&self) -> Vec<&'static str> {
+        if self.derives.is_empty() { Vec::new() } else { vec!["derive"] }
+    }
+}
+
+/// Returns the byte index of the parenthesis closing the provided text, which
+/// follows an opening parenthesis, skipping the parentheses in string
+/// literals.
+fn closing_parenthesis(text: &str) -> Option<usize> {
+    let mut depth = 0_usize;
+    let mut in_string = false;
+    let mut escaped = false;
+    for (index, character) in text.char_indices() {
+        match character {
+            _ if escaped => escaped = false,
+            '\\' if in_string => escaped = true,
+            '"' => in_string = !in_string,
+            '(' if !in_string => depth += 1,
+            ')' if !in_string => {
+                if depth == 0 {
+                    return Some(index);
+                }
+                depth -= 1;
+            }
+            _ => {}
+        }
+    }
+    None
+}
@@ -1,5 +1,8 @@
 //! Submodule providing error types used in SynQL.
 
+mod check_constraint_error_kind;
+pub use check_constraint_error_kind::CheckConstraintErrorKind;
+
 /// Enum representing errors that can occur in SynQL.
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
@@ -15,19 +18,237 @@ pub enum Error {
         /// SQL type of the column.
         sql_type: String,
     },
+    #[error(
+        "The column `{column_name}` of table `{table_name}` has the multi-dimensional array type `{sql_type}`, which is not supported"
+    )]
+    /// Error raised when a column is an array of more than one dimension,
+    /// which cannot be decoded into a vector of its element type.
+    MultiDimensionalArray {
+        /// Name of the table the column belongs to.
+        table_name: String,
+        /// Name of the multi-dimensional array column.
+        column_name: String,
+        /// SQL type of the column.
+        sql_type: String,
+    },
     #[error("Function definition not found for function `{function_name}`")]
     /// Function definition not found error.
     FunctionNotFound {
         /// Name of the function whose definition was not found.
         function_name: String,
     },
+    #[error(
+        "Unable to translate check constraint `{constraint}` of table `{table_name}`: {reason} in `{sub_expression}`"
+    )]
+    /// Error raised when a check constraint cannot be translated into Rust
+    /// code.
+    UnsupportedCheckConstraint {
+        /// Name of the table of the check constraint.
+        table_name: String,
+        /// SQL text of the check constraint.
+        constraint: String,
+        /// SQL text of the offending sub-expression.
+        sub_expression: String,
+        /// Reason why the sub-expression could not be translated.
+        reason: CheckConstraintErrorKind,
+    },
+    #[error(
+        "Unable to translate check `{check}` of domain `{domain}`: {reason} in `{sub_expression}`"
+    )]
+    /// Error raised when a check of a domain cannot be translated into Rust
+    /// code.
+    UnsupportedDomainCheck {
+        /// Name of the domain, qualified with its schema if any.
+        domain: String,
+        /// SQL text of the check.
+        check: String,
+        /// SQL text of the offending sub-expression.
+        sub_expression: String,
+        /// Reason why the sub-expression could not be translated.
+        reason: CheckConstraintErrorKind,
+    },
+    #[error("Base type not found for domain `{domain}` with SQL type `{sql_type}`")]
+    /// Error raised when the base type of a domain is not provided by any of
+    /// the external crates of the workspace.
+    DomainTypeNotFound {
+        /// Name of the domain, qualified with its schema if any.
+        domain: String,
+        /// SQL type the domain is based on.
+        sql_type: String,
+    },
+    #[error(
+        "Type not found for attribute `{attribute}` of composite type `{composite}` with SQL type `{sql_type}`"
+    )]
+    /// Error raised when the type of an attribute of a composite type is not
+    /// provided by any of the external crates of the workspace.
+    CompositeAttributeTypeNotFound {
+        /// Name of the composite type, qualified with its schema if any.
+        composite: String,
+        /// Name of the attribute whose type was not found.
+        attribute: String,
+        /// SQL type of the attribute.
+        sql_type: String,
+    },
     #[error("Database contains more than {0} columns, which is unsupported")]
     /// Error indicating the database has more columns than supported.
     TooManyColumns(usize),
     #[error("I/O error: {0}")]
     /// I/O error.
     IO(#[from] std::io::Error),
+    #[error("Formatting error: {0}")]
+    /// Error raised while formatting the contents of a generated file.
+    Fmt(#[from] std::fmt::Error),
+    #[error(
+        "Generated files were modified by hand and would be overwritten: {}",
+        .0.iter().map(|path| path.display().to_string()).collect::<Vec<_>>().join(", ")
+    )]
+    /// Error raised when regenerating a workspace would overwrite or delete
+    /// generated files which were modified by hand since their generation.
+    HandEditedFiles(Vec<std::path::PathBuf>),
+    #[error("The workspace manifest lists the path `{}`, which is not relative to the workspace", .0.display())]
+    /// Error raised when the manifest of a workspace lists a path which is
+    /// absolute or escapes the workspace, such as `../Cargo.toml`, and would
+    /// therefore lead to files outside of the workspace being deleted.
+    InvalidManifestPath(std::path::PathBuf),
+    #[error("Generated code for `{}` is not valid Rust: {reason}", .path.display())]
+    /// Error raised when the code generated for a file cannot be parsed as
+    /// Rust, and therefore cannot be formatted.
+    InvalidGeneratedCode {
+        /// Path of the generated file, relative to the workspace root.
+        path: std::path::PathBuf,
+        /// Description of the parsing error.
+        reason: String,
+    },
     #[error("Invalid TOML dependency: {0}")]
     /// Invalid TOML dependency error.
     InvalidTomlDependency(String),
+    #[error("Invalid table selector `{selector}`: {reason}")]
+    /// Error raised when a table selector pattern is invalid.
+    InvalidTableSelector {
+        /// The invalid pattern.
+        selector: String,
+        /// Description of the parsing error.
+        reason: String,
+    },
+    #[error("The {0} does not match any table of the database")]
+    /// Error raised when a selector of the allow list does not match any
+    /// table, which usually indicates a typo.
+    UnmatchedTableSelector(String),
+    #[error("The {first} and the {second} would both be generated as the `{crate_name}` crate")]
+    /// Error raised when two of the crates to be generated share the same
+    /// name, such as homonymous tables of schemas which are not qualified.
+    CrateNameCollision {
+        /// The colliding crate name.
+        crate_name: String,
+        /// Description of the first crate with this name.
+        first: String,
+        /// Description of the second crate with this name.
+        second: String,
+    },
+    #[error(
+        "The {origin} would be generated as the `{crate_name}` crate, which is not a valid crate name"
+    )]
+    /// Error raised when the name of one of the crates to be generated, as
+    /// derived by the naming strategy, is not a valid Rust identifier once its
+    /// hyphens are replaced with underscores.
+    InvalidCrateName {
+        /// The invalid crate name.
+        crate_name: String,
+        /// Description of the crate with this name.
+        origin: String,
+    },
+    #[error(
+        "The default value `{default_value}` of column `{column_name}` of table `{table_name}` is rejected: {reason}"
+    )]
+    /// Error raised when the default value of a column cannot be generated,
+    /// such as a default value which does not satisfy the checks of its
+    /// domain.
+    InvalidDefaultValue {
+        /// Name of the table the column belongs to.
+        table_name: String,
+        /// Name of the column with the default value.
+        column_name: String,
+        /// The rejected SQL default value.
+        default_value: String,
+        /// Why the default value is rejected.
+        reason: String,
+    },
+    #[error("The {first} and the {second} would both be generated as `{identifier}`")]
+    /// Error raised when two distinct items of the schema would be generated
+    /// with the same identifier once their names are normalized, such as the
+    /// `MyColumn` and `my_column` columns, or a table named after an external
+    /// crate.
+    IdentifierCollision {
+        /// The colliding identifier.
+        identifier: String,
+        /// Description of the first item with this identifier.
+        first: String,
+        /// Description of the second item with this identifier.
+        second: String,
+    },
+    #[error("Invalid `@synql` directive on the {target}: {reason}")]
+    /// Error raised when the comment of a table or column contains a malformed
+    /// or inapplicable `@synql(...)` directive.
+    InvalidDirective {
+        /// Description of the table or column.
+        target: String,
+        /// Why the directive is invalid.
+        reason: String,
+    },
+    #[error("Invalid Rust type override for the {target}: {reason}")]
+    /// Error raised when the Rust type overriding the one of a column is not
+    /// a valid type, is not provided by any external crate, or targets a
+    /// column which does not exist.
+    InvalidColumnType {
+        /// Description of the column.
+        target: String,
+        /// Why the type override is invalid.
+        reason: String,
+    },
+    #[error(
+        "The postgres type `{postgres_type}` is provided by the `{}` crates with the same priority",
+        .crates.join("`, `")
+    )]
+    /// Error raised when a postgres type is provided by several crates with
+    /// the same priority, and is not pinned to any of them.
+    AmbiguousPostgresType {
+        /// The ambiguous postgres type.
+        postgres_type: String,
+        /// Names of the crates providing the type, in their registration
+        /// order.
+        crates: Vec<String>,
+    },
+    #[error(
+        "The postgres type `{postgres_type}` is pinned to the `{crate_name}` crate, which does not provide it"
+    )]
+    /// Error raised when a postgres type is pinned to a crate which is not
+    /// part of the workspace or does not provide it.
+    InvalidPostgresTypePin {
+        /// The pinned postgres type.
+        postgres_type: String,
+        /// Name of the crate the type is pinned to.
+        crate_name: String,
+    },
+    #[error("Unable to rebuild the external crates of the workspace: {0}")]
+    /// Error raised when the thread-safe representation of an external crate,
+    /// from which each thread generating crates in parallel rebuilds it,
+    /// cannot be parsed back.
+    InvalidExternalCrate(syn::Error),
+    #[error(
+        "The {first} and the {second} would both be mapped to the `{postgres_type}` Postgres type"
+    )]
+    /// Error raised when two user-defined types share the same name once
+    /// lowercased, as the Postgres types are matched case-insensitively.
+    DuplicateType {
+        /// The lowercased name shared by the types.
+        postgres_type: String,
+        /// Description of the first type with this name.
+        first: String,
+        /// Description of the second type with this name.
+        second: String,
+    },
+    #[error("Unable to parse the user-defined types: {0}")]
+    /// Error raised when the SQL declaring the user-defined types cannot be
+    /// parsed.
+    InvalidUserDefinedTypes(String),
 }
@@ -0,0 +1,87 @@
+//! Submodule defining the `CheckConstraintErrorKind` enum, describing why a
+//! check constraint could not be translated into Rust code.
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+/// Enumeration of the reasons why a check constraint could not be translated
+/// into Rust code.
+pub enum CheckConstraintErrorKind {
+    #[error("unsupported cast kind `{0}`")]
+    /// The cast kind is not supported, only `::` casts are.
+    UnsupportedCastKind(String),
+    #[error("casts with a format are not supported")]
+    /// Casts with a format are not supported.
+    UnsupportedCastFormat,
+    #[error("unsupported operator `{0}`")]
+    /// The operator is not supported in this position.
+    UnsupportedOperator(String),
+    #[error("unsupported value `{0}`")]
+    /// The literal value is not supported.
+    UnsupportedValue(String),
+    #[error("the type of the numeric literal cannot be inferred")]
+    /// The type of a numeric literal cannot be inferred from its context.
+    NumberWithoutTypeHint,
+    #[error("the literal cannot be cast to `{0}`")]
+    /// The literal cannot be cast to the expected Rust type.
+    InvalidLiteral(String),
+    #[error("unsupported expression")]
+    /// The expression is not supported.
+    UnsupportedExpression,
+    #[error("unsupported {0} in function call")]
+    /// The function call uses a clause which is not supported, such as `OVER`,
+    /// `FILTER` or the ODBC syntax.
+    UnsupportedFunctionClause(&'static str),
+    #[error("unsupported {0} function argument")]
+    /// The function argument is not supported, such as named arguments,
+    /// wildcards or subqueries.
+    UnsupportedFunctionArgument(&'static str),
+    #[error("expected {expected} function arguments, found {found}")]
+    /// The number of arguments does not match the function definition.
+    ArgumentCountMismatch {
+        /// Number of arguments in the function definition.
+        expected: usize,
+        /// Number of arguments in the function call.
+        found: usize,
+    },
+    #[error("function `{0}` not found")]
+    /// The function is not defined in the database.
+    FunctionNotFound(String),
+    #[error("no Rust type found for an argument of function `{0}`")]
+    /// The Rust type of an argument of the function cannot be determined.
+    MissingArgumentType(String),
+    #[error("function `{0}` cannot be called in the check of a domain")]
+    /// Functions cannot be called in the checks of a domain, as the types
+    /// crate does not depend on the crates implementing them.
+    UnsupportedDomainFunction(String),
+    #[error("function `{0}` has no Rust implementation in the workspace")]
+    /// The function has no corresponding external function in the workspace.
+    MissingExternalFunction(String),
+    #[error("{0} columns are scoped in a single function call, at most two are supported")]
+    /// Too many columns are scoped by a single function call.
+    TooManyScopedColumns(usize),
+    #[error("column references are not supported in this position")]
+    /// A column is referenced where only values are supported.
+    UnsupportedColumnReference,
+    #[error("column `{0}` not found")]
+    /// The column is not defined in the table of the check constraint.
+    ColumnNotFound(String),
+    #[error("no Rust type found for column `{0}`")]
+    /// The Rust type of the column cannot be determined.
+    ColumnTypeNotFound(String),
+    #[error("the type of the sub-expression cannot be inferred")]
+    /// The type of a sub-expression cannot be inferred.
+    MissingType,
+    #[error("mismatched types {0}")]
+    /// The operands of an operator have different types.
+    MismatchedTypes(String),
+    #[error("`{0}` is not a range")]
+    /// A range operator or function is applied to a value which is not a
+    /// range.
+    NotARange(String),
+    #[error("no current value is known for the type of `{0}`")]
+    /// A value is compared with the current time, but its type does not
+    /// define the expression evaluating to its current value.
+    MissingCurrentValue(String),
+    #[error("null check on non-nullable column `{0}`")]
+    /// A nullability check is applied to a non-nullable column.
+    NullCheckOnNonNullableColumn(String),
+}
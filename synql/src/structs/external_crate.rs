@@ -8,9 +8,10 @@ use quote::ToTokens;
 use syn::Type;
 
 use crate::structs::{
-    ExternalFunction, ExternalFunctionRef, ExternalType, ExternalTypeRef, TomlDependency,
-    external_crate::builder::ExternalCrateBuilderError,
+    ExternalFunction, ExternalFunctionRef, ExternalType, ExternalTypeRef, PostgresType,
+    TomlDependency, external_crate::builder::ExternalCrateBuilderError,
 };
+mod bigdecimal_crate;
 mod builder;
 mod chrono_crate;
 mod core_crate;
@@ -22,9 +23,14 @@ mod rosetta_utc;
 mod rosetta_uuid_crate;
 mod serde;
 mod serde_json;
+#[cfg(feature = "rayon")]
+mod source;
 mod std_crate;
+mod time_crate;
 mod validation_errors;
 pub use diesel_crate::MaximalNumberOfColumns;
+#[cfg(feature = "rayon")]
+pub(crate) use source::ExternalCrateSource;
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 /// Struct defining the crate required by some type found in the postgres
@@ -35,8 +41,14 @@ pub struct ExternalCrate {
     /// List of postgres types and their corresponding diesel and rust types
     /// defined within the crate.
     types: Vec<ExternalType>,
+    /// The ranges and arrays of the types defined within the crate, derived
+    /// once the crate is made available within a workspace.
+    derived_types: Vec<ExternalType>,
     /// Methods defined within the crate.
     functions: Vec<ExternalFunction>,
+    /// The priority of the crate over the others providing the same postgres
+    /// types.
+    priority: i16,
 }
 
 impl PartialOrd for ExternalCrate {
@@ -80,7 +92,9 @@ impl ExternalCrate {
     #[inline]
     #[must_use]
     pub fn is_dependency(&self) -> bool {
-        self.dependency.get_version().is_some() || self.dependency.get_git().is_some()
+        self.dependency.get_version().is_some()
+            || self.dependency.get_git().is_some()
+            || self.dependency.get_path().is_some()
     }
 
     /// Returns the version of the crate if it is a dependency.
@@ -100,6 +114,21 @@ impl ExternalCrate {
             .map(|repo| (repo, self.dependency.get_branch().unwrap_or_default()))
     }
 
+    /// Returns the priority of the crate over the other crates of the
+    /// workspace providing the same postgres types.
+    #[inline]
+    #[must_use]
+    pub fn priority(&self) -> i16 {
+        self.priority
+    }
+
+    /// Returns the types provided by the crate.
+    #[inline]
+    #[must_use]
+    pub fn types(&self) -> &[ExternalType] {
+        &self.types
+    }
+
     /// Returns the feature flags required by the crate.
     #[inline]
     #[must_use]
@@ -115,6 +144,7 @@ impl ExternalCrate {
     pub fn external_type(&self, ident: &Type) -> Option<ExternalTypeRef<'_>> {
         self.types
             .iter()
+            .chain(&self.derived_types)
             .find(|t| {
                 t.rust_type().to_token_stream().to_string() == ident.to_token_stream().to_string()
             })
@@ -134,13 +164,55 @@ impl ExternalCrate {
     /// Returns the external type compatible with the provided postgres name, if
     /// any.
     ///
+    /// The types whose postgres types match the modifiers of the type, such as
+    /// the `geography(point, *)` pattern for `geography(point, 4326)`, take
+    /// precedence over the ones ignoring them, while the ranges and the
+    /// arrays, whatever their number of dimensions, are resolved through the
+    /// type of their bounds and elements, unless the crate explicitly provides
+    /// them.
+    ///
     /// # Arguments
     /// * `postgres_type` - The postgres type to find a compatible type for.
     #[must_use]
     pub fn external_postgres_type(&self, postgres_type: &str) -> Option<ExternalTypeRef<'_>> {
-        self.types
-            .iter()
-            .find(|t| t.is_compatible_with(postgres_type))
+        let postgres_type = PostgresType::parse(postgres_type);
+        let find = |postgres_type: &PostgresType| {
+            [true, false].into_iter().find_map(|parameterized| {
+                self.types
+                    .iter()
+                    .chain(&self.derived_types)
+                    .find(|t| t.matched_modifiers_of(postgres_type, parameterized).is_some())
+            })
+        };
+        find(&postgres_type)
+            .or_else(|| {
+                let element = postgres_type.array_element()?;
+                find(&element.clone().with_name(&format!("{}[]", element.name())))
+            })
             .map(|t| ExternalTypeRef::new(self, t))
     }
+
+    /// Derives the ranges and the arrays of the types defined within the
+    /// crate, but the ones the crate explicitly provides.
+    ///
+    /// # Arguments
+    /// * `nullable_elements` - Whether the elements of the arrays are nullable.
+    pub(crate) fn derive_types(&mut self, nullable_elements: bool) {
+        let ranges = self.types.iter().flat_map(ExternalType::ranges).collect::<Vec<_>>();
+        let arrays = self
+            .types
+            .iter()
+            .chain(&ranges)
+            .filter_map(|t| t.array(nullable_elements))
+            .collect::<Vec<_>>();
+        self.derived_types = ranges
+            .into_iter()
+            .chain(arrays)
+            .filter(|derived_type| {
+                !derived_type.postgres_types().iter().any(|postgres_type| {
+                    self.types.iter().any(|t| t.is_compatible_with(postgres_type))
+                })
+            })
+            .collect();
+    }
 }
@@ -0,0 +1,32 @@
+//! Submodule implementing the method `bigdecimal` for the [`ExternalCrate`]
+//! struct which initializes a `ExternalCrate` instance describing the
+//! `bigdecimal` crate.
+
+use crate::structs::{ExternalCrate, ExternalType};
+
+impl ExternalCrate {
+    /// Returns the `ExternalCrate` instance describing the `bigdecimal`
+    /// crate, which maps the arbitrary-precision `numeric` type.
+    #[must_use]
+    pub fn bigdecimal() -> ExternalCrate {
+        ExternalCrate::new("bigdecimal")
+            .unwrap()
+            .version("0.4.8")
+            .unwrap()
+            .feature("serde")
+            .types([ExternalType::new(
+                syn::parse_quote!(::diesel::sql_types::Numeric),
+                syn::parse_quote!(::bigdecimal::BigDecimal),
+            )
+            .postgres_types(["numeric", "decimal"])
+            .unwrap()
+            .supports_debug()
+            .supports_clone()
+            .supports_default()
+            .supports_hash()
+            .supports_ord()
+            .into()])
+            .unwrap()
+            .into()
+    }
+}
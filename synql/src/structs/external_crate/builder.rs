@@ -14,6 +14,9 @@ pub struct ExternalCrateBuilder {
     types: Vec<ExternalType>,
     /// The functions provided by the crate.
     functions: Vec<ExternalFunction>,
+    /// The priority of the crate over the others providing the same postgres
+    /// types.
+    priority: i16,
 }
 
 impl ExternalCrateBuilder {
@@ -27,7 +30,12 @@ impl ExternalCrateBuilder {
         if name.trim().is_empty() || name.contains(' ') {
             return Err(ExternalCrateBuilderError::InvalidName);
         }
-        Ok(Self { dependency: TomlDependency::new(name), types: Vec::new(), functions: Vec::new() })
+        Ok(Self {
+            dependency: TomlDependency::new(name),
+            types: Vec::new(),
+            functions: Vec::new(),
+            priority: 0,
+        })
     }
 }
 
@@ -80,10 +88,10 @@ impl ExternalCrateBuilder {
         mut self,
         required_type: ExternalType,
     ) -> Result<Self, ExternalCrateBuilderError> {
-        for postgres_type in required_type.postgres_types() {
-            if self.types.iter().any(|t| t.is_compatible_with(postgres_type)) {
-                return Err(ExternalCrateBuilderError::DuplicatedPostgresType);
-            }
+        if required_type.patterns().iter().any(|pattern| {
+            self.types.iter().flat_map(ExternalType::patterns).any(|existing| existing == pattern)
+        }) {
+            return Err(ExternalCrateBuilderError::DuplicatedPostgresType);
         }
         self.types.push(required_type);
         Ok(self)
@@ -130,6 +138,17 @@ impl ExternalCrateBuilder {
         Ok(self)
     }
 
+    /// Sets the path of the crate, if it is a crate of the workspace.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidTomlDependency` if the dependency is a workspace
+    /// dependency.
+    pub fn path<S: ToString + ?Sized>(mut self, path: &S) -> Result<Self, Error> {
+        self.dependency = self.dependency.path(path.to_string())?;
+        Ok(self)
+    }
+
     /// Adds a feature to the crate.
     ///
     /// # Arguments
@@ -156,6 +175,18 @@ impl ExternalCrateBuilder {
         self
     }
 
+    /// Sets the priority of the crate over the other crates of the workspace
+    /// providing the same postgres types, which are mapped to the type of the
+    /// crate with the highest priority. The priority is zero by default.
+    ///
+    /// # Arguments
+    /// * `priority` - The priority of the crate.
+    #[must_use]
+    pub fn priority(mut self, priority: i16) -> Self {
+        self.priority = priority;
+        self
+    }
+
     /// Adds a function provided by the crate.
     ///
     /// # Arguments
@@ -189,7 +220,9 @@ impl From<ExternalCrateBuilder> for ExternalCrate {
         ExternalCrate {
             dependency: value.dependency,
             types: value.types,
+            derived_types: Vec::new(),
             functions: value.functions,
+            priority: value.priority,
         }
     }
 }
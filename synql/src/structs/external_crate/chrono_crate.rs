@@ -20,6 +20,7 @@ impl ExternalCrate {
                 )
                 .postgres_types(["timestamp", "timestamp without time zone"])
                 .unwrap()
+                .current_value(syn::parse_quote!(::chrono::Utc::now().naive_utc()))
                 .supports_debug()
                 .supports_copy()
                 .supports_ord()
@@ -31,6 +32,7 @@ impl ExternalCrate {
                 )
                 .postgres_types(["timestamptz", "timestamp with time zone"])
                 .unwrap()
+                .current_value(syn::parse_quote!(::chrono::Utc::now()))
                 .supports_debug()
                 .supports_copy()
                 .supports_ord()
@@ -42,6 +44,7 @@ impl ExternalCrate {
                 )
                 .postgres_type("date")
                 .unwrap()
+                .current_value(syn::parse_quote!(::chrono::Utc::now().date_naive()))
                 .supports_debug()
                 .supports_copy()
                 .supports_ord()
@@ -53,6 +56,7 @@ impl ExternalCrate {
                 )
                 .postgres_type("time")
                 .unwrap()
+                .current_value(syn::parse_quote!(::chrono::Utc::now().time()))
                 .supports_debug()
                 .supports_copy()
                 .supports_ord()
@@ -139,7 +139,7 @@ impl ExternalType {
     /// `core` crate.
     fn f64() -> Self {
         ExternalType::new(syn::parse_quote!(::diesel::sql_types::Double), syn::parse_quote!(f64))
-            .postgres_types(["float8", "double precision", "numeric"])
+            .postgres_types(["float8", "double precision"])
             .unwrap()
             .supports_debug()
             .supports_copy()
@@ -52,10 +52,15 @@ impl MaximalNumberOfColumns {
 impl ExternalCrate {
     /// Returns `ExternalCrate` instance describing the `diesel`
     /// crate.
+    ///
+    /// The crate has a lower priority than the default one, so that its
+    /// `PgInterval` is only used when no date and time crate provides the
+    /// `interval` type.
     #[must_use]
     pub fn diesel(number_of_columns: MaximalNumberOfColumns) -> ExternalCrate {
         ExternalCrate::new("diesel")
             .unwrap()
+            .priority(-1)
             .feature("extras")
             .features(number_of_columns.as_diesel_feature_str())
             .git("https://github.com/LucaCappelletti94/diesel", "future3")
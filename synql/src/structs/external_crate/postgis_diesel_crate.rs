@@ -30,13 +30,30 @@ impl ExternalCrate {
     }
 }
 
+/// Returns the postgres types of the provided geometric subtype, that is the
+/// subtype itself alongside the `geometry` and `geography` types constrained
+/// to it, whatever their SRID.
+///
+/// # Arguments
+///
+/// * `subtype` - The geometric subtype, such as `point`.
+fn geometric_types(subtype: &str) -> [String; 5] {
+    [
+        subtype.to_owned(),
+        format!("geometry({subtype})"),
+        format!("geometry({subtype}, *)"),
+        format!("geography({subtype})"),
+        format!("geography({subtype}, *)"),
+    ]
+}
+
 impl ExternalType {
     fn point() -> Self {
         ExternalType::new(
             syn::parse_quote!(::postgis_diesel::sql_types::Geometry),
             syn::parse_quote!(::postgis_diesel::types::Point),
         )
-        .postgres_types(["point", "geography(point, 4326)", "geometry(point, 4326)"])
+        .postgres_types(geometric_types("point"))
         .unwrap()
         .supports_copy()
         .supports_debug()
@@ -50,7 +67,7 @@ impl ExternalType {
             syn::parse_quote!(::postgis_diesel::sql_types::Geometry),
             syn::parse_quote!(::postgis_diesel::types::LineString),
         )
-        .postgres_type("linestring")
+        .postgres_types(geometric_types("linestring"))
         .unwrap()
         .supports_clone()
         .supports_debug()
@@ -63,7 +80,7 @@ impl ExternalType {
             syn::parse_quote!(::postgis_diesel::sql_types::Geometry),
             syn::parse_quote!(::postgis_diesel::types::Polygon),
         )
-        .postgres_type("polygon")
+        .postgres_types(geometric_types("polygon"))
         .unwrap()
         .supports_clone()
         .supports_debug()
@@ -76,7 +93,7 @@ impl ExternalType {
             syn::parse_quote!(::postgis_diesel::sql_types::Geometry),
             syn::parse_quote!(::postgis_diesel::types::MultiPoint),
         )
-        .postgres_type("multipoint")
+        .postgres_types(geometric_types("multipoint"))
         .unwrap()
         .supports_clone()
         .supports_debug()
@@ -89,7 +106,7 @@ impl ExternalType {
             syn::parse_quote!(::postgis_diesel::sql_types::Geometry),
             syn::parse_quote!(::postgis_diesel::types::MultiLineString),
         )
-        .postgres_type("multilinestring")
+        .postgres_types(geometric_types("multilinestring"))
         .unwrap()
         .supports_clone()
         .supports_debug()
@@ -102,7 +119,7 @@ impl ExternalType {
             syn::parse_quote!(::postgis_diesel::sql_types::Geometry),
             syn::parse_quote!(::postgis_diesel::types::MultiPolygon),
         )
-        .postgres_type("multipolygon")
+        .postgres_types(geometric_types("multipolygon"))
         .unwrap()
         .supports_clone()
         .supports_debug()
@@ -115,7 +132,7 @@ impl ExternalType {
             syn::parse_quote!(::postgis_diesel::sql_types::Geometry),
             syn::parse_quote!(::postgis_diesel::types::GeometryCollection),
         )
-        .postgres_type("geometrycollection")
+        .postgres_types(geometric_types("geometrycollection"))
         .unwrap()
         .supports_clone()
         .supports_debug()
@@ -7,10 +7,15 @@ use crate::structs::{ExternalCrate, ExternalType};
 impl ExternalCrate {
     /// Returns `ExternalCrate` instance describing the
     /// `rosetta_utc` crate.
+    ///
+    /// The crate has a higher priority than the default one, so that its
+    /// `TimestampUTC` is preferred over the `timestamptz` types of the other
+    /// date and time crates it is registered with.
     #[must_use]
     pub fn rosetta_utc() -> ExternalCrate {
         ExternalCrate::new("rosetta-utc")
             .unwrap()
+            .priority(1)
             .version("0.1.0")
             .unwrap()
             .git("https://github.com/earth-metabolome-initiative/rosetta-utc", "main")
@@ -22,6 +27,7 @@ impl ExternalCrate {
             )
             .postgres_types(["timestamp with time zone", "timestamptz"])
             .unwrap()
+            .current_value(syn::parse_quote!(::rosetta_utc::TimestampUTC::now()))
             .supports_debug()
             .supports_copy()
             .supports_eq()
@@ -0,0 +1,107 @@
+//! Submodule providing a thread-safe representation of an `ExternalCrate`,
+//! which allows each thread generating crates to rebuild its own copy of the
+//! external crates made available within the workspace.
+
+use quote::ToTokens;
+
+use crate::structs::{
+    ExternalCrate, ExternalFunction, ExternalType, TomlDependency,
+    external_type::ExternalTypeSource,
+};
+
+#[derive(Clone, Debug)]
+/// Thread-safe representation of an `ExternalCrate`.
+pub(crate) struct ExternalCrateSource {
+    /// The TOML dependency of the crate.
+    dependency: TomlDependency,
+    /// Thread-safe representation of the types defined within the crate.
+    types: Vec<ExternalTypeSource>,
+    /// Names and textual paths of the functions defined within the crate.
+    functions: Vec<(String, String)>,
+    /// The priority of the crate over the others providing the same postgres
+    /// types.
+    priority: i16,
+}
+
+// Both conversions destructure their input exhaustively, so that adding a field
+// to the `ExternalCrate` fails to compile until it is carried by the source.
+impl From<&ExternalCrate> for ExternalCrateSource {
+    fn from(external_crate: &ExternalCrate) -> Self {
+        // The derived types are computed again once the crate is made
+        // available within a workspace.
+        let ExternalCrate { dependency, types, derived_types: _, functions, priority } =
+            external_crate;
+        Self {
+            dependency: dependency.clone(),
+            types: types.iter().map(ExternalTypeSource::from).collect(),
+            functions: functions
+                .iter()
+                .map(|function| {
+                    (function.name().to_string(), function.path().to_token_stream().to_string())
+                })
+                .collect(),
+            priority: *priority,
+        }
+    }
+}
+
+impl TryFrom<&ExternalCrateSource> for ExternalCrate {
+    type Error = syn::Error;
+
+    fn try_from(source: &ExternalCrateSource) -> Result<Self, Self::Error> {
+        let ExternalCrateSource { dependency, types, functions, priority } = source;
+        Ok(Self {
+            dependency: dependency.clone(),
+            types: types.iter().map(ExternalType::try_from).collect::<Result<_, _>>()?,
+            derived_types: Vec::new(),
+            functions: functions
+                .iter()
+                .map(|(name, path)| Ok(ExternalFunction::new(name, syn::parse_str(path)?)))
+                .collect::<Result<_, syn::Error>>()?,
+            priority: *priority,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::structs::{TemporalBackend, external_crate::MaximalNumberOfColumns};
+
+    #[test]
+    fn test_round_trip() {
+        let external_crates = [
+            ExternalCrate::postgis_diesel(),
+            ExternalCrate::bigdecimal(),
+            ExternalCrate::diesel(MaximalNumberOfColumns::Columns16),
+            ExternalCrate::pgrx_validation(),
+            ExternalCrate::std(),
+            ExternalCrate::time(),
+        ]
+        .into_iter()
+        .chain(TemporalBackend::default().external_crates())
+        .chain([ExternalCrate::new("labels")
+            .unwrap()
+            .version("0.1.0")
+            .unwrap()
+            .priority(2)
+            .types([ExternalType::new(
+                syn::parse_quote!(::diesel::sql_types::Text),
+                syn::parse_quote!(::labels::Label),
+            )
+            .postgres_type("label")
+            .unwrap()
+            .literal("unknown", syn::parse_quote!(::labels::Label::Unknown))
+            .base_type(syn::parse_quote!(String))
+            .current_value(syn::parse_quote!(::labels::Label::now()))
+            .supports_ord()
+            .into()])
+            .unwrap()
+            .into()]);
+        for external_crate in external_crates {
+            let rebuilt = ExternalCrate::try_from(&ExternalCrateSource::from(&external_crate))
+                .expect("The thread-safe representation must be parsed back");
+            assert_eq!(rebuilt, external_crate, "{}", external_crate.name());
+        }
+    }
+}
@@ -5,22 +5,21 @@ use crate::structs::{ExternalCrate, ExternalType};
 
 impl ExternalCrate {
     /// Returns `ExternalCrate` instance describing the `std` crate.
+    ///
+    /// The crate has a lower priority than the default one, so that its
+    /// `SystemTime` is only used when no date and time crate is available.
     #[must_use]
     pub fn std() -> ExternalCrate {
         ExternalCrate::new("std")
             .unwrap()
+            .priority(-1)
             .types([
                 ExternalType::string(),
                 ExternalType::vec_u8(),
-                ExternalType::vec_i16(),
-                ExternalType::vec_i32(),
-                ExternalType::vec_u32(),
-                ExternalType::vec_f32(),
-                ExternalType::vec_f64(),
-                ExternalType::vec_bool(),
+                ExternalType::int2vector(),
+                ExternalType::oidvector(),
                 ExternalType::ip_addr(),
                 ExternalType::mac_addr(),
-                ExternalType::vec_string(),
                 ExternalType::system_time(),
             ])
             .unwrap()
@@ -70,14 +69,16 @@ impl ExternalType {
             .into()
     }
 
-    /// Returns an `ExternalType` instance describing the `Vec<i16>` type from
-    /// the `std` crate.
-    fn vec_i16() -> Self {
+    /// Returns an `ExternalType` instance describing the `int2vector` type,
+    /// mapped to a `Vec<i16>`.
+    ///
+    /// The arrays of the other types are derived from their element type.
+    fn int2vector() -> Self {
         ExternalType::new(
             syn::parse_quote!(diesel::sql_types::Array<diesel::sql_types::SmallInt>),
             syn::parse_quote!(Vec<i16>),
         )
-        .postgres_types(["_int2", "int2[]", "_smallint", "int2vector"])
+        .postgres_types(["int2vector"])
         .unwrap()
         .supports_debug()
         .supports_clone()
@@ -87,108 +88,14 @@ impl ExternalType {
         .into()
     }
 
-    /// Returns an `ExternalType` instance describing the `Vec<i32>` type from
-    /// the `std` crate.
-    fn vec_i32() -> Self {
-        ExternalType::new(
-            syn::parse_quote!(diesel::sql_types::Array<diesel::sql_types::Integer>),
-            syn::parse_quote!(Vec<i32>),
-        )
-        .postgres_types(["_int4", "int4[]", "_integer", "_cardinal_number"])
-        .unwrap()
-        .supports_debug()
-        .supports_clone()
-        .supports_default()
-        .supports_hash()
-        .supports_ord()
-        .into()
-    }
-
-    /// Returns an `ExternalType` instance describing the `Vec<u32>` type from
-    /// the `std` crate.
-    fn vec_u32() -> Self {
+    /// Returns an `ExternalType` instance describing the `oidvector` type,
+    /// mapped to a `Vec<u32>`.
+    fn oidvector() -> Self {
         ExternalType::new(
             syn::parse_quote!(diesel::sql_types::Array<diesel::sql_types::Oid>),
             syn::parse_quote!(Vec<u32>),
         )
-        .postgres_types(["_oid", "oid[]", "_regtype", "regtype[]", "oidvector"])
-        .unwrap()
-        .supports_debug()
-        .supports_clone()
-        .supports_default()
-        .supports_hash()
-        .supports_ord()
-        .into()
-    }
-
-    /// Returns an `ExternalType` instance describing the `Vec<f32>` type from
-    /// the `std` crate.
-    fn vec_f32() -> Self {
-        ExternalType::new(
-            syn::parse_quote!(diesel::sql_types::Array<diesel::sql_types::Float>),
-            syn::parse_quote!(Vec<f32>),
-        )
-        .postgres_types(["_float4", "float4[]", "_real"])
-        .unwrap()
-        .supports_debug()
-        .supports_clone()
-        .supports_default()
-        .supports_partial_eq()
-        .supports_partial_ord()
-        .into()
-    }
-
-    /// Returns an `ExternalType` instance describing the `Vec<f64>` type from
-    /// the `std` crate.
-    fn vec_f64() -> Self {
-        ExternalType::new(
-            syn::parse_quote!(diesel::sql_types::Array<diesel::sql_types::Double>),
-            syn::parse_quote!(Vec<f64>),
-        )
-        .postgres_types(["_float8", "float8[]", "_double_precision"])
-        .unwrap()
-        .supports_debug()
-        .supports_clone()
-        .supports_default()
-        .supports_partial_eq()
-        .supports_partial_ord()
-        .into()
-    }
-
-    /// Returns an `ExternalType` instance describing the `Vec<bool>` type from
-    /// the `std` crate.
-    fn vec_bool() -> Self {
-        ExternalType::new(
-            syn::parse_quote!(diesel::sql_types::Array<diesel::sql_types::Bool>),
-            syn::parse_quote!(Vec<bool>),
-        )
-        .postgres_types(["_bool", "bool[]"])
-        .unwrap()
-        .supports_debug()
-        .supports_clone()
-        .supports_default()
-        .supports_hash()
-        .supports_ord()
-        .into()
-    }
-
-    /// Returns an [`ExternalType`] instance describing the `Vec<String>` type
-    /// from the `std` crate.
-    fn vec_string() -> Self {
-        ExternalType::new(
-            syn::parse_quote!(diesel::sql_types::Array<diesel::sql_types::Text>),
-            syn::parse_quote!(Vec<String>),
-        )
-        .postgres_types([
-            "_text",
-            "text[]",
-            "_character varying",
-            "_varchar",
-            "_bpchar",
-            "_name",
-            "_char",
-            "_aclitem",
-        ])
+        .postgres_types(["oidvector"])
         .unwrap()
         .supports_debug()
         .supports_clone()
@@ -0,0 +1,72 @@
+//! Submodule implementing the method `time` for the [`ExternalCrate`] struct
+//! which initializes a `ExternalCrate` instance describing the `time` crate.
+
+use crate::structs::{ExternalCrate, ExternalType};
+
+impl ExternalCrate {
+    /// Returns the `ExternalCrate` instance describing the `time`
+    /// crate.
+    #[must_use]
+    pub fn time() -> ExternalCrate {
+        ExternalCrate::new("time")
+            .unwrap()
+            .version("0.3.44")
+            .unwrap()
+            .feature("serde")
+            .types([
+                ExternalType::new(
+                    syn::parse_quote!(::diesel::sql_types::Timestamp),
+                    syn::parse_quote!(::time::PrimitiveDateTime),
+                )
+                .postgres_types(["timestamp", "timestamp without time zone"])
+                .unwrap()
+                .current_value(syn::parse_quote!({
+                    let now = ::time::OffsetDateTime::now_utc();
+                    ::time::PrimitiveDateTime::new(now.date(), now.time())
+                }))
+                .supports_debug()
+                .supports_copy()
+                .supports_ord()
+                .supports_hash()
+                .into(),
+                ExternalType::new(
+                    syn::parse_quote!(::diesel::sql_types::Timestamptz),
+                    syn::parse_quote!(::time::OffsetDateTime),
+                )
+                .postgres_types(["timestamptz", "timestamp with time zone"])
+                .unwrap()
+                .current_value(syn::parse_quote!(::time::OffsetDateTime::now_utc()))
+                .supports_debug()
+                .supports_copy()
+                .supports_ord()
+                .supports_hash()
+                .into(),
+                ExternalType::new(
+                    syn::parse_quote!(::diesel::sql_types::Date),
+                    syn::parse_quote!(::time::Date),
+                )
+                .postgres_type("date")
+                .unwrap()
+                .current_value(syn::parse_quote!(::time::OffsetDateTime::now_utc().date()))
+                .supports_debug()
+                .supports_copy()
+                .supports_ord()
+                .supports_hash()
+                .into(),
+                ExternalType::new(
+                    syn::parse_quote!(::diesel::sql_types::Time),
+                    syn::parse_quote!(::time::Time),
+                )
+                .postgres_type("time")
+                .unwrap()
+                .current_value(syn::parse_quote!(::time::OffsetDateTime::now_utc().time()))
+                .supports_debug()
+                .supports_copy()
+                .supports_ord()
+                .supports_hash()
+                .into(),
+            ])
+            .unwrap()
+            .into()
+    }
+}
@@ -2,13 +2,20 @@
 //! in the postgres database schema.
 
 mod builder;
+#[cfg(feature = "rayon")]
+mod source;
 mod traits_mask;
-use std::{fmt::Debug, hash::Hash};
+use std::{borrow::Cow, fmt::Debug, hash::Hash};
 
 pub use builder::ExternalTypeBuilder;
 use quote::{ToTokens, quote};
+#[cfg(feature = "rayon")]
+pub(crate) use source::ExternalTypeSource;
+use strum::IntoEnumIterator;
 pub use traits_mask::Trait;
 
+use crate::structs::PostgresType;
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 /// Struct defining the type required by some type found in the postgres
 /// database schema.
@@ -21,9 +28,129 @@ pub struct ExternalType {
     rust_type: syn::Type,
     /// The postgres types which are compatible with the diesel and rust types
     /// defined within the crate.
-    postgres_types: Vec<&'static str>,
+    postgres_types: Vec<String>,
+    /// The postgres types parsed into patterns, in the same order, so that
+    /// they are not parsed again whenever a type is matched against them.
+    patterns: Vec<PostgresType>,
     /// The traits supported by the current type.
     traits: traits_mask::TraitsMask,
+    /// The SQL literals, such as the labels of an enum, which are cast to the
+    /// provided Rust expressions.
+    literals: Vec<(String, syn::Expr)>,
+    /// The type the current type is validated from, such as the base type of
+    /// a domain, which the literals are cast to before being validated.
+    base_type: Option<syn::Type>,
+    /// The expression evaluating to the current value of the type, such as
+    /// the current timestamp, which `CURRENT_TIMESTAMP` and `now()` are
+    /// translated to.
+    current_value: Option<syn::Expr>,
+}
+
+/// Returns the element type of the provided Postgres array type, if it is an
+/// array type.
+///
+/// Postgres does not distinguish arrays by their declared size, so
+/// `integer[]`, `integer[3]`, `integer array` and `_integer` all have
+/// `integer` as element type. Only one dimension is stripped, so that the
+/// element type of `integer[][]` is `integer[]`.
+///
+/// # Arguments
+///
+/// * `postgres_type` - The Postgres type, possibly schema-qualified.
+pub(crate) fn array_element(postgres_type: &str) -> Option<String> {
+    let postgres_type = postgres_type.trim().to_lowercase();
+    if let Some(stripped) = postgres_type.strip_suffix(']') {
+        return Some(stripped[..stripped.rfind('[')?].trim_end().to_owned());
+    }
+    if let Some(element) = postgres_type.strip_suffix(" array") {
+        return Some(element.trim_end().to_owned());
+    }
+    // The internal names of the arrays, such as `_int4`, prefix the name of
+    // their element type with an underscore.
+    match postgres_type.rsplit_once('.') {
+        Some((schema, name)) => name.strip_prefix('_').map(|element| format!("{schema}.{element}")),
+        None => postgres_type.strip_prefix('_').map(ToOwned::to_owned),
+    }
+}
+
+/// Returns the number of dimensions of the provided Postgres type, such as
+/// `0` for `integer`, `1` for `integer[]` or `_int4`, and `2` for
+/// `integer[][]`.
+///
+/// The arrays of more than one dimension are not supported, as they cannot be
+/// decoded into vectors of their element type.
+///
+/// # Arguments
+///
+/// * `postgres_type` - The Postgres type, possibly schema-qualified.
+pub(crate) fn array_dimensions(postgres_type: &str) -> usize {
+    let mut dimensions = 0;
+    let mut current = postgres_type.to_owned();
+    while let Some(element) = array_element(&current) {
+        dimensions += 1;
+        current = element;
+    }
+    dimensions
+}
+
+/// The built-in range types of Postgres, alongside their multirange type and
+/// the type of their bounds.
+const RANGE_TYPES: [(&str, &str, &str); 6] = [
+    ("int4range", "int4multirange", "int4"),
+    ("int8range", "int8multirange", "int8"),
+    ("numrange", "nummultirange", "numeric"),
+    ("tsrange", "tsmultirange", "timestamp"),
+    ("tstzrange", "tstzmultirange", "timestamptz"),
+    ("daterange", "datemultirange", "date"),
+];
+
+/// Returns the provided Postgres type without its modifiers, such as
+/// `numeric` for `numeric(10, 2)` or `timestamp with time zone` for
+/// `timestamp(3) with time zone`.
+///
+/// # Arguments
+///
+/// * `postgres_type` - The Postgres type, possibly with modifiers.
+pub(crate) fn without_modifiers(postgres_type: &str) -> Cow<'_, str> {
+    if !postgres_type.contains('(') {
+        return Cow::Borrowed(postgres_type);
+    }
+    let mut depth = 0usize;
+    let mut stripped = String::with_capacity(postgres_type.len());
+    for character in postgres_type.chars() {
+        match character {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            character if depth == 0 => stripped.push(character),
+            _ => {}
+        }
+    }
+    Cow::Owned(stripped.split_whitespace().collect::<Vec<_>>().join(" ").replace(" [", "["))
+}
+
+/// Returns the literal of the provided SQL value, stripped of its cast and
+/// of its quotes, such as `a::b` for `'a::b'::text` or `it's` for
+/// `'it''s'::text`.
+///
+/// # Arguments
+///
+/// * `value` - The SQL value, such as the default value of a column.
+pub(crate) fn sql_literal(value: &str) -> Cow<'_, str> {
+    let value = value.trim();
+    let Some(quoted) = value.strip_prefix('\'') else {
+        return Cow::Borrowed(value.split("::").next().unwrap_or(value).trim());
+    };
+    let mut literal = String::with_capacity(quoted.len());
+    let mut characters = quoted.chars().peekable();
+    while let Some(character) = characters.next() {
+        // Quotes are escaped by doubling them, while a single one closes the
+        // literal.
+        if character == '\'' && characters.next_if_eq(&'\'').is_none() {
+            break;
+        }
+        literal.push(character);
+    }
+    Cow::Owned(literal)
 }
 
 impl ExternalType {
@@ -50,10 +177,38 @@ impl ExternalType {
     /// Returns a reference over the postgres types which are compatible with
     /// the diesel and rust types defined within the crate.
     #[must_use]
-    pub fn postgres_types(&self) -> &[&'static str] {
+    pub fn postgres_types(&self) -> &[String] {
         &self.postgres_types
     }
 
+    /// Returns the parsed patterns of the postgres types which are compatible
+    /// with the diesel and rust types defined within the crate.
+    #[must_use]
+    pub(crate) fn patterns(&self) -> &[PostgresType] {
+        &self.patterns
+    }
+
+    /// Returns the SQL literals which are cast to the provided Rust
+    /// expressions, such as the labels of an enum.
+    #[must_use]
+    pub fn literals(&self) -> &[(String, syn::Expr)] {
+        &self.literals
+    }
+
+    /// Returns the type the current type is validated from, such as the base
+    /// type of a domain, if any.
+    #[must_use]
+    pub fn base_type(&self) -> Option<&syn::Type> {
+        self.base_type.as_ref()
+    }
+
+    /// Returns the expression evaluating to the current value of the type,
+    /// such as `::chrono::Utc::now()`, if any.
+    #[must_use]
+    pub fn current_value(&self) -> Option<&syn::Expr> {
+        self.current_value.as_ref()
+    }
+
     /// Returns whether the type is a `Unit` type.
     #[must_use]
     pub fn is_unit(&self) -> bool {
@@ -72,19 +227,188 @@ impl ExternalType {
     }
 
     /// Returns whether the current `ExternalType` is compatible with the given
-    /// postgres type.
+    /// postgres type, that is whether any of its postgres types, used as
+    /// patterns over the parsed type, matches it.
     ///
     /// # Arguments
     /// * `postgres_type` - The postgres type to check compatibility with.
     #[must_use]
     pub fn is_compatible_with(&self, postgres_type: &str) -> bool {
-        self.postgres_types.iter().any(|t| t.eq_ignore_ascii_case(postgres_type))
+        self.matched_modifiers(postgres_type).is_some()
+    }
+
+    /// Returns the modifiers of the given postgres type which are matched by
+    /// the postgres types of the current `ExternalType`, such as `["4326"]`
+    /// for `geography(point, 4326)` and the `geography(point, *)` pattern, if
+    /// the type is compatible with it.
+    ///
+    /// The patterns with modifiers take precedence over the ones without.
+    ///
+    /// # Arguments
+    /// * `postgres_type` - The postgres type to match.
+    #[must_use]
+    pub fn matched_modifiers(&self, postgres_type: &str) -> Option<Vec<String>> {
+        let postgres_type = PostgresType::parse(postgres_type);
+        [true, false].into_iter().find_map(|parameterized| {
+            self.matched_modifiers_of(&postgres_type, parameterized)
+                .map(|modifiers| modifiers.into_iter().map(ToOwned::to_owned).collect())
+        })
+    }
+
+    /// Returns the modifiers of the given parsed postgres type which are
+    /// matched by the postgres types of the current `ExternalType` with or
+    /// without modifiers, if any of them matches.
+    ///
+    /// # Arguments
+    /// * `postgres_type` - The parsed postgres type to match.
+    /// * `parameterized` - Whether to only consider the postgres types with
+    ///   modifiers, or the ones without.
+    pub(crate) fn matched_modifiers_of<'a>(
+        &self,
+        postgres_type: &'a PostgresType,
+        parameterized: bool,
+    ) -> Option<Vec<&'a str>> {
+        self.patterns.iter().find_map(|pattern| {
+            if pattern.is_parameterized() != parameterized {
+                return None;
+            }
+            postgres_type.matched_modifiers(pattern)
+        })
+    }
+
+    /// Returns the type of the arrays of the current type, which is matched
+    /// by the array names of its Postgres types, such as `integer[]` and
+    /// `_integer`, if the current type is not an array itself.
+    ///
+    /// The arrays support the traits of their elements but `Copy`, and can
+    /// always be defaulted to an empty vector.
+    ///
+    /// # Arguments
+    ///
+    /// * `nullable_elements` - Whether the elements of the arrays are nullable,
+    ///   in which case they are mapped to `Vec<Option<T>>`.
+    pub(crate) fn array(&self, nullable_elements: bool) -> Option<ExternalType> {
+        if let syn::Type::Path(diesel_type) = &self.diesel_type
+            && diesel_type.path.segments.last().is_some_and(|segment| segment.ident == "Array")
+        {
+            return None;
+        }
+        let mut postgres_types = Vec::new();
+        for postgres_type in &self.postgres_types {
+            let internal_name = match postgres_type.rsplit_once('.') {
+                Some((schema, name)) => format!("{schema}._{name}"),
+                None => format!("_{postgres_type}"),
+            };
+            for array_name in [format!("{postgres_type}[]"), internal_name] {
+                if !postgres_types.contains(&array_name) {
+                    postgres_types.push(array_name);
+                }
+            }
+        }
+        let diesel_type = &self.diesel_type;
+        let rust_type = &self.rust_type;
+        let (diesel_type, rust_type): (syn::Type, syn::Type) = if nullable_elements {
+            (
+                syn::parse_quote!(diesel::sql_types::Array<diesel::sql_types::Nullable<#diesel_type>>),
+                syn::parse_quote!(Vec<Option<#rust_type>>),
+            )
+        } else {
+            (
+                syn::parse_quote!(diesel::sql_types::Array<#diesel_type>),
+                syn::parse_quote!(Vec<#rust_type>),
+            )
+        };
+        let builder =
+            ExternalType::new(diesel_type, rust_type).postgres_types(postgres_types).ok()?;
+        Some(
+            Trait::iter()
+                .filter(|core_trait| *core_trait != Trait::Copy && self.supports(*core_trait))
+                .fold(builder, ExternalTypeBuilder::supports_trait)
+                .supports_default()
+                .into(),
+        )
+    }
+
+    /// Returns the types of the ranges and multiranges whose bounds are of
+    /// the current type, such as `int4range` and `int4multirange` for the
+    /// type mapping `int4`.
+    ///
+    /// The ranges are mapped to diesel's `(Bound<T>, Bound<T>)`, which support
+    /// the traits of their bounds but the ordering and default ones, while
+    /// the multiranges are mapped to vectors of ranges.
+    pub(crate) fn ranges(&self) -> Vec<ExternalType> {
+        let diesel_type = &self.diesel_type;
+        let rust_type = &self.rust_type;
+        let range_rust_type: syn::Type =
+            syn::parse_quote!((::core::ops::Bound<#rust_type>, ::core::ops::Bound<#rust_type>));
+        let range_traits = Trait::iter().filter(|core_trait| {
+            !matches!(core_trait, Trait::Default | Trait::Ord | Trait::PartialOrd)
+                && self.supports(*core_trait)
+        });
+        let mut ranges = Vec::new();
+        for (range_name, multirange_name, _) in
+            RANGE_TYPES.iter().filter(|(_, _, bound_name)| self.is_compatible_with(bound_name))
+        {
+            ranges.push(
+                range_traits
+                    .clone()
+                    .fold(
+                        ExternalType::new(
+                            syn::parse_quote!(diesel::sql_types::Range<#diesel_type>),
+                            range_rust_type.clone(),
+                        )
+                        .postgres_type(range_name)
+                        .expect("The range names are lowercase"),
+                        ExternalTypeBuilder::supports_trait,
+                    )
+                    .into(),
+            );
+            ranges.push(
+                range_traits
+                    .clone()
+                    .filter(|core_trait| *core_trait != Trait::Copy)
+                    .fold(
+                        ExternalType::new(
+                            syn::parse_quote!(diesel::sql_types::Multirange<#diesel_type>),
+                            syn::parse_quote!(Vec<#range_rust_type>),
+                        )
+                        .postgres_type(multirange_name)
+                        .expect("The multirange names are lowercase"),
+                        ExternalTypeBuilder::supports_trait,
+                    )
+                    .supports_default()
+                    .into(),
+            );
+        }
+        ranges
+    }
+
+    /// Returns the type of the bounds of the current type, if it is a range.
+    pub(crate) fn range_bound_type(&self) -> Option<&syn::Type> {
+        let syn::Type::Tuple(tuple) = &self.rust_type else {
+            return None;
+        };
+        if tuple.elems.len() != 2 || tuple.elems[0] != tuple.elems[1] {
+            return None;
+        }
+        let syn::Type::Path(bound) = &tuple.elems[0] else {
+            return None;
+        };
+        let segment = bound.path.segments.last()?;
+        if segment.ident != "Bound" {
+            return None;
+        }
+        let syn::PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+            return None;
+        };
+        match arguments.args.first()? {
+            syn::GenericArgument::Type(bound_type) => Some(bound_type),
+            _ => None,
+        }
     }
 
     /// Casts a value to the external type.
     pub(crate) fn cast(&self, value: &str) -> Result<proc_macro2::TokenStream, syn::Error> {
-        let string_rust_type = self.rust_type.to_token_stream().to_string();
-
         // Reusable error factory for parse failures in the match arms.
         let mk_err = || {
             syn::Error::new_spanned(
@@ -93,67 +417,118 @@ impl ExternalType {
             )
         };
 
-        Ok(match string_rust_type.as_str() {
+        if let Some(base_type) = &self.base_type {
+            // Literals of validated types are cast to their base type, and
+            // converted with the `TryFrom` implementation of the validated
+            // type, as they are checked against its validation at generation
+            // time.
+            let rust_type = &self.rust_type;
+            let literal = sql_literal(value);
+            let casted = if base_type.to_token_stream().to_string() == "String" {
+                let literal = literal.as_ref();
+                quote! { ::std::string::String::from(#literal) }
+            } else {
+                Self::cast_to(base_type, &literal).ok_or_else(mk_err)?
+            };
+            return Ok(quote! {
+                <#rust_type>::try_from(#casted)
+                    .expect("The default value is validated at generation time")
+            });
+        }
+
+        if !self.literals.is_empty() {
+            // Literals such as `'happy'::mood` are stripped of their cast.
+            let literal = sql_literal(value);
+            return self
+                .literals
+                .iter()
+                .find(|(label, _)| *label == literal)
+                .map(|(_, expression)| expression.to_token_stream())
+                .ok_or_else(mk_err);
+        }
+
+        Self::cast_to(&self.rust_type, value).ok_or_else(mk_err)
+    }
+
+    /// Casts a value to the provided primitive type, returning `None` if the
+    /// type is not supported or the value cannot be parsed.
+    fn cast_to(rust_type: &syn::Type, value: &str) -> Option<proc_macro2::TokenStream> {
+        Some(match rust_type.to_token_stream().to_string().as_str() {
             "String" | "str" => {
                 quote! { #value }
             }
             "bool" => {
-                let casted: bool = value.parse().map_err(|_| mk_err())?;
+                let casted: bool = value.parse().ok()?;
                 quote! { #casted }
             }
-            // Numeric parsing arms. Keep them explicit (simple and clear)
-            // while still using the shared `mk_err` closure above to
-            // avoid duplicated error construction.
+            // Numeric parsing arms. Keep them explicit (simple and clear),
+            // leaving the error construction to the caller.
             "i16" => {
-                let casted: i16 = value.parse().map_err(|_| mk_err())?;
+                let casted: i16 = value.parse().ok()?;
                 quote! { #casted }
             }
             "u16" => {
-                let casted: u16 = value.parse().map_err(|_| mk_err())?;
+                let casted: u16 = value.parse().ok()?;
                 quote! { #casted }
             }
             "i32" => {
-                let casted: i32 = value.parse().map_err(|_| mk_err())?;
+                let casted: i32 = value.parse().ok()?;
                 quote! { #casted }
             }
             "u32" => {
-                let casted: u32 = value.parse().map_err(|_| mk_err())?;
+                let casted: u32 = value.parse().ok()?;
                 quote! { #casted }
             }
             "i64" => {
-                let casted: i64 = value.parse().map_err(|_| mk_err())?;
+                let casted: i64 = value.parse().ok()?;
                 quote! { #casted }
             }
             "u64" => {
-                let casted: u64 = value.parse().map_err(|_| mk_err())?;
+                let casted: u64 = value.parse().ok()?;
                 quote! { #casted }
             }
             "usize" => {
-                let casted: usize = value.parse().map_err(|_| mk_err())?;
+                let casted: usize = value.parse().ok()?;
                 quote! { #casted }
             }
             "isize" => {
-                let casted: isize = value.parse().map_err(|_| mk_err())?;
+                let casted: isize = value.parse().ok()?;
                 quote! { #casted }
             }
             "f32" => {
-                let casted: f32 = value.parse().map_err(|_| mk_err())?;
+                let casted: f32 = value.parse().ok()?;
                 quote! { #casted }
             }
             "f64" => {
-                let casted: f64 = value.parse().map_err(|_| mk_err())?;
+                let casted: f64 = value.parse().ok()?;
                 quote! { #casted }
             }
             "u128" => {
-                let casted: u128 = value.parse().map_err(|_| mk_err())?;
+                let casted: u128 = value.parse().ok()?;
                 quote! { #casted }
             }
             "i128" => {
-                let casted: i128 = value.parse().map_err(|_| mk_err())?;
+                let casted: i128 = value.parse().ok()?;
                 quote! { #casted }
             }
+            ":: bigdecimal :: BigDecimal" => {
+                // Decimal literals such as `'1.50'::numeric` are stripped of
+                // their cast and quotes, and parsed at runtime so that no
+                // precision is lost.
+                let literal = sql_literal(value);
+                let literal = literal.as_ref();
+                if literal.parse::<f64>().is_err()
+                    || !literal.chars().all(|c| c.is_ascii_digit() || "+-.eE".contains(c))
+                {
+                    return None;
+                }
+                quote! {
+                    <::bigdecimal::BigDecimal as ::core::str::FromStr>::from_str(#literal)
+                        .expect("The literal must be a valid decimal")
+                }
+            }
             _ => {
-                return Err(mk_err());
+                return None;
             }
         })
     }
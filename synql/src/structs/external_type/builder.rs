@@ -1,7 +1,7 @@
 //! Submodule providing a builder for the `ExternalType` struct.
 
 use crate::structs::{
-    ExternalType,
+    ExternalType, PostgresType,
     external_type::{Trait, traits_mask::TraitsMask},
 };
 
@@ -15,16 +15,33 @@ pub struct ExternalTypeBuilder {
     rust_type: syn::Type,
     /// The postgres types which are compatible with the diesel and rust types
     /// defined within the crate.
-    postgres_types: Vec<&'static str>,
+    postgres_types: Vec<String>,
+    /// The postgres types parsed into patterns, in the same order.
+    patterns: Vec<PostgresType>,
     /// Trait mask representing the traits supported by the current type.
     traits: TraitsMask,
+    /// The SQL literals which are cast to the provided Rust expressions.
+    literals: Vec<(String, syn::Expr)>,
+    /// The type the current type is validated from, if any.
+    base_type: Option<syn::Type>,
+    /// The expression evaluating to the current value of the type, if any.
+    current_value: Option<syn::Expr>,
 }
 
 impl ExternalTypeBuilder {
     /// Creates a new `ExternalTypeBuilder`.
     #[must_use]
     pub fn new(diesel_type: syn::Type, rust_type: syn::Type) -> Self {
-        Self { diesel_type, rust_type, postgres_types: Vec::new(), traits: TraitsMask::default() }
+        Self {
+            diesel_type,
+            rust_type,
+            postgres_types: Vec::new(),
+            patterns: Vec::new(),
+            traits: TraitsMask::default(),
+            literals: Vec::new(),
+            base_type: None,
+            current_value: None,
+        }
     }
 }
 
@@ -37,32 +54,104 @@ pub enum ExternalTypeBuilderError {
     #[error("The provided postgres type is not lowercase")]
     /// If the provided postgres type is not lowercase.
     NotLowercasePostgresType,
+    #[error("The provided postgres type is not a valid pattern")]
+    /// If the provided postgres type has no name, unbalanced parentheses or
+    /// wildcards which are not whole modifiers.
+    InvalidPostgresTypePattern,
 }
 
 impl ExternalTypeBuilder {
     /// Adds a postgres type which is compatible with the diesel and rust types
     /// defined within the crate.
     ///
+    /// The postgres type is a pattern over the parsed types: without
+    /// modifiers, such as `numeric`, it matches the type whatever its
+    /// modifiers, while with modifiers, such as `geography(point, *)`, it only
+    /// matches the same modifiers, where `*` matches any modifier.
+    ///
     /// # Arguments
     /// * `postgres_type` - The postgres type to add.
     ///
     /// # Errors
     ///
-    /// Returns an error if the postgres type is already present.
-    pub fn postgres_type(
-        mut self,
-        postgres_type: &'static str,
-    ) -> Result<Self, ExternalTypeBuilderError> {
-        if self.postgres_types.contains(&postgres_type) {
+    /// * Returns an error if the postgres type is already present.
+    /// * Returns an error if the postgres type is not lowercase.
+    /// * Returns an error if the postgres type is not a valid pattern.
+    pub fn postgres_type(mut self, postgres_type: &str) -> Result<Self, ExternalTypeBuilderError> {
+        let pattern = PostgresType::parse(postgres_type);
+        if self.patterns.contains(&pattern) {
             return Err(ExternalTypeBuilderError::DuplicatedPostgresType);
         }
         if postgres_type != postgres_type.to_lowercase() {
             return Err(ExternalTypeBuilderError::NotLowercasePostgresType);
         }
-        self.postgres_types.push(postgres_type);
+        if !pattern.is_valid_pattern()
+            || postgres_type.matches('(').count() != postgres_type.matches(')').count()
+        {
+            return Err(ExternalTypeBuilderError::InvalidPostgresTypePattern);
+        }
+        self.postgres_types.push(postgres_type.to_owned());
+        self.patterns.push(pattern);
         Ok(self)
     }
 
+    /// Adds a SQL literal which is cast to the provided Rust expression, such
+    /// as the label of an enum and the corresponding variant.
+    ///
+    /// # Arguments
+    /// * `sql_literal` - The SQL literal, without quotes nor cast.
+    /// * `expression` - The Rust expression the literal is cast to.
+    #[must_use]
+    pub fn literal(mut self, sql_literal: &str, expression: syn::Expr) -> Self {
+        self.literals.push((sql_literal.to_owned(), expression));
+        self
+    }
+
+    /// Sets the type the current type is validated from, such as the base
+    /// type of a domain, which the literals are cast to before being
+    /// converted with `TryFrom`.
+    ///
+    /// # Arguments
+    /// * `base_type` - The type the current type is validated from.
+    #[must_use]
+    pub fn base_type(mut self, base_type: syn::Type) -> Self {
+        self.base_type = Some(base_type);
+        self
+    }
+
+    /// Sets the expression evaluating to the current value of the type, such
+    /// as `::chrono::Utc::now()`, which the `CURRENT_TIMESTAMP`,
+    /// `CURRENT_DATE`, `CURRENT_TIME` and `now()` defaults and comparisons
+    /// are translated to.
+    ///
+    /// # Arguments
+    /// * `current_value` - The expression evaluating to the current value.
+    #[must_use]
+    pub fn current_value(mut self, current_value: syn::Expr) -> Self {
+        self.current_value = Some(current_value);
+        self
+    }
+
+    /// Sets that the current type supports the provided trait, alongside the
+    /// traits it implies.
+    ///
+    /// # Arguments
+    /// * `trait_ref` - The supported trait.
+    #[must_use]
+    pub fn supports_trait(self, trait_ref: Trait) -> Self {
+        match trait_ref {
+            Trait::Copy => self.supports_copy(),
+            Trait::Clone => self.supports_clone(),
+            Trait::Default => self.supports_default(),
+            Trait::Debug => self.supports_debug(),
+            Trait::Hash => self.supports_hash(),
+            Trait::Ord => self.supports_ord(),
+            Trait::PartialOrd => self.supports_partial_ord(),
+            Trait::Eq => self.supports_eq(),
+            Trait::PartialEq => self.supports_partial_eq(),
+        }
+    }
+
     /// Sets that the current type supports copy.
     #[must_use]
     pub fn supports_copy(mut self) -> Self {
@@ -142,12 +231,16 @@ impl ExternalTypeBuilder {
     /// # Errors
     ///
     /// Returns an error if any of the postgres types is already present.
-    pub fn postgres_types<I>(mut self, postgres_types: I) -> Result<Self, ExternalTypeBuilderError>
+    pub fn postgres_types<I, S>(
+        mut self,
+        postgres_types: I,
+    ) -> Result<Self, ExternalTypeBuilderError>
     where
-        I: IntoIterator<Item = &'static str>,
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
     {
         for postgres_type in postgres_types {
-            self = self.postgres_type(postgres_type)?;
+            self = self.postgres_type(postgres_type.as_ref())?;
         }
         Ok(self)
     }
@@ -159,7 +252,11 @@ impl From<ExternalTypeBuilder> for ExternalType {
             diesel_type: builder.diesel_type,
             rust_type: builder.rust_type,
             postgres_types: builder.postgres_types,
+            patterns: builder.patterns,
             traits: builder.traits,
+            literals: builder.literals,
+            base_type: builder.base_type,
+            current_value: builder.current_value,
         }
     }
 }
@@ -0,0 +1,98 @@
+//! Submodule providing a thread-safe representation of an `ExternalType`.
+//!
+//! The `syn` types describing an `ExternalType` are neither `Send` nor `Sync`,
+//! so they are stored as their textual representation and parsed back when
+//! the `ExternalType` is rebuilt, possibly in another thread.
+
+use quote::ToTokens;
+
+use super::{ExternalType, traits_mask::TraitsMask};
+use crate::structs::PostgresType;
+
+#[derive(Clone, Debug)]
+/// Thread-safe representation of an `ExternalType`.
+pub(crate) struct ExternalTypeSource {
+    /// Textual representation of the diesel type.
+    diesel_type: String,
+    /// Textual representation of the rust type.
+    rust_type: String,
+    /// The postgres types compatible with the type.
+    postgres_types: Vec<String>,
+    /// The postgres types parsed into patterns.
+    patterns: Vec<PostgresType>,
+    /// The traits supported by the type.
+    traits: TraitsMask,
+    /// The SQL literals alongside the textual representation of the Rust
+    /// expressions they are cast to.
+    literals: Vec<(String, String)>,
+    /// Textual representation of the type the type is validated from, if
+    /// any.
+    base_type: Option<String>,
+    /// Textual representation of the expression evaluating to the current
+    /// value of the type, if any.
+    current_value: Option<String>,
+}
+
+// Both conversions destructure their input exhaustively, so that adding a field
+// to the `ExternalType` fails to compile until it is carried by the source.
+impl From<&ExternalType> for ExternalTypeSource {
+    fn from(external_type: &ExternalType) -> Self {
+        let ExternalType {
+            diesel_type,
+            rust_type,
+            postgres_types,
+            patterns,
+            traits,
+            literals,
+            base_type,
+            current_value,
+        } = external_type;
+        Self {
+            diesel_type: diesel_type.to_token_stream().to_string(),
+            rust_type: rust_type.to_token_stream().to_string(),
+            postgres_types: postgres_types.clone(),
+            patterns: patterns.clone(),
+            traits: traits.clone(),
+            literals: literals
+                .iter()
+                .map(|(literal, expression)| {
+                    (literal.clone(), expression.to_token_stream().to_string())
+                })
+                .collect(),
+            base_type: base_type.as_ref().map(|base_type| base_type.to_token_stream().to_string()),
+            current_value: current_value
+                .as_ref()
+                .map(|current_value| current_value.to_token_stream().to_string()),
+        }
+    }
+}
+
+impl TryFrom<&ExternalTypeSource> for ExternalType {
+    type Error = syn::Error;
+
+    fn try_from(source: &ExternalTypeSource) -> Result<Self, Self::Error> {
+        let ExternalTypeSource {
+            diesel_type,
+            rust_type,
+            postgres_types,
+            patterns,
+            traits,
+            literals,
+            base_type,
+            current_value,
+        } = source;
+        Ok(Self {
+            diesel_type: syn::parse_str(diesel_type)?,
+            rust_type: syn::parse_str(rust_type)?,
+            postgres_types: postgres_types.clone(),
+            patterns: patterns.clone(),
+            traits: traits.clone(),
+            literals: literals
+                .iter()
+                .map(|(literal, expression)| Ok((literal.clone(), syn::parse_str(expression)?)))
+                .collect::<Result<_, syn::Error>>()?,
+            base_type: base_type.as_deref().map(syn::parse_str).transpose()?,
+            current_value: current_value.as_deref().map(syn::parse_str).transpose()?,
+        })
+    }
+}
@@ -41,6 +41,26 @@ impl<'workspace> ExternalTypeRef<'workspace> {
         self.type_ref.rust_type()
     }
 
+    /// Returns the expression evaluating to the current value of the type,
+    /// such as the current timestamp, if any.
+    #[inline]
+    #[must_use]
+    pub fn current_value(&self) -> Option<&'workspace syn::Expr> {
+        self.type_ref.current_value()
+    }
+
+    /// Returns the modifiers of the provided postgres type which are matched
+    /// by the type, such as the SRID of a `geography(point, 4326)`, if the
+    /// type is compatible with it.
+    ///
+    /// # Arguments
+    /// * `postgres_type` - The postgres type to match.
+    #[inline]
+    #[must_use]
+    pub fn matched_modifiers(&self, postgres_type: &str) -> Option<Vec<String>> {
+        self.type_ref.matched_modifiers(postgres_type)
+    }
+
     /// Returns a reference to the external crate.
     #[inline]
     #[must_use]
@@ -116,6 +136,27 @@ impl<'workspace> ExternalTypeRef<'workspace> {
         self.crate_name() == "rosetta-uuid"
     }
 
+    /// Returns the type of the bounds of the range, if the type is a range
+    /// such as an `int4range`.
+    #[must_use]
+    pub fn range_bound_type(&self) -> Option<ExternalTypeRef<'workspace>> {
+        self.external_crate.external_type(self.type_ref.range_bound_type()?)
+    }
+
+    /// Returns whether the type is a range or a multirange, whose diesel type
+    /// cannot be inferred from its Rust type made of bounds.
+    #[must_use]
+    pub fn is_range(&self) -> bool {
+        let syn::Type::Path(diesel_type) = self.diesel_type() else {
+            return false;
+        };
+        diesel_type
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "Range" || segment.ident == "Multirange")
+    }
+
     /// Casts a value to the external type.
     pub(crate) fn cast(&self, value: &str) -> Result<proc_macro2::TokenStream, syn::Error> {
         self.type_ref.cast(value)
@@ -0,0 +1,286 @@
+//! Submodule defining the `GeneratedWorkspace` struct, an in-memory
+//! representation of the files composing a generated workspace.
+
+use std::{
+    collections::BTreeMap,
+    path::{Component, Path, PathBuf},
+};
+
+use proc_macro2::TokenStream;
+
+use crate::{
+    structs::{FileStatistics, GenerationWarning},
+    utils::format_rust_code,
+};
+
+mod manifest;
+use manifest::{Manifest, content_hash};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+/// In-memory representation of a generated workspace, mapping the path of
+/// each generated file, relative to the workspace root, to its contents.
+pub struct GeneratedWorkspace {
+    /// Contents of the generated files, sorted by their relative path.
+    files: BTreeMap<PathBuf, String>,
+    /// Constructs which were skipped in lenient mode while generating the
+    /// files.
+    warnings: Vec<GenerationWarning>,
+}
+
+/// Normalizes the provided relative path, dropping any `.` component so that
+/// `./crate/Cargo.toml` and `crate/Cargo.toml` refer to the same file.
+fn normalize(path: &Path) -> PathBuf {
+    path.components().filter(|component| !matches!(component, Component::CurDir)).collect()
+}
+
+impl GeneratedWorkspace {
+    /// Creates a new empty `GeneratedWorkspace`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inserts a file into the generated workspace, returning the previous
+    /// contents if a file at the same path was already present.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the file, relative to the workspace root.
+    /// * `contents` - The contents of the file.
+    pub fn insert<P: AsRef<Path>>(&mut self, path: P, contents: String) -> Option<String> {
+        self.files.insert(normalize(path.as_ref()), contents)
+    }
+
+    /// Inserts a Rust source file into the generated workspace, pretty-printing
+    /// the provided code.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the file, relative to the workspace root.
+    /// * `code` - The token stream representing the contents of the file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the provided code cannot be parsed as a Rust file.
+    pub(crate) fn insert_rust<P: AsRef<Path>>(
+        &mut self,
+        path: P,
+        code: TokenStream,
+    ) -> Result<(), crate::Error> {
+        let path = path.as_ref();
+        let contents = format_rust_code(code).map_err(|error| {
+            crate::Error::InvalidGeneratedCode { path: normalize(path), reason: error.to_string() }
+        })?;
+        self.insert(path, contents);
+        Ok(())
+    }
+
+    /// Returns the contents of the file at the provided relative path, if
+    /// any.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the file, relative to the workspace root.
+    #[must_use]
+    pub fn get<P: AsRef<Path>>(&self, path: P) -> Option<&str> {
+        self.files.get(&normalize(path.as_ref())).map(String::as_str)
+    }
+
+    /// Returns whether a file exists at the provided relative path.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the file, relative to the workspace root.
+    #[must_use]
+    pub fn contains<P: AsRef<Path>>(&self, path: P) -> bool {
+        self.files.contains_key(&normalize(path.as_ref()))
+    }
+
+    /// Returns the number of generated files.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.files.len()
+    }
+
+    /// Returns whether no file has been generated.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+
+    /// Iterates over the relative paths of the generated files, in order.
+    pub fn paths(&self) -> impl Iterator<Item = &Path> {
+        self.files.keys().map(PathBuf::as_path)
+    }
+
+    /// Iterates over the relative paths and contents of the generated files,
+    /// in order.
+    pub fn files(&self) -> impl Iterator<Item = (&Path, &str)> {
+        self.files.iter().map(|(path, contents)| (path.as_path(), contents.as_str()))
+    }
+
+    /// Returns the constructs which were skipped in lenient mode while
+    /// generating the files, such as the check constraints which could not
+    /// be translated.
+    #[inline]
+    #[must_use]
+    pub fn warnings(&self) -> &[GenerationWarning] {
+        &self.warnings
+    }
+
+    /// Records the provided constructs as skipped while generating the files.
+    ///
+    /// # Arguments
+    ///
+    /// * `warnings` - The constructs which were skipped.
+    pub(crate) fn extend_warnings<I: IntoIterator<Item = GenerationWarning>>(
+        &mut self,
+        warnings: I,
+    ) {
+        self.warnings.extend(warnings);
+    }
+
+    /// Returns the files of a previous generation in the provided directory
+    /// which were modified by hand since they were generated, and which would
+    /// therefore be overwritten or deleted by writing this workspace.
+    ///
+    /// Generated files are detected as modified by comparing their contents
+    /// with the hash recorded in the manifest at the root of the workspace.
+    /// Files whose contents already match the newly generated contents are
+    /// not reported, as writing the workspace would not alter them.
+    ///
+    /// # Arguments
+    ///
+    /// * `root` - The directory where the workspace was previously written.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the manifest or any of the files cannot be read.
+    pub fn hand_edited_files(&self, root: &Path) -> Result<Vec<PathBuf>, crate::Error> {
+        let manifest = Manifest::load(root)?;
+        let mut hand_edited_files = Vec::new();
+        for (relative_path, hash) in manifest.hashes() {
+            let existing = match std::fs::read(root.join(relative_path)) {
+                Ok(existing) => existing,
+                Err(error) if error.kind() == std::io::ErrorKind::NotFound => continue,
+                Err(error) => return Err(error.into()),
+            };
+            if content_hash(&existing) == hash
+                || self.get(relative_path).is_some_and(|contents| contents.as_bytes() == existing)
+            {
+                continue;
+            }
+            hand_edited_files.push(relative_path.to_path_buf());
+        }
+        Ok(hand_edited_files)
+    }
+
+    /// Writes the generated files to disk, creating any missing directory.
+    ///
+    /// Files whose contents on disk already match the generated contents are
+    /// left untouched, so that their modification time is preserved and cargo
+    /// does not needlessly rebuild the corresponding crates.
+    ///
+    /// The list of generated files and the hash of their contents are recorded
+    /// in a manifest at the root of the workspace: files listed in the manifest
+    /// of a previous run which are no longer generated, such as the crates of
+    /// tables which were dropped from the schema, are deleted alongside any
+    /// directory left empty. Files which were not generated by SynQL are never
+    /// touched.
+    ///
+    /// # Arguments
+    ///
+    /// * `root` - The directory where the workspace is written.
+    ///
+    /// # Errors
+    ///
+    /// * Returns [`HandEditedFiles`](crate::Error::HandEditedFiles) if any of
+    ///   the previously generated files was modified by hand, in which case
+    ///   nothing is written. Use [`overwrite`](Self::overwrite) to discard the
+    ///   manual changes.
+    /// * Returns an error if any of the files cannot be written or deleted.
+    pub fn write(&self, root: &Path) -> Result<FileStatistics, crate::Error> {
+        let hand_edited_files = self.hand_edited_files(root)?;
+        if !hand_edited_files.is_empty() {
+            return Err(crate::Error::HandEditedFiles(hand_edited_files));
+        }
+        self.overwrite(root)
+    }
+
+    /// Writes the generated files to disk as [`write`](Self::write) does, but
+    /// overwrites or deletes previously generated files even when they were
+    /// modified by hand.
+    ///
+    /// # Arguments
+    ///
+    /// * `root` - The directory where the workspace is written.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the files cannot be written or deleted.
+    pub fn overwrite(&self, root: &Path) -> Result<FileStatistics, crate::Error> {
+        let mut statistics = FileStatistics::default();
+        let previous_manifest = Manifest::load(root)?;
+
+        for (relative_path, contents) in &self.files {
+            let path = root.join(relative_path);
+            if std::fs::read(&path).is_ok_and(|existing| existing == contents.as_bytes()) {
+                statistics.record_unchanged();
+                continue;
+            }
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(path, contents)?;
+            statistics.record_written();
+        }
+
+        for stale_path in previous_manifest.paths().filter(|path| !self.contains(path)) {
+            let path = root.join(stale_path);
+            match std::fs::remove_file(&path) {
+                Ok(()) => statistics.record_deleted(1),
+                Err(error) if error.kind() == std::io::ErrorKind::NotFound => continue,
+                Err(error) => return Err(error.into()),
+            }
+            remove_empty_ancestors(root, &path)?;
+        }
+
+        Manifest::new(self.files()).store(root)?;
+
+        Ok(statistics)
+    }
+}
+
+impl Extend<(PathBuf, String)> for GeneratedWorkspace {
+    fn extend<I: IntoIterator<Item = (PathBuf, String)>>(&mut self, files: I) {
+        for (path, contents) in files {
+            self.insert(path, contents);
+        }
+    }
+}
+
+impl IntoIterator for GeneratedWorkspace {
+    type Item = (PathBuf, String);
+    type IntoIter = std::collections::btree_map::IntoIter<PathBuf, String>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.files.into_iter()
+    }
+}
+
+/// Removes the ancestors of the provided path which were left empty, stopping
+/// at the workspace root.
+fn remove_empty_ancestors(root: &Path, path: &Path) -> std::io::Result<()> {
+    for ancestor in path.ancestors().skip(1) {
+        if ancestor == root || !ancestor.starts_with(root) {
+            break;
+        }
+        if std::fs::read_dir(ancestor)?.next().is_some() {
+            break;
+        }
+        std::fs::remove_dir(ancestor)?;
+    }
+    Ok(())
+}
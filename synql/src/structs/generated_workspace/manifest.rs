@@ -0,0 +1,140 @@
+//! Submodule defining the manifest recording which files of a workspace were
+//! generated by SynQL, alongside a hash of their contents, so that stale files
+//! can be pruned and hand-edited files detected on regeneration.
+
+use std::{
+    collections::BTreeMap,
+    path::{Component, Path, PathBuf},
+};
+
+/// Name of the manifest file, stored at the root of the workspace.
+pub(super) const MANIFEST_FILE_NAME: &str = ".synql-manifest";
+
+/// Header of the manifest file.
+const MANIFEST_HEADER: &str = "# Files generated by SynQL. This file is maintained automatically.";
+
+/// Computes the 64-bit FNV-1a hash of the provided contents.
+///
+/// The hash is only used to detect changes to generated files, so a simple
+/// and stable non-cryptographic hash is sufficient.
+pub(super) fn content_hash(contents: &[u8]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+    contents.iter().fold(OFFSET_BASIS, |hash, byte| (hash ^ u64::from(*byte)).wrapping_mul(PRIME))
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+/// Manifest of the files generated in a workspace.
+pub(super) struct Manifest {
+    /// Paths of the generated files, relative to the workspace root, mapped
+    /// to the hash of their contents when known.
+    files: BTreeMap<PathBuf, Option<u64>>,
+}
+
+impl Manifest {
+    /// Creates a manifest listing the provided relative paths and contents.
+    pub(super) fn new<'a>(files: impl Iterator<Item = (&'a Path, &'a str)>) -> Self {
+        Self {
+            files: files
+                .map(|(path, contents)| {
+                    (path.to_path_buf(), Some(content_hash(contents.as_bytes())))
+                })
+                .collect(),
+        }
+    }
+
+    /// Loads the manifest stored in the provided workspace root, returning an
+    /// empty manifest if none exists.
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if the manifest exists but cannot be read.
+    /// * Returns [`InvalidManifestPath`](crate::Error::InvalidManifestPath) if
+    ///   the manifest lists a path which is not made only of normal components,
+    ///   such as an absolute path or one containing `..`.
+    pub(super) fn load(root: &Path) -> Result<Self, crate::Error> {
+        let contents = match std::fs::read_to_string(root.join(MANIFEST_FILE_NAME)) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
+                return Ok(Self::default());
+            }
+            Err(error) => return Err(error.into()),
+        };
+        let files = contents
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(|line| {
+                // Each line is either `<hash> <path>` or, for manifests written
+                // before hashes were recorded, a bare `<path>`.
+                let (hash, path) = match line.split_once(' ') {
+                    Some((hash, path)) => {
+                        match u64::from_str_radix(hash, 16) {
+                            Ok(hash) => (Some(hash), path),
+                            Err(_) => (None, line),
+                        }
+                    }
+                    None => (None, line),
+                };
+                // The stale paths are deleted from the workspace, so only the
+                // paths made of normal components, which cannot escape it, are
+                // accepted.
+                let is_normal = |segment: &str| {
+                    let mut components = Path::new(segment).components();
+                    matches!(components.next(), Some(Component::Normal(_)))
+                        && components.next().is_none()
+                };
+                if !path.split('/').all(is_normal) {
+                    return Err(crate::Error::InvalidManifestPath(PathBuf::from(path)));
+                }
+                let path = path.split('/').collect::<PathBuf>();
+                Ok((path, hash))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { files })
+    }
+
+    /// Iterates over the relative paths listed in the manifest.
+    pub(super) fn paths(&self) -> impl Iterator<Item = &Path> {
+        self.files.keys().map(PathBuf::as_path)
+    }
+
+    /// Iterates over the relative paths listed in the manifest alongside the
+    /// hash of the contents they were generated with, when known.
+    pub(super) fn hashes(&self) -> impl Iterator<Item = (&Path, u64)> {
+        self.files.iter().filter_map(|(path, hash)| hash.map(|hash| (path.as_path(), hash)))
+    }
+
+    /// Returns the textual representation of the manifest.
+    pub(super) fn contents(&self) -> String {
+        let mut contents = String::from(MANIFEST_HEADER);
+        contents.push('\n');
+        for (path, hash) in &self.files {
+            let components = path
+                .components()
+                .map(|component| component.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>();
+            if let Some(hash) = hash {
+                contents.push_str(&format!("{hash:016x} "));
+            }
+            contents.push_str(&components.join("/"));
+            contents.push('\n');
+        }
+        contents
+    }
+
+    /// Stores the manifest in the provided workspace root.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the manifest cannot be written.
+    pub(super) fn store(&self, root: &Path) -> std::io::Result<()> {
+        let path = root.join(MANIFEST_FILE_NAME);
+        let contents = self.contents();
+        if std::fs::read(&path).is_ok_and(|existing| existing == contents.as_bytes()) {
+            return Ok(());
+        }
+        std::fs::create_dir_all(root)?;
+        std::fs::write(path, contents)
+    }
+}
@@ -0,0 +1,126 @@
+//! Submodule defining the `GenerationReport` struct, summarizing the outcome
+//! of a workspace generation.
+
+use std::fmt::Display;
+
+use time_requirements::prelude::TimeTracker;
+
+use crate::structs::GenerationWarning;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+/// Counts of the files touched while writing a generated workspace to disk.
+pub struct FileStatistics {
+    /// Number of files whose contents were written to disk.
+    written: usize,
+    /// Number of files which were left untouched as their contents did not
+    /// change.
+    unchanged: usize,
+    /// Number of files which were deleted from disk.
+    deleted: usize,
+}
+
+impl FileStatistics {
+    /// Returns the number of files whose contents were written to disk.
+    #[inline]
+    #[must_use]
+    pub fn written(&self) -> usize {
+        self.written
+    }
+
+    /// Returns the number of files which were left untouched as their
+    /// contents did not change.
+    #[inline]
+    #[must_use]
+    pub fn unchanged(&self) -> usize {
+        self.unchanged
+    }
+
+    /// Returns the number of files which were deleted from disk.
+    #[inline]
+    #[must_use]
+    pub fn deleted(&self) -> usize {
+        self.deleted
+    }
+
+    /// Records a file whose contents were written to disk.
+    pub(crate) fn record_written(&mut self) {
+        self.written += 1;
+    }
+
+    /// Records a file which was left untouched.
+    pub(crate) fn record_unchanged(&mut self) {
+        self.unchanged += 1;
+    }
+
+    /// Records several files which were deleted from disk.
+    pub(crate) fn record_deleted(&mut self, number_of_files: usize) {
+        self.deleted += number_of_files;
+    }
+}
+
+impl Display for FileStatistics {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} written, {} unchanged, {} deleted",
+            self.written, self.unchanged, self.deleted
+        )
+    }
+}
+
+/// Report of a workspace generation, including the time spent in each task,
+/// the files touched on disk and the constructs skipped in lenient mode.
+pub struct GenerationReport {
+    /// Time spent in each of the generation tasks.
+    time_tracker: TimeTracker,
+    /// Counts of the files touched on disk.
+    file_statistics: FileStatistics,
+    /// Constructs which were skipped in lenient mode.
+    warnings: Vec<GenerationWarning>,
+}
+
+impl GenerationReport {
+    /// Creates a new `GenerationReport`.
+    pub(crate) fn new(
+        time_tracker: TimeTracker,
+        file_statistics: FileStatistics,
+        warnings: Vec<GenerationWarning>,
+    ) -> Self {
+        Self { time_tracker, file_statistics, warnings }
+    }
+
+    /// Returns the time tracker recording the generation tasks.
+    #[inline]
+    #[must_use]
+    pub fn time_tracker(&self) -> &TimeTracker {
+        &self.time_tracker
+    }
+
+    /// Consumes the report, returning the time tracker.
+    #[inline]
+    #[must_use]
+    pub fn into_time_tracker(self) -> TimeTracker {
+        self.time_tracker
+    }
+
+    /// Returns the counts of the files touched on disk.
+    #[inline]
+    #[must_use]
+    pub fn file_statistics(&self) -> FileStatistics {
+        self.file_statistics
+    }
+
+    /// Returns the constructs which were skipped in lenient mode, such as the
+    /// excluded tables and the dropped check constraints.
+    #[inline]
+    #[must_use]
+    pub fn warnings(&self) -> &[GenerationWarning] {
+        &self.warnings
+    }
+}
+
+impl From<GenerationReport> for TimeTracker {
+    fn from(report: GenerationReport) -> Self {
+        report.into_time_tracker()
+    }
+}
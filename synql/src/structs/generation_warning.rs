@@ -0,0 +1,152 @@
+//! Submodule defining the `GenerationWarning` enum, describing the constructs
+//! skipped while generating a workspace in lenient mode.
+
+use std::fmt::Display;
+
+use crate::structs::CheckConstraintErrorKind;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Construct which was skipped or altered while generating a workspace.
+pub enum GenerationWarning {
+    /// A table was excluded as one of its columns has a type which is not
+    /// provided by any of the external crates.
+    UnmappableColumnType {
+        /// Name of the excluded table.
+        table_name: String,
+        /// Name of the column whose type could not be mapped.
+        column_name: String,
+        /// SQL type of the column.
+        sql_type: String,
+    },
+    /// A table was excluded as one of its columns is a multi-dimensional
+    /// array, which is not supported.
+    MultiDimensionalArray {
+        /// Name of the excluded table.
+        table_name: String,
+        /// Name of the multi-dimensional array column.
+        column_name: String,
+        /// SQL type of the column.
+        sql_type: String,
+    },
+    /// A table was excluded as it depends on a table which was excluded.
+    ExcludedDependency {
+        /// Name of the excluded table.
+        table_name: String,
+        /// Name of the excluded table it depends on.
+        dependency: String,
+    },
+    /// A check constraint could not be translated, and was dropped from the
+    /// validation of the column.
+    DroppedCheckConstraint {
+        /// Name of the table the constraint belongs to.
+        table_name: String,
+        /// The check constraint which was dropped.
+        constraint: String,
+        /// The sub-expression which could not be translated.
+        sub_expression: String,
+        /// Why the sub-expression could not be translated.
+        reason: CheckConstraintErrorKind,
+    },
+    /// A check of a domain could not be translated, and was dropped from the
+    /// validation of the corresponding newtype.
+    DroppedDomainCheck {
+        /// Name of the domain the check belongs to.
+        domain: String,
+        /// The check which was dropped.
+        check: String,
+        /// The sub-expression which could not be translated.
+        sub_expression: String,
+        /// Why the sub-expression could not be translated.
+        reason: CheckConstraintErrorKind,
+    },
+    /// The default value of a column was rejected, and omitted from the
+    /// generated struct.
+    DroppedDefaultValue {
+        /// Name of the table the column belongs to.
+        table_name: String,
+        /// Name of the column whose default value was omitted.
+        column_name: String,
+        /// The SQL default value which was omitted.
+        default_value: String,
+        /// Why the default value was rejected.
+        reason: String,
+    },
+    /// An item was renamed as its identifier collided with the one of another
+    /// item.
+    DisambiguatedIdentifier {
+        /// Description of the renamed item.
+        renamed: String,
+        /// Description of the item it collided with.
+        other: String,
+        /// The colliding identifier.
+        identifier: String,
+        /// The identifier the item was renamed to.
+        disambiguated: String,
+    },
+    /// A postgres type is provided by several crates with the same priority,
+    /// and was mapped to the first of them.
+    AmbiguousPostgresType {
+        /// The ambiguous postgres type.
+        postgres_type: String,
+        /// Names of the crates providing the type, in their registration
+        /// order.
+        crates: Vec<String>,
+    },
+}
+
+impl Display for GenerationWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnmappableColumnType { table_name, column_name, sql_type } => {
+                write!(
+                    f,
+                    "Excluded table `{table_name}`: no external crate provides the type `{sql_type}` of column `{column_name}`"
+                )
+            }
+            Self::MultiDimensionalArray { table_name, column_name, sql_type } => {
+                write!(
+                    f,
+                    "Excluded table `{table_name}`: column `{column_name}` has the unsupported multi-dimensional array type `{sql_type}`"
+                )
+            }
+            Self::ExcludedDependency { table_name, dependency } => {
+                write!(
+                    f,
+                    "Excluded table `{table_name}`: it depends on excluded table `{dependency}`"
+                )
+            }
+            Self::DroppedCheckConstraint { table_name, constraint, sub_expression, reason } => {
+                write!(
+                    f,
+                    "Dropped check constraint `{constraint}` of table `{table_name}`: cannot translate `{sub_expression}`: {reason}"
+                )
+            }
+            Self::DroppedDomainCheck { domain, check, sub_expression, reason } => {
+                write!(
+                    f,
+                    "Dropped check `{check}` of domain `{domain}`: cannot translate `{sub_expression}`: {reason}"
+                )
+            }
+            Self::DroppedDefaultValue { table_name, column_name, default_value, reason } => {
+                write!(
+                    f,
+                    "Omitted the default value `{default_value}` of column `{column_name}` of table `{table_name}`: {reason}"
+                )
+            }
+            Self::DisambiguatedIdentifier { renamed, other, identifier, disambiguated } => {
+                write!(
+                    f,
+                    "Renamed the {renamed} to `{disambiguated}`: `{identifier}` is already used by the {other}"
+                )
+            }
+            Self::AmbiguousPostgresType { postgres_type, crates } => {
+                write!(
+                    f,
+                    "Mapped the postgres type `{postgres_type}` to the `{}` crate: it is also provided by the `{}` crates with the same priority",
+                    crates[0],
+                    crates[1..].join("`, `")
+                )
+            }
+        }
+    }
+}
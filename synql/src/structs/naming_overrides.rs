@@ -0,0 +1,220 @@
+//! Submodule defining the `NamingOverrides` struct, a naming strategy with
+//! explicit overrides for specific tables and columns.
+
+use std::collections::BTreeMap;
+
+use crate::{structs::DefaultNamingStrategy, traits::NamingStrategy};
+
+/// Key of the overridden names of a table, made of its schema, if any, and
+/// its SQL name.
+type TableKey = (Option<String>, String);
+
+#[derive(Debug, Clone, Default)]
+/// Naming strategy applying explicit overrides for specific tables and
+/// columns, and deferring to a base strategy for all other names.
+///
+/// The overrides of the tables and columns are keyed by the schema of the
+/// table, so that homonymous tables of different schemas, such as
+/// `public.users` and `auth.users`, can be named independently. An override
+/// registered without a schema applies to the tables of every schema which
+/// have no override of their own.
+///
+/// # Example
+///
+/// ```rust
+/// use synql::prelude::*;
+///
+/// let naming = NamingOverrides::default()
+///     .rename_struct(None, "data", "Datum")
+///     .rename_struct(Some("auth"), "users", "Account")
+///     .rename_field(None, "users", "FirstName", "given_name")
+///     .rename_crate(Some("auth"), "users", "auth-accounts");
+/// assert_eq!(naming.struct_name(None, "data"), "Datum");
+/// assert_eq!(naming.struct_name(Some("public"), "data"), "Datum");
+/// assert_eq!(naming.struct_name(Some("auth"), "users"), "Account");
+/// assert_eq!(naming.struct_name(Some("public"), "users"), "User");
+/// assert_eq!(naming.field_name(Some("auth"), "users", "FirstName"), "given_name");
+/// assert_eq!(naming.field_name(None, "users", "LastName"), "last_name");
+/// assert_eq!(naming.crate_name("db", Some("auth"), "users", "auth_users"), "auth-accounts");
+/// assert_eq!(naming.crate_name("db", Some("public"), "users", "users"), "db-users");
+/// ```
+pub struct NamingOverrides<S = DefaultNamingStrategy> {
+    /// Strategy used for the names which are not overridden.
+    base: S,
+    /// Overridden table names, keyed by the schema and SQL name of the table.
+    table_names: BTreeMap<TableKey, String>,
+    /// Overridden crate names, keyed by the schema and SQL name of the table.
+    crate_names: BTreeMap<TableKey, String>,
+    /// Overridden struct names, keyed by the schema and SQL name of the table.
+    struct_names: BTreeMap<TableKey, String>,
+    /// Overridden field names, keyed by the schema and SQL name of the table
+    /// and the SQL name of the column.
+    field_names: BTreeMap<(TableKey, String), String>,
+}
+
+/// Returns the key of the provided table.
+///
+/// # Arguments
+///
+/// * `table_schema` - The schema of the table, if any.
+/// * `table_name` - The SQL name of the table.
+fn table_key(table_schema: Option<&str>, table_name: &str) -> TableKey {
+    (table_schema.map(str::to_owned), table_name.to_owned())
+}
+
+/// Returns the override of the provided table, falling back to the override
+/// registered without a schema.
+///
+/// # Arguments
+///
+/// * `overrides` - The overrides, keyed by table.
+/// * `table_schema` - The schema of the table, if any.
+/// * `key` - Function building the key of the override from a table key.
+fn lookup<'a, K: Ord>(
+    overrides: &'a BTreeMap<K, String>,
+    table_schema: Option<&str>,
+    key: impl Fn(Option<&str>) -> K,
+) -> Option<&'a String> {
+    overrides
+        .get(&key(table_schema))
+        .or_else(|| table_schema.and_then(|_| overrides.get(&key(None))))
+}
+
+impl<S: NamingStrategy> NamingOverrides<S> {
+    /// Creates new `NamingOverrides` deferring to the provided strategy for
+    /// the names which are not overridden.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The strategy used for the names which are not overridden.
+    #[must_use]
+    pub fn with_base(base: S) -> Self {
+        Self {
+            base,
+            table_names: BTreeMap::new(),
+            crate_names: BTreeMap::new(),
+            struct_names: BTreeMap::new(),
+            field_names: BTreeMap::new(),
+        }
+    }
+
+    /// Overrides the snake-cased name of a table, used to name its crate.
+    ///
+    /// # Arguments
+    ///
+    /// * `table_schema` - The schema of the table, or `None` to override the
+    ///   tables of every schema.
+    /// * `table_name` - The SQL name of the table.
+    /// * `name` - The name to use instead.
+    #[must_use]
+    pub fn rename_table(
+        mut self,
+        table_schema: Option<&str>,
+        table_name: &str,
+        name: &str,
+    ) -> Self {
+        self.table_names.insert(table_key(table_schema, table_name), name.to_owned());
+        self
+    }
+
+    /// Overrides the name of the crate of a table.
+    ///
+    /// # Arguments
+    ///
+    /// * `table_schema` - The schema of the table, or `None` to override the
+    ///   tables of every schema.
+    /// * `table_name` - The SQL name of the table.
+    /// * `name` - The crate name to use instead.
+    #[must_use]
+    pub fn rename_crate(
+        mut self,
+        table_schema: Option<&str>,
+        table_name: &str,
+        name: &str,
+    ) -> Self {
+        self.crate_names.insert(table_key(table_schema, table_name), name.to_owned());
+        self
+    }
+
+    /// Overrides the name of the struct representing a row of a table.
+    ///
+    /// # Arguments
+    ///
+    /// * `table_schema` - The schema of the table, or `None` to override the
+    ///   tables of every schema.
+    /// * `table_name` - The SQL name of the table.
+    /// * `name` - The struct name to use instead.
+    #[must_use]
+    pub fn rename_struct(
+        mut self,
+        table_schema: Option<&str>,
+        table_name: &str,
+        name: &str,
+    ) -> Self {
+        self.struct_names.insert(table_key(table_schema, table_name), name.to_owned());
+        self
+    }
+
+    /// Overrides the name of the field representing a column.
+    ///
+    /// # Arguments
+    ///
+    /// * `table_schema` - The schema of the table of the column, or `None` to
+    ///   override the tables of every schema.
+    /// * `table_name` - The SQL name of the table of the column.
+    /// * `column_name` - The SQL name of the column.
+    /// * `name` - The field name to use instead.
+    #[must_use]
+    pub fn rename_field(
+        mut self,
+        table_schema: Option<&str>,
+        table_name: &str,
+        column_name: &str,
+        name: &str,
+    ) -> Self {
+        self.field_names
+            .insert((table_key(table_schema, table_name), column_name.to_owned()), name.to_owned());
+        self
+    }
+}
+
+impl<S: NamingStrategy> NamingStrategy for NamingOverrides<S> {
+    fn table_name(&self, table_schema: Option<&str>, table_name: &str) -> String {
+        lookup(&self.table_names, table_schema, |schema| table_key(schema, table_name))
+            .cloned()
+            .unwrap_or_else(|| self.base.table_name(table_schema, table_name))
+    }
+
+    fn crate_name(
+        &self,
+        workspace_name: &str,
+        table_schema: Option<&str>,
+        table_name: &str,
+        qualified_name: &str,
+    ) -> String {
+        lookup(&self.crate_names, table_schema, |schema| table_key(schema, table_name))
+            .cloned()
+            .unwrap_or_else(|| {
+                self.base.crate_name(workspace_name, table_schema, table_name, qualified_name)
+            })
+    }
+
+    fn struct_name(&self, table_schema: Option<&str>, table_name: &str) -> String {
+        lookup(&self.struct_names, table_schema, |schema| table_key(schema, table_name))
+            .cloned()
+            .unwrap_or_else(|| self.base.struct_name(table_schema, table_name))
+    }
+
+    fn field_name(
+        &self,
+        table_schema: Option<&str>,
+        table_name: &str,
+        column_name: &str,
+    ) -> String {
+        lookup(&self.field_names, table_schema, |schema| {
+            (table_key(schema, table_name), column_name.to_owned())
+        })
+        .cloned()
+        .unwrap_or_else(|| self.base.field_name(table_schema, table_name, column_name))
+    }
+}
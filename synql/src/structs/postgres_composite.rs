@@ -0,0 +1,357 @@
+//! Submodule defining the `PostgresComposite` struct, describing a composite
+//! type declared with `CREATE TYPE ... AS (...)`.
+
+use heck::{ToSnakeCase, ToUpperCamelCase};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use strum::IntoEnumIterator;
+use syn::{Ident, visit_mut::VisitMut};
+
+use crate::{
+    structs::{ExternalType, ExternalTypeRef, Trait, Workspace, external_type::without_modifiers},
+    utils::sanitized_ident,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// Composite type declared with `CREATE TYPE address AS (street text, city
+/// text)`, which is generated as a Rust struct in the types crate of the
+/// workspace.
+///
+/// As the attributes of a composite type are always nullable, the fields of
+/// the struct are optional. The struct implements the diesel traits of its
+/// SQL type over the corresponding `Record`, alongside serde and the core
+/// derives supported by all of its attributes. Arrays of the composite type
+/// are mapped to vectors of the struct.
+pub struct PostgresComposite {
+    /// The schema of the type, if any.
+    schema: Option<String>,
+    /// The name of the type.
+    name: String,
+    /// The names and normalized SQL types of the attributes, in their
+    /// declaration order.
+    attributes: Vec<(String, String)>,
+}
+
+/// Rewrites the paths of the types provided by the types crate into paths
+/// relative to the crate root, as they are used within the types crate itself.
+struct LocalPaths<'a> {
+    /// The ident of the types crate.
+    crate_ident: &'a Ident,
+}
+
+impl VisitMut for LocalPaths<'_> {
+    fn visit_path_mut(&mut self, path: &mut syn::Path) {
+        if path.leading_colon.is_some()
+            && path.segments.first().is_some_and(|segment| segment.ident == *self.crate_ident)
+        {
+            path.leading_colon = None;
+            path.segments[0].ident = format_ident!("crate");
+        }
+        syn::visit_mut::visit_path_mut(self, path);
+    }
+}
+
+impl PostgresComposite {
+    /// Creates a new `PostgresComposite`.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the type.
+    /// * `attributes` - The names and SQL types of the attributes, in their
+    ///   declaration order. The modifiers of the types, such as the length of a
+    ///   `varchar(20)[]` or the precision of a `time(0) with time zone`, are
+    ///   ignored.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use synql::prelude::*;
+    ///
+    /// let composite = PostgresComposite::new(
+    ///     "shift",
+    ///     [("tags", "VARCHAR(20)[]"), ("starts_at", "TIME(0) WITH TIME ZONE")],
+    /// );
+    /// assert_eq!(
+    ///     composite.attributes(),
+    ///     [
+    ///         ("tags".to_owned(), "varchar[]".to_owned()),
+    ///         ("starts_at".to_owned(), "time with time zone".to_owned()),
+    ///     ]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn new<I, N, T>(name: &str, attributes: I) -> Self
+    where
+        I: IntoIterator<Item = (N, T)>,
+        N: ToString,
+        T: AsRef<str>,
+    {
+        Self {
+            schema: None,
+            name: name.to_string(),
+            attributes: attributes
+                .into_iter()
+                .map(|(attribute_name, data_type)| {
+                    let data_type = without_modifiers(data_type.as_ref().trim()).to_lowercase();
+                    (attribute_name.to_string(), data_type)
+                })
+                .collect(),
+        }
+    }
+
+    /// Sets the schema of the type.
+    ///
+    /// # Arguments
+    ///
+    /// * `schema` - The schema of the type.
+    #[must_use]
+    pub fn schema(mut self, schema: &str) -> Self {
+        self.schema = Some(schema.to_string());
+        self
+    }
+
+    /// Returns the schema of the type, if any.
+    #[must_use]
+    pub fn get_schema(&self) -> Option<&str> {
+        self.schema.as_deref()
+    }
+
+    /// Returns the name of the type.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the names and normalized SQL types of the attributes, in their
+    /// declaration order.
+    #[must_use]
+    pub fn attributes(&self) -> &[(String, String)] {
+        &self.attributes
+    }
+
+    /// Returns the ident of the generated struct.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use synql::prelude::*;
+    ///
+    /// let address = PostgresComposite::new("postal_address", [("city", "text")]);
+    /// assert_eq!(address.ident().to_string(), "PostalAddress");
+    /// ```
+    #[must_use]
+    pub fn ident(&self) -> Ident {
+        sanitized_ident(&self.name.to_upper_camel_case())
+    }
+
+    /// Returns the ident of the generated diesel SQL type.
+    #[must_use]
+    pub fn sql_type_ident(&self) -> Ident {
+        format_ident!("{}SqlType", self.ident())
+    }
+
+    /// Returns the name of the type, qualified with its schema if any.
+    fn qualified_name(&self) -> String {
+        match &self.schema {
+            Some(schema) => format!("{schema}.{}", self.name),
+            None => self.name.clone(),
+        }
+    }
+
+    /// Returns the traits which can be derived for the generated struct, that
+    /// is the traits supported by all of its attributes and `Default`, as the
+    /// fields are optional.
+    ///
+    /// # Arguments
+    ///
+    /// * `supports` - Returns whether the Rust type of the provided SQL type
+    ///   supports the provided trait.
+    pub(crate) fn supported_traits<F>(&self, supports: F) -> Vec<Trait>
+    where
+        F: Fn(&str, Trait) -> bool,
+    {
+        Trait::iter()
+            .filter(|core_trait| {
+                *core_trait == Trait::Default
+                    || self.attributes.iter().all(|(_, data_type)| supports(data_type, *core_trait))
+            })
+            .collect()
+    }
+
+    /// Returns the external type describing the generated struct, provided
+    /// by the crate with the provided ident.
+    ///
+    /// # Arguments
+    ///
+    /// * `crate_ident` - The ident of the types crate.
+    /// * `unqualified` - Whether the type is also matched by its unqualified
+    ///   name, which is not the case when homonymous types exist in other
+    ///   schemas.
+    /// * `traits` - The traits supported by the generated struct.
+    ///
+    /// Returns `None` if the name of the type is not a valid Postgres type
+    /// name, such as one with unbalanced parentheses.
+    pub(crate) fn external_type(
+        &self,
+        crate_ident: &Ident,
+        unqualified: bool,
+        traits: &[Trait],
+    ) -> Option<ExternalType> {
+        let ident = self.ident();
+        let sql_type_ident = self.sql_type_ident();
+        let mut postgres_types = Vec::new();
+        if unqualified || self.schema.is_none() {
+            postgres_types.push(self.name.to_lowercase());
+        }
+        if self.schema.is_some() {
+            postgres_types.push(self.qualified_name().to_lowercase());
+        }
+        let builder = ExternalType::new(
+            syn::parse_quote!(::#crate_ident::#sql_type_ident),
+            syn::parse_quote!(::#crate_ident::#ident),
+        )
+        .postgres_types(postgres_types)
+        .ok()?;
+        Some(
+            traits
+                .iter()
+                .fold(builder, |builder, core_trait| builder.supports_trait(*core_trait))
+                .into(),
+        )
+    }
+
+    /// Returns the external types of the attributes.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace` - The workspace where the type is generated.
+    ///
+    /// # Errors
+    ///
+    /// Returns
+    /// [`CompositeAttributeTypeNotFound`](crate::Error::CompositeAttributeTypeNotFound)
+    /// if the type of an attribute is not provided by any of the external
+    /// crates of the workspace.
+    pub(crate) fn attribute_types<'workspace>(
+        &self,
+        workspace: &'workspace Workspace,
+    ) -> Result<Vec<ExternalTypeRef<'workspace>>, crate::Error> {
+        self.attributes
+            .iter()
+            .map(|(attribute_name, data_type)| {
+                workspace.external_postgres_type(data_type).ok_or_else(|| {
+                    crate::Error::CompositeAttributeTypeNotFound {
+                        composite: self.qualified_name(),
+                        attribute: attribute_name.clone(),
+                        sql_type: data_type.clone(),
+                    }
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the tokens of the generated struct, alongside its diesel SQL
+    /// type and trait implementations.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace` - The workspace where the type is generated.
+    ///
+    /// # Errors
+    ///
+    /// Returns
+    /// [`CompositeAttributeTypeNotFound`](crate::Error::CompositeAttributeTypeNotFound)
+    /// if the type of an attribute is not provided by any of the external
+    /// crates of the workspace.
+    pub(crate) fn to_syn(&self, workspace: &Workspace) -> Result<TokenStream, crate::Error> {
+        let ident = self.ident();
+        let sql_type_ident = self.sql_type_ident();
+        let name = &self.name;
+        let qualified_name = self.qualified_name();
+        let schema = self.schema.as_ref().map(|schema| quote! { , schema = #schema });
+        let documentation = format!("Struct representing the `{qualified_name}` Postgres type.");
+        let sql_type_documentation =
+            format!("Diesel SQL type of the `{qualified_name}` Postgres type.");
+
+        let attribute_types = self.attribute_types(workspace)?;
+        let derives = self
+            .supported_traits(|data_type, core_trait| {
+                workspace
+                    .external_postgres_type(data_type)
+                    .is_some_and(|attribute_type| attribute_type.supports_trait(core_trait))
+            })
+            .into_iter()
+            .map(|core_trait| core_trait.path());
+
+        // The types provided by the types crate itself are referenced from the
+        // crate root.
+        let crate_ident = sanitized_ident(&workspace.types_crate_name());
+        let mut local_paths = LocalPaths { crate_ident: &crate_ident };
+        let (rust_types, diesel_types): (Vec<syn::Type>, Vec<syn::Type>) = attribute_types
+            .iter()
+            .map(|attribute_type| {
+                let mut rust_type = attribute_type.rust_type().clone();
+                let mut diesel_type = attribute_type.diesel_type().clone();
+                local_paths.visit_type_mut(&mut rust_type);
+                local_paths.visit_type_mut(&mut diesel_type);
+                (rust_type, diesel_type)
+            })
+            .unzip();
+        let field_idents = self
+            .attributes
+            .iter()
+            .map(|(attribute_name, _)| sanitized_ident(&attribute_name.to_snake_case()))
+            .collect::<Vec<_>>();
+        let field_documentations = self
+            .attributes
+            .iter()
+            .map(|(attribute_name, _)| format!("The `{attribute_name}` attribute."));
+
+        Ok(quote! {
+            #[derive(
+                #(#derives,)*
+                ::serde::Serialize, ::serde::Deserialize,
+                ::diesel::expression::AsExpression, ::diesel::deserialize::FromSqlRow,
+            )]
+            #[diesel(sql_type = #sql_type_ident)]
+            #[doc = #documentation]
+            pub struct #ident {
+                #(
+                    #[doc = #field_documentations]
+                    pub #field_idents: Option<#rust_types>,
+                )*
+            }
+
+            #[derive(
+                Debug, Clone, Copy, Default,
+                ::diesel::sql_types::SqlType, ::diesel::query_builder::QueryId,
+            )]
+            #[diesel(postgres_type(name = #name #schema))]
+            #[doc = #sql_type_documentation]
+            pub struct #sql_type_ident;
+
+            impl ::diesel::serialize::ToSql<#sql_type_ident, ::diesel::pg::Pg> for #ident {
+                fn to_sql<'b>(
+                    &'b self,
+                    out: &mut ::diesel::serialize::Output<'b, '_, ::diesel::pg::Pg>,
+                ) -> ::diesel::serialize::Result {
+                    ::diesel::serialize::WriteTuple::<(
+                        #(::diesel::sql_types::Nullable<#diesel_types>,)*
+                    )>::write_tuple(&(#(&self.#field_idents,)*), &mut out.reborrow())
+                }
+            }
+
+            impl ::diesel::deserialize::FromSql<#sql_type_ident, ::diesel::pg::Pg> for #ident {
+                fn from_sql(
+                    value: ::diesel::pg::PgValue<'_>,
+                ) -> ::diesel::deserialize::Result<Self> {
+                    let (#(#field_idents,)*) = <(#(Option<#rust_types>,)*) as ::diesel::deserialize::FromSql<
+                        ::diesel::sql_types::Record<(#(::diesel::sql_types::Nullable<#diesel_types>,)*)>,
+                        ::diesel::pg::Pg,
+                    >>::from_sql(value)?;
+                    Ok(Self { #(#field_idents),* })
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,390 @@
+//! Submodule defining the `PostgresDomain` struct, describing a domain
+//! declared with `CREATE DOMAIN`.
+
+mod evaluate;
+
+use heck::ToUpperCamelCase;
+use proc_macro2::TokenStream;
+use quote::{ToTokens, quote};
+use sql_traits::traits::DatabaseLike;
+use sqlparser::ast::Expr;
+use strum::IntoEnumIterator;
+use syn::Ident;
+
+use crate::{
+    structs::{
+        ExternalType, ExternalTypeRef, GenerationWarning, Trait, Workspace,
+        external_type::{sql_literal, without_modifiers},
+        workspace::resolve_postgres_type,
+    },
+    traits::check_constraint::domain_check_to_syn,
+    utils::{sanitized_ident, todo_comment},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// Domain declared with `CREATE DOMAIN positive_int AS integer CHECK (VALUE >
+/// 0)`, which is generated as a validated newtype in the types crate of the
+/// workspace.
+///
+/// The checks of the domain are translated into the `TryFrom` implementation
+/// of the newtype over its base type, so that the columns of the domain are
+/// validated by their type.
+pub struct PostgresDomain {
+    /// The schema of the domain, if any.
+    schema: Option<String>,
+    /// The name of the domain.
+    name: String,
+    /// The normalized SQL type the domain is based on.
+    data_type: String,
+    /// The checks of the domain, referencing its value as `VALUE`.
+    checks: Vec<Expr>,
+}
+
+impl PostgresDomain {
+    /// Creates a new `PostgresDomain`.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the domain.
+    /// * `data_type` - The SQL type the domain is based on, whose modifiers
+    ///   such as the length of a `varchar` or the precision of a `timestamp(3)
+    ///   with time zone` are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use synql::prelude::*;
+    ///
+    /// let domain = PostgresDomain::new("price", "NUMERIC(10, 2)[]");
+    /// assert_eq!(domain.data_type(), "numeric[]");
+    /// let domain = PostgresDomain::new("instant", "TIMESTAMP(3) WITH TIME ZONE");
+    /// assert_eq!(domain.data_type(), "timestamp with time zone");
+    /// ```
+    #[must_use]
+    pub fn new(name: &str, data_type: &str) -> Self {
+        Self {
+            schema: None,
+            name: name.to_string(),
+            data_type: without_modifiers(data_type.trim()).to_lowercase(),
+            checks: Vec::new(),
+        }
+    }
+
+    /// Sets the schema of the domain.
+    ///
+    /// # Arguments
+    ///
+    /// * `schema` - The schema of the domain.
+    #[must_use]
+    pub fn schema(mut self, schema: &str) -> Self {
+        self.schema = Some(schema.to_string());
+        self
+    }
+
+    /// Adds a check to the domain.
+    ///
+    /// # Arguments
+    ///
+    /// * `check` - The check expression, referencing the value as `VALUE`.
+    #[must_use]
+    pub fn check(mut self, check: Expr) -> Self {
+        self.checks.push(check);
+        self
+    }
+
+    /// Returns the schema of the domain, if any.
+    #[must_use]
+    pub fn get_schema(&self) -> Option<&str> {
+        self.schema.as_deref()
+    }
+
+    /// Returns the name of the domain.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the normalized SQL type the domain is based on.
+    #[must_use]
+    pub fn data_type(&self) -> &str {
+        &self.data_type
+    }
+
+    /// Returns the checks of the domain.
+    #[must_use]
+    pub fn checks(&self) -> &[Expr] {
+        &self.checks
+    }
+
+    /// Checks that the provided default value satisfies the checks of the
+    /// domain, which are evaluated at generation time.
+    ///
+    /// # Arguments
+    ///
+    /// * `default_value` - The SQL default value, such as `'abc'::username`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the reason why the default value is rejected, if it does not
+    /// satisfy one of the checks or if one of the checks cannot be evaluated
+    /// at generation time.
+    pub(crate) fn validate_default(&self, default_value: &str) -> Result<(), String> {
+        let literal = sql_literal(default_value);
+        for check in &self.checks {
+            match evaluate::satisfies(check, &literal) {
+                Some(true) => {}
+                Some(false) => {
+                    return Err(format!("it does not satisfy the check `{check}` of the domain"));
+                }
+                None => {
+                    return Err(format!(
+                        "the check `{check}` of the domain cannot be evaluated at generation time"
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the ident of the generated newtype.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use synql::prelude::*;
+    ///
+    /// let domain = PostgresDomain::new("positive_int", "integer");
+    /// assert_eq!(domain.ident().to_string(), "PositiveInt");
+    /// ```
+    #[must_use]
+    pub fn ident(&self) -> Ident {
+        sanitized_ident(&self.name.to_upper_camel_case())
+    }
+
+    /// Returns the name of the domain, qualified with its schema if any.
+    pub(crate) fn qualified_name(&self) -> String {
+        match &self.schema {
+            Some(schema) => format!("{schema}.{}", self.name),
+            None => self.name.clone(),
+        }
+    }
+
+    /// Returns the external type the domain is based on, provided by any of
+    /// the external crates but the types crate, in which the newtype is
+    /// defined.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace` - The workspace where the domain is generated.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DomainTypeNotFound`](crate::Error::DomainTypeNotFound) if
+    /// no external crate provides the base type of the domain.
+    pub(crate) fn base_type<'workspace>(
+        &self,
+        workspace: &'workspace Workspace,
+    ) -> Result<ExternalTypeRef<'workspace>, crate::Error> {
+        let types_crate_name = workspace.types_crate_name();
+        let base_crates = workspace
+            .external_crates()
+            .filter(|external_crate| external_crate.name() != types_crate_name)
+            .collect::<Vec<_>>();
+        resolve_postgres_type(
+            base_crates.iter().copied(),
+            workspace.pinned_postgres_types(),
+            &self.data_type,
+        )
+        .ok_or_else(|| {
+            crate::Error::DomainTypeNotFound {
+                domain: self.qualified_name(),
+                sql_type: self.data_type.clone(),
+            }
+        })
+    }
+
+    /// Returns the external type describing the generated newtype, provided
+    /// by the crate with the provided ident.
+    ///
+    /// # Arguments
+    ///
+    /// * `crate_ident` - The ident of the types crate.
+    /// * `unqualified` - Whether the domain is also matched by its unqualified
+    ///   name, which is not the case when homonymous types exist in other
+    ///   schemas.
+    /// * `base_type` - The external type the domain is based on.
+    ///
+    /// Returns `None` if the name of the domain is not a valid Postgres type
+    /// name, such as one with unbalanced parentheses.
+    pub(crate) fn external_type(
+        &self,
+        crate_ident: &Ident,
+        unqualified: bool,
+        base_type: ExternalTypeRef<'_>,
+    ) -> Option<ExternalType> {
+        let ident = self.ident();
+        let mut postgres_types = Vec::new();
+        if unqualified || self.schema.is_none() {
+            postgres_types.push(self.name.to_lowercase());
+        }
+        if self.schema.is_some() {
+            postgres_types.push(self.qualified_name().to_lowercase());
+        }
+        let builder = ExternalType::new(
+            base_type.diesel_type().clone(),
+            syn::parse_quote!(::#crate_ident::#ident),
+        )
+        .postgres_types(postgres_types)
+        .ok()?
+        .base_type(base_type.rust_type().clone());
+        // The newtype supports the traits of its base type, but the default
+        // one, as the default value of the base type may not be valid.
+        Some(
+            Trait::iter()
+                .filter(|core_trait| {
+                    *core_trait != Trait::Default && base_type.supports_trait(*core_trait)
+                })
+                .fold(builder, |builder, core_trait| builder.supports_trait(core_trait))
+                .into(),
+        )
+    }
+
+    /// Returns the tokens of the generated newtype, alongside its validating
+    /// `TryFrom` implementation and diesel trait implementations.
+    ///
+    /// In lenient mode, the checks which cannot be translated are replaced by
+    /// `// TODO` placeholders.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace` - The workspace where the domain is generated.
+    /// * `database` - The database defining the domain.
+    ///
+    /// # Errors
+    ///
+    /// * Returns [`DomainTypeNotFound`](crate::Error::DomainTypeNotFound) if no
+    ///   external crate provides the base type of the domain.
+    /// * Returns
+    ///   [`UnsupportedDomainCheck`](crate::Error::UnsupportedDomainCheck) if
+    ///   a check cannot be translated, unless the workspace is lenient.
+    pub(crate) fn to_syn<DB: DatabaseLike>(
+        &self,
+        workspace: &Workspace,
+        database: &DB,
+    ) -> Result<TokenStream, crate::Error> {
+        let ident = self.ident();
+        let base_type = self.base_type(workspace)?;
+        let base_rust_type = base_type.rust_type();
+        let diesel_type = base_type.diesel_type();
+        let base_rust_type_name = base_rust_type.to_token_stream().to_string();
+        let qualified_name = self.qualified_name();
+        let documentation = format!(
+            "Newtype representing the `{qualified_name}` Postgres domain over `{}`.",
+            self.data_type
+        );
+
+        let mut validations = Vec::new();
+        for check in &self.checks {
+            match domain_check_to_syn(self, check, base_type, workspace, database) {
+                Ok(validation) => validations.push(validation),
+                Err(crate::Error::UnsupportedDomainCheck {
+                    domain,
+                    check,
+                    sub_expression,
+                    reason,
+                }) if workspace.is_lenient() => {
+                    validations.push(todo_comment(&format!(
+                        "unsupported check `{check}`, cannot translate `{sub_expression}`: {reason}"
+                    )));
+                    workspace.warn(GenerationWarning::DroppedDomainCheck {
+                        domain,
+                        check,
+                        sub_expression,
+                        reason,
+                    });
+                }
+                Err(error) => return Err(error),
+            }
+        }
+
+        let derives = Trait::iter()
+            .filter(|core_trait| {
+                *core_trait != Trait::Default && base_type.supports_trait(*core_trait)
+            })
+            .map(|core_trait| core_trait.path());
+
+        Ok(quote! {
+            #[derive(
+                #(#derives,)*
+                ::serde::Deserialize,
+                ::diesel::expression::AsExpression, ::diesel::deserialize::FromSqlRow,
+            )]
+            #[serde(try_from = #base_rust_type_name)]
+            #[diesel(sql_type = #diesel_type)]
+            #[doc = #documentation]
+            pub struct #ident(#base_rust_type);
+
+            impl #ident {
+                #[must_use]
+                /// Returns a reference to the validated value.
+                pub const fn get(&self) -> &#base_rust_type {
+                    &self.0
+                }
+
+                #[must_use]
+                /// Returns the validated value.
+                pub fn into_inner(self) -> #base_rust_type {
+                    self.0
+                }
+            }
+
+            impl ::core::convert::TryFrom<#base_rust_type> for #ident {
+                type Error = ::validation_errors::ValidationError;
+
+                fn try_from(value: #base_rust_type) -> Result<Self, Self::Error> {
+                    {
+                        let value = &value;
+                        #(#validations)*
+                    }
+                    Ok(Self(value))
+                }
+            }
+
+            impl ::core::convert::AsRef<#base_rust_type> for #ident {
+                fn as_ref(&self) -> &#base_rust_type {
+                    &self.0
+                }
+            }
+
+            impl From<#ident> for #base_rust_type {
+                fn from(value: #ident) -> Self {
+                    value.0
+                }
+            }
+
+            impl ::serde::Serialize for #ident {
+                fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    ::serde::Serialize::serialize(&self.0, serializer)
+                }
+            }
+
+            impl ::diesel::serialize::ToSql<#diesel_type, ::diesel::pg::Pg> for #ident {
+                fn to_sql<'b>(
+                    &'b self,
+                    out: &mut ::diesel::serialize::Output<'b, '_, ::diesel::pg::Pg>,
+                ) -> ::diesel::serialize::Result {
+                    <#base_rust_type as ::diesel::serialize::ToSql<#diesel_type, ::diesel::pg::Pg>>::to_sql(&self.0, out)
+                }
+            }
+
+            impl ::diesel::deserialize::FromSql<#diesel_type, ::diesel::pg::Pg> for #ident {
+                fn from_sql(
+                    value: ::diesel::pg::PgValue<'_>,
+                ) -> ::diesel::deserialize::Result<Self> {
+                    let value = <#base_rust_type as ::diesel::deserialize::FromSql<#diesel_type, ::diesel::pg::Pg>>::from_sql(value)?;
+                    Ok(Self::try_from(value)?)
+                }
+            }
+        })
+    }
+}
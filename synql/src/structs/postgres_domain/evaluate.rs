@@ -0,0 +1,225 @@
+//! Submodule evaluating the checks of a domain over a constant value, such as
+//! the default value of a column, at generation time.
+//!
+//! Only the checks made of comparisons, boolean and arithmetic operators and
+//! length functions over literals are evaluated, while any other construct,
+//! such as a call to a validation function, is reported as undecidable.
+
+use std::cmp::Ordering;
+
+use sqlparser::ast::{
+    BinaryOperator, Expr, FunctionArg, FunctionArgExpr, FunctionArguments, UnaryOperator, Value,
+    ValueWithSpan,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+/// Constant value a check is evaluated to.
+enum Scalar {
+    /// A boolean value.
+    Bool(bool),
+    /// An integer value.
+    Integer(i128),
+    /// A decimal value.
+    Decimal(f64),
+    /// A textual value, which is also compared as a number or a boolean when
+    /// compared with one, as the untyped literals of Postgres are.
+    Text(String),
+}
+
+impl Scalar {
+    /// Returns the scalar parsed from the provided number.
+    fn number(number: &str) -> Option<Self> {
+        let number = number.trim();
+        number.parse().map(Scalar::Integer).ok().or_else(|| {
+            number.parse().ok().filter(|number: &f64| number.is_finite()).map(Scalar::Decimal)
+        })
+    }
+
+    /// Returns the current scalar as a number, parsing it if it is textual.
+    fn as_number(&self) -> Option<Self> {
+        match self {
+            Scalar::Integer(_) | Scalar::Decimal(_) => Some(self.clone()),
+            Scalar::Text(text) => Scalar::number(text),
+            Scalar::Bool(_) => None,
+        }
+    }
+
+    /// Returns the current scalar as a boolean, parsing it if it is textual.
+    fn as_bool(&self) -> Option<bool> {
+        match self {
+            Scalar::Bool(value) => Some(*value),
+            Scalar::Text(text) => {
+                match text.trim().to_lowercase().as_str() {
+                    "true" | "t" | "yes" | "y" | "on" | "1" => Some(true),
+                    "false" | "f" | "no" | "n" | "off" | "0" => Some(false),
+                    _ => None,
+                }
+            }
+            Scalar::Integer(_) | Scalar::Decimal(_) => None,
+        }
+    }
+
+    /// Returns the ordering of the current scalar with respect to the other
+    /// one, or `None` if they cannot be compared.
+    fn compare(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (Scalar::Text(left), Scalar::Text(right)) => Some(left.cmp(right)),
+            (Scalar::Bool(_), _) | (_, Scalar::Bool(_)) => {
+                self.as_bool()?.partial_cmp(&other.as_bool()?)
+            }
+            _ => {
+                match (self.as_number()?, other.as_number()?) {
+                    (Scalar::Integer(left), Scalar::Integer(right)) => Some(left.cmp(&right)),
+                    (left, right) => left.as_f64()?.partial_cmp(&right.as_f64()?),
+                }
+            }
+        }
+    }
+
+    /// Returns the current numeric scalar as a floating point number.
+    #[allow(clippy::cast_precision_loss)]
+    fn as_f64(&self) -> Option<f64> {
+        match self {
+            Scalar::Integer(value) => Some(*value as f64),
+            Scalar::Decimal(value) => Some(*value),
+            Scalar::Bool(_) | Scalar::Text(_) => None,
+        }
+    }
+
+    /// Applies the provided arithmetic operator to the current scalar and the
+    /// other one.
+    fn arithmetic(&self, op: &BinaryOperator, other: &Self) -> Option<Self> {
+        match (self.as_number()?, other.as_number()?) {
+            (Scalar::Integer(left), Scalar::Integer(right)) => {
+                Some(Scalar::Integer(match op {
+                    BinaryOperator::Plus => left.checked_add(right)?,
+                    BinaryOperator::Minus => left.checked_sub(right)?,
+                    BinaryOperator::Multiply => left.checked_mul(right)?,
+                    BinaryOperator::Divide => left.checked_div(right)?,
+                    BinaryOperator::Modulo => left.checked_rem(right)?,
+                    _ => return None,
+                }))
+            }
+            (left, right) => {
+                let (left, right) = (left.as_f64()?, right.as_f64()?);
+                let result = match op {
+                    BinaryOperator::Plus => left + right,
+                    BinaryOperator::Minus => left - right,
+                    BinaryOperator::Multiply => left * right,
+                    BinaryOperator::Divide => left / right,
+                    BinaryOperator::Modulo => left % right,
+                    _ => return None,
+                };
+                result.is_finite().then_some(Scalar::Decimal(result))
+            }
+        }
+    }
+}
+
+/// Returns whether the provided literal satisfies the provided check, or
+/// `None` if the check contains a construct which cannot be evaluated at
+/// generation time.
+///
+/// # Arguments
+///
+/// * `check` - The check, referencing the value as `VALUE`.
+/// * `literal` - The literal the check is evaluated over, stripped of its
+///   quotes and cast.
+pub(super) fn satisfies(check: &Expr, literal: &str) -> Option<bool> {
+    evaluate(check, &Scalar::Text(literal.to_owned()))?.as_bool()
+}
+
+/// Evaluates the provided expression, returning `None` if it contains a
+/// construct which cannot be evaluated at generation time.
+///
+/// # Arguments
+///
+/// * `expr` - The expression, referencing the value as `VALUE`.
+/// * `value` - The value the expression is evaluated over.
+fn evaluate(expr: &Expr, value: &Scalar) -> Option<Scalar> {
+    match expr {
+        Expr::Identifier(ident) if ident.value.eq_ignore_ascii_case("value") => Some(value.clone()),
+        Expr::Value(ValueWithSpan { value: literal, .. }) => {
+            match literal {
+                Value::Boolean(boolean) => Some(Scalar::Bool(*boolean)),
+                Value::Number(number, _) => Scalar::number(&number.to_string()),
+                Value::SingleQuotedString(text) => Some(Scalar::Text(text.clone())),
+                _ => None,
+            }
+        }
+        Expr::Nested(inner) | Expr::Cast { expr: inner, .. } => evaluate(inner, value),
+        Expr::UnaryOp { op, expr: inner } => {
+            let inner = evaluate(inner, value)?;
+            match op {
+                UnaryOperator::Not => Some(Scalar::Bool(!inner.as_bool()?)),
+                UnaryOperator::Plus => inner.as_number(),
+                UnaryOperator::Minus => {
+                    match inner.as_number()? {
+                        Scalar::Integer(number) => number.checked_neg().map(Scalar::Integer),
+                        Scalar::Decimal(number) => Some(Scalar::Decimal(-number)),
+                        Scalar::Bool(_) | Scalar::Text(_) => None,
+                    }
+                }
+                _ => None,
+            }
+        }
+        Expr::BinaryOp { left, op, right } => {
+            let left = evaluate(left, value)?;
+            let right = evaluate(right, value)?;
+            match op {
+                BinaryOperator::And => Some(Scalar::Bool(left.as_bool()? && right.as_bool()?)),
+                BinaryOperator::Or => Some(Scalar::Bool(left.as_bool()? || right.as_bool()?)),
+                BinaryOperator::Eq => Some(Scalar::Bool(left.compare(&right)?.is_eq())),
+                BinaryOperator::NotEq => Some(Scalar::Bool(left.compare(&right)?.is_ne())),
+                BinaryOperator::Gt => Some(Scalar::Bool(left.compare(&right)?.is_gt())),
+                BinaryOperator::GtEq => Some(Scalar::Bool(left.compare(&right)?.is_ge())),
+                BinaryOperator::Lt => Some(Scalar::Bool(left.compare(&right)?.is_lt())),
+                BinaryOperator::LtEq => Some(Scalar::Bool(left.compare(&right)?.is_le())),
+                BinaryOperator::Plus
+                | BinaryOperator::Minus
+                | BinaryOperator::Multiply
+                | BinaryOperator::Divide
+                | BinaryOperator::Modulo => left.arithmetic(op, &right),
+                _ => None,
+            }
+        }
+        // The evaluated values are never null.
+        Expr::IsNull(inner) | Expr::IsNotNull(inner) => {
+            evaluate(inner, value)?;
+            Some(Scalar::Bool(matches!(expr, Expr::IsNotNull(_))))
+        }
+        Expr::Between { expr: inner, negated, low, high } => {
+            let inner = evaluate(inner, value)?;
+            let low = evaluate(low, value)?;
+            let high = evaluate(high, value)?;
+            let between = inner.compare(&low)?.is_ge() && inner.compare(&high)?.is_le();
+            Some(Scalar::Bool(between != *negated))
+        }
+        Expr::InList { expr: inner, list, negated } => {
+            let inner = evaluate(inner, value)?;
+            let mut contained = false;
+            for item in list {
+                contained |= inner.compare(&evaluate(item, value)?)?.is_eq();
+            }
+            Some(Scalar::Bool(contained != *negated))
+        }
+        Expr::Function(function) => {
+            let name = function.name.to_string().to_lowercase();
+            if !matches!(name.as_str(), "length" | "char_length" | "character_length") {
+                return None;
+            }
+            let FunctionArguments::List(arguments) = &function.args else {
+                return None;
+            };
+            let [FunctionArg::Unnamed(FunctionArgExpr::Expr(argument))] = arguments.args.as_slice()
+            else {
+                return None;
+            };
+            match evaluate(argument, value)? {
+                Scalar::Text(text) => Some(Scalar::Integer(text.chars().count().try_into().ok()?)),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,288 @@
+//! Submodule defining the `PostgresEnum` struct, describing an enumerated
+//! type declared with `CREATE TYPE ... AS ENUM`.
+
+use heck::ToUpperCamelCase;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::Ident;
+
+use crate::{structs::ExternalType, utils::sanitized_ident};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// Enumerated type declared with `CREATE TYPE mood AS ENUM ('sad', 'happy')`,
+/// which is generated as a Rust enum in the types crate of the workspace.
+///
+/// The enum implements the diesel traits of its SQL type, alongside
+/// `Display` and `FromStr` over the labels, serde and the core derives. Its
+/// first variant is the default one.
+pub struct PostgresEnum {
+    /// The schema of the type, if any.
+    schema: Option<String>,
+    /// The name of the type.
+    name: String,
+    /// The labels of the type, in their declaration order.
+    labels: Vec<String>,
+    /// The names of the variants of the labels which are renamed, keyed by
+    /// label.
+    variant_names: Vec<(String, String)>,
+}
+
+impl PostgresEnum {
+    /// Creates a new `PostgresEnum`.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the type.
+    /// * `labels` - The labels of the type, in their declaration order.
+    #[must_use]
+    pub fn new<I, S>(name: &str, labels: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: ToString,
+    {
+        Self {
+            schema: None,
+            name: name.to_string(),
+            labels: labels.into_iter().map(|label| label.to_string()).collect(),
+            variant_names: Vec::new(),
+        }
+    }
+
+    /// Sets the schema of the type.
+    ///
+    /// # Arguments
+    ///
+    /// * `schema` - The schema of the type.
+    #[must_use]
+    pub fn schema(mut self, schema: &str) -> Self {
+        self.schema = Some(schema.to_string());
+        self
+    }
+
+    /// Returns the schema of the type, if any.
+    #[must_use]
+    pub fn get_schema(&self) -> Option<&str> {
+        self.schema.as_deref()
+    }
+
+    /// Returns the name of the type.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the labels of the type, in their declaration order.
+    #[must_use]
+    pub fn labels(&self) -> &[String] {
+        &self.labels
+    }
+
+    /// Returns the ident of the generated enum.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use synql::prelude::*;
+    ///
+    /// let mood = PostgresEnum::new("mood", ["sad", "happy"]);
+    /// assert_eq!(mood.ident().to_string(), "Mood");
+    /// ```
+    #[must_use]
+    pub fn ident(&self) -> Ident {
+        sanitized_ident(&self.name.to_upper_camel_case())
+    }
+
+    /// Returns the ident of the generated diesel SQL type.
+    #[must_use]
+    pub fn sql_type_ident(&self) -> Ident {
+        format_ident!("{}SqlType", self.ident())
+    }
+
+    /// Renames the variant of the provided label, which is otherwise named
+    /// after the label in upper camel case.
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - The label of the variant.
+    /// * `name` - The name of the variant.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use synql::prelude::*;
+    ///
+    /// let mood = PostgresEnum::new("mood", ["happy", "Happy"]).rename_variant("Happy", "Elated");
+    /// assert_eq!(mood.variant_ident("happy").to_string(), "Happy");
+    /// assert_eq!(mood.variant_ident("Happy").to_string(), "Elated");
+    /// ```
+    #[must_use]
+    pub fn rename_variant(mut self, label: &str, name: &str) -> Self {
+        self.variant_names.retain(|(renamed_label, _)| renamed_label != label);
+        self.variant_names.push((label.to_owned(), name.to_owned()));
+        self
+    }
+
+    /// Returns the ident of the variant of the provided label.
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - The label of the variant.
+    #[must_use]
+    pub fn variant_ident(&self, label: &str) -> Ident {
+        match self.variant_names.iter().find(|(renamed_label, _)| renamed_label == label) {
+            Some((_, name)) => sanitized_ident(name),
+            None => sanitized_ident(&label.to_upper_camel_case()),
+        }
+    }
+
+    /// Returns the name of the type, qualified with its schema if any.
+    fn qualified_name(&self) -> String {
+        match &self.schema {
+            Some(schema) => format!("{schema}.{}", self.name),
+            None => self.name.clone(),
+        }
+    }
+
+    /// Returns the external type describing the generated enum, provided by
+    /// the crate with the provided ident.
+    ///
+    /// # Arguments
+    ///
+    /// * `crate_ident` - The ident of the types crate.
+    /// * `unqualified` - Whether the type is also matched by its unqualified
+    ///   name, which is not the case when homonymous types exist in other
+    ///   schemas.
+    ///
+    /// Returns `None` if the name of the type is not a valid Postgres type
+    /// name, such as one with unbalanced parentheses.
+    pub(crate) fn external_type(
+        &self,
+        crate_ident: &Ident,
+        unqualified: bool,
+    ) -> Option<ExternalType> {
+        let ident = self.ident();
+        let sql_type_ident = self.sql_type_ident();
+        let mut postgres_types = Vec::new();
+        if unqualified || self.schema.is_none() {
+            postgres_types.push(self.name.to_lowercase());
+        }
+        if self.schema.is_some() {
+            postgres_types.push(self.qualified_name().to_lowercase());
+        }
+        let mut builder = ExternalType::new(
+            syn::parse_quote!(::#crate_ident::#sql_type_ident),
+            syn::parse_quote!(::#crate_ident::#ident),
+        )
+        .postgres_types(postgres_types)
+        .ok()?
+        .supports_debug()
+        .supports_copy()
+        .supports_ord()
+        .supports_hash();
+        if !self.labels.is_empty() {
+            builder = builder.supports_default();
+        }
+        for label in &self.labels {
+            let variant_ident = self.variant_ident(label);
+            builder =
+                builder.literal(label, syn::parse_quote!(::#crate_ident::#ident::#variant_ident));
+        }
+        Some(builder.into())
+    }
+
+    /// Returns the tokens of the generated enum, alongside its diesel SQL
+    /// type and trait implementations.
+    pub(crate) fn to_syn(&self) -> TokenStream {
+        let ident = self.ident();
+        let sql_type_ident = self.sql_type_ident();
+        let name = &self.name;
+        let qualified_name = self.qualified_name();
+        let schema = self.schema.as_ref().map(|schema| quote! { , schema = #schema });
+        let documentation = format!("Enum representing the `{qualified_name}` Postgres type.");
+        let sql_type_documentation =
+            format!("Diesel SQL type of the `{qualified_name}` Postgres type.");
+        let default_derive = (!self.labels.is_empty()).then(|| quote! { Default, });
+
+        let variant_idents =
+            self.labels.iter().map(|label| self.variant_ident(label)).collect::<Vec<_>>();
+        let labels = &self.labels;
+        let variants = labels.iter().zip(&variant_idents).enumerate().map(
+            |(index, (label, variant_ident))| {
+                let variant_documentation = format!("The `{label}` label.");
+                let default = (index == 0).then(|| quote! { #[default] });
+                quote! {
+                    #[doc = #variant_documentation]
+                    #default
+                    #[serde(rename = #label)]
+                    #variant_ident
+                }
+            },
+        );
+
+        quote! {
+            #[derive(
+                Debug, Clone, Copy, #default_derive PartialEq, Eq, PartialOrd, Ord, Hash,
+                ::serde::Serialize, ::serde::Deserialize,
+                ::diesel::expression::AsExpression, ::diesel::deserialize::FromSqlRow,
+            )]
+            #[diesel(sql_type = #sql_type_ident)]
+            #[doc = #documentation]
+            pub enum #ident {
+                #(#variants),*
+            }
+
+            #[derive(
+                Debug, Clone, Copy, Default,
+                ::diesel::sql_types::SqlType, ::diesel::query_builder::QueryId,
+            )]
+            #[diesel(postgres_type(name = #name #schema))]
+            #[doc = #sql_type_documentation]
+            pub struct #sql_type_ident;
+
+            impl #ident {
+                #[must_use]
+                /// Returns the label of the variant.
+                pub const fn as_str(&self) -> &'static str {
+                    match *self {
+                        #(Self::#variant_idents => #labels,)*
+                    }
+                }
+            }
+
+            impl ::core::fmt::Display for #ident {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    f.write_str(self.as_str())
+                }
+            }
+
+            impl ::core::str::FromStr for #ident {
+                type Err = UnknownLabelError;
+
+                fn from_str(label: &str) -> Result<Self, Self::Err> {
+                    match label {
+                        #(#labels => Ok(Self::#variant_idents),)*
+                        _ => Err(UnknownLabelError::new(#qualified_name, label)),
+                    }
+                }
+            }
+
+            impl ::diesel::serialize::ToSql<#sql_type_ident, ::diesel::pg::Pg> for #ident {
+                fn to_sql<'b>(
+                    &'b self,
+                    out: &mut ::diesel::serialize::Output<'b, '_, ::diesel::pg::Pg>,
+                ) -> ::diesel::serialize::Result {
+                    ::std::io::Write::write_all(out, self.as_str().as_bytes())?;
+                    Ok(::diesel::serialize::IsNull::No)
+                }
+            }
+
+            impl ::diesel::deserialize::FromSql<#sql_type_ident, ::diesel::pg::Pg> for #ident {
+                fn from_sql(
+                    value: ::diesel::pg::PgValue<'_>,
+                ) -> ::diesel::deserialize::Result<Self> {
+                    Ok(::core::str::from_utf8(value.as_bytes())?.parse()?)
+                }
+            }
+        }
+    }
+}
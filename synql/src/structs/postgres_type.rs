@@ -0,0 +1,254 @@
+//! Submodule defining the `PostgresType` struct, describing a Postgres type
+//! parsed into its name and its modifiers.
+
+use std::fmt::Display;
+
+use crate::structs::external_type::{array_element, without_modifiers};
+
+/// The modifier of the patterns matching any modifier.
+const WILDCARD: &str = "*";
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// Postgres type parsed into its name and its modifiers, such as `geography`
+/// and `point, 4326` for `geography(Point, 4326)`.
+///
+/// The postgres types of an [`ExternalType`](crate::structs::ExternalType)
+/// are patterns over the parsed types: a pattern without modifiers, such as
+/// `numeric`, matches the type whatever its modifiers, while a pattern with
+/// modifiers, such as `geography(point, *)`, only matches the types with the
+/// same modifiers, where `*` matches any modifier.
+pub struct PostgresType {
+    /// The lowercase name of the type, without its modifiers.
+    name: String,
+    /// The lowercase modifiers of the type, if it declares any.
+    modifiers: Option<Vec<String>>,
+}
+
+impl PostgresType {
+    /// Parses the provided Postgres type.
+    ///
+    /// # Arguments
+    ///
+    /// * `postgres_type` - The Postgres type, possibly with modifiers.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use synql::prelude::*;
+    ///
+    /// let point = PostgresType::parse("geography(Point, 4326)");
+    /// assert_eq!(point.name(), "geography");
+    /// assert_eq!(point.modifiers(), ["point", "4326"]);
+    /// assert_eq!(point.srid(), Some(4326));
+    /// ```
+    #[must_use]
+    pub fn parse(postgres_type: &str) -> Self {
+        let postgres_type = postgres_type.trim().to_lowercase();
+        let name = without_modifiers(&postgres_type).trim().to_owned();
+        let modifiers = postgres_type.find('(').map(|start| {
+            let mut depth = 0usize;
+            let mut modifiers = vec![String::new()];
+            for character in postgres_type[start + 1..].chars() {
+                match character {
+                    ')' if depth == 0 => break,
+                    ',' if depth == 0 => modifiers.push(String::new()),
+                    character => {
+                        match character {
+                            '(' => depth += 1,
+                            ')' => depth -= 1,
+                            _ => {}
+                        }
+                        if let Some(modifier) = modifiers.last_mut() {
+                            modifier.push(character);
+                        }
+                    }
+                }
+            }
+            modifiers
+                .into_iter()
+                .map(|modifier| modifier.split_whitespace().collect::<Vec<_>>().join(" "))
+                .filter(|modifier| !modifier.is_empty())
+                .collect()
+        });
+        Self { name, modifiers }
+    }
+
+    /// Returns the name of the type, without its modifiers.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the modifiers of the type, such as `["10", "2"]` for
+    /// `numeric(10, 2)`.
+    #[must_use]
+    pub fn modifiers(&self) -> &[String] {
+        self.modifiers.as_deref().unwrap_or_default()
+    }
+
+    /// Returns whether the type declares modifiers.
+    #[must_use]
+    pub fn is_parameterized(&self) -> bool {
+        self.modifiers.is_some()
+    }
+
+    /// Returns whether the current type is matched by the provided pattern.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - The pattern, whose `*` modifiers match any modifier.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use synql::prelude::*;
+    ///
+    /// let point = PostgresType::parse("geometry(point, 3857)");
+    /// assert!(point.matches(&PostgresType::parse("geometry(point, *)")));
+    /// assert!(point.matches(&PostgresType::parse("geometry")));
+    /// assert!(!point.matches(&PostgresType::parse("geometry(linestring, *)")));
+    /// ```
+    #[must_use]
+    pub fn matches(&self, pattern: &PostgresType) -> bool {
+        self.matched_modifiers(pattern).is_some()
+    }
+
+    /// Returns the modifiers of the current type which are not fixed by the
+    /// provided pattern, that is the ones matched by its `*` modifiers or all
+    /// of them if the pattern has no modifiers, if the pattern matches.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - The pattern, whose `*` modifiers match any modifier.
+    #[must_use]
+    pub fn matched_modifiers(&self, pattern: &PostgresType) -> Option<Vec<&str>> {
+        if self.name != pattern.name {
+            return None;
+        }
+        let Some(pattern_modifiers) = &pattern.modifiers else {
+            return Some(self.modifiers().iter().map(String::as_str).collect());
+        };
+        let modifiers = self.modifiers.as_ref()?;
+        if modifiers.len() != pattern_modifiers.len() {
+            return None;
+        }
+        let mut matched_modifiers = Vec::new();
+        for (modifier, pattern_modifier) in modifiers.iter().zip(pattern_modifiers) {
+            if pattern_modifier == WILDCARD {
+                matched_modifiers.push(modifier.as_str());
+            } else if modifier != pattern_modifier {
+                return None;
+            }
+        }
+        Some(matched_modifiers)
+    }
+
+    /// Returns whether the current type, used as a pattern, is valid, that is
+    /// whether its name is not empty and its `*` wildcards only appear as
+    /// whole modifiers.
+    pub(crate) fn is_valid_pattern(&self) -> bool {
+        !self.name.is_empty()
+            && !self.name.contains(WILDCARD)
+            && self
+                .modifiers()
+                .iter()
+                .all(|modifier| modifier == WILDCARD || !modifier.contains(WILDCARD))
+    }
+
+    /// Returns the element type of the current type, alongside its modifiers,
+    /// if the current type is an array.
+    pub(crate) fn array_element(&self) -> Option<PostgresType> {
+        Some(Self { name: array_element(&self.name)?, modifiers: self.modifiers.clone() })
+    }
+
+    /// Returns the current type renamed to the provided name, such as the
+    /// normalized name of the type, keeping its modifiers.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The new name of the type, whose modifiers are ignored.
+    #[must_use]
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = without_modifiers(&name.trim().to_lowercase()).trim().to_owned();
+        self
+    }
+
+    /// Returns the maximum length of the character and bit string types,
+    /// such as `255` for `varchar(255)`, if it is declared.
+    #[must_use]
+    pub fn max_length(&self) -> Option<u32> {
+        if !matches!(
+            self.name.as_str(),
+            "varchar"
+                | "character varying"
+                | "char"
+                | "character"
+                | "bpchar"
+                | "bit"
+                | "bit varying"
+                | "varbit"
+        ) {
+            return None;
+        }
+        match self.modifiers() {
+            [max_length] => max_length.parse().ok(),
+            _ => None,
+        }
+    }
+
+    /// Returns the precision of the `numeric` and `decimal` types, such as
+    /// `10` for `numeric(10, 2)`, if it is declared.
+    #[must_use]
+    pub fn precision(&self) -> Option<u32> {
+        if !matches!(self.name.as_str(), "numeric" | "decimal") {
+            return None;
+        }
+        match self.modifiers() {
+            [precision] | [precision, _] => precision.parse().ok(),
+            _ => None,
+        }
+    }
+
+    /// Returns the scale of the `numeric` and `decimal` types, such as `2` for
+    /// `numeric(10, 2)` and `0` for `numeric(10)`, if it is declared.
+    #[must_use]
+    pub fn scale(&self) -> Option<i64> {
+        let _precision = self.precision()?;
+        match self.modifiers() {
+            [_, scale] => scale.parse().ok(),
+            _ => Some(0),
+        }
+    }
+
+    /// Returns the spatial reference identifier of the `geometry` and
+    /// `geography` types, such as `4326` for `geography(point, 4326)`, if it
+    /// is declared.
+    #[must_use]
+    pub fn srid(&self) -> Option<i32> {
+        if !matches!(self.name.as_str(), "geometry" | "geography") {
+            return None;
+        }
+        match self.modifiers() {
+            [_, srid] => srid.parse().ok(),
+            _ => None,
+        }
+    }
+}
+
+impl Display for PostgresType {
+    /// Formats the type with its modifiers following its name, but before
+    /// the time zone of the temporal types and the brackets of the arrays,
+    /// such as `timestamp(3) with time zone` and `varchar(255)[]`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Some(modifiers) = &self.modifiers else {
+            return f.write_str(&self.name);
+        };
+        let split = [self.name.find(" with"), self.name.find('[')]
+            .into_iter()
+            .flatten()
+            .min()
+            .unwrap_or(self.name.len());
+        let (name, suffix) = self.name.split_at(split);
+        write!(f, "{name}({}){suffix}", modifiers.join(", "))
+    }
+}
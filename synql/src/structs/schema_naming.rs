@@ -0,0 +1,72 @@
+//! Submodule defining the `SchemaNaming` enum, describing how the schema of a
+//! table is reflected in the names of the generated crates and idents.
+
+use heck::ToSnakeCase;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// Scheme used to qualify the names of the generated crates and idents with
+/// the schema of the corresponding tables.
+///
+/// A qualified table named `users` in the `auth` schema is generated in the
+/// `{workspace}-auth_users` crate, and re-exported as `auth_users` and
+/// `AuthUser` by the sink crates.
+pub enum SchemaNaming {
+    /// The schema of the tables is ignored.
+    Unqualified,
+    /// The tables of all schemas but the provided one are qualified.
+    QualifyExcept(String),
+    /// The tables of all schemas are qualified.
+    Qualified,
+}
+
+impl Default for SchemaNaming {
+    /// By default, the tables of the `public` schema are not qualified.
+    fn default() -> Self {
+        Self::QualifyExcept("public".to_string())
+    }
+}
+
+impl SchemaNaming {
+    /// Returns the schema qualifying the names of the tables of the provided
+    /// schema, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `schema` - The schema of the table, if any.
+    #[must_use]
+    pub fn qualifier<'schema>(&self, schema: Option<&'schema str>) -> Option<&'schema str> {
+        let schema = schema?;
+        match self {
+            Self::Unqualified => None,
+            Self::QualifyExcept(unqualified_schema) if unqualified_schema == schema => None,
+            Self::QualifyExcept(_) | Self::Qualified => Some(schema),
+        }
+    }
+
+    /// Returns the provided snake-cased name, qualified with the provided
+    /// schema according to the naming scheme.
+    ///
+    /// # Arguments
+    ///
+    /// * `schema` - The schema of the table, if any.
+    /// * `snake_name` - The snake-cased name to qualify.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use synql::prelude::*;
+    ///
+    /// let naming = SchemaNaming::default();
+    /// assert_eq!(naming.qualify(Some("public"), "users"), "users");
+    /// assert_eq!(naming.qualify(Some("auth"), "users"), "auth_users");
+    /// assert_eq!(naming.qualify(None, "users"), "users");
+    /// assert_eq!(SchemaNaming::Qualified.qualify(Some("public"), "users"), "public_users");
+    /// ```
+    #[must_use]
+    pub fn qualify(&self, schema: Option<&str>, snake_name: &str) -> String {
+        match self.qualifier(schema) {
+            Some(schema) => format!("{}_{snake_name}", schema.to_snake_case()),
+            None => snake_name.to_owned(),
+        }
+    }
+}
@@ -1,34 +1,62 @@
 //! Submodule defining the `SQLWorkspace` trait which allows to generate the
 //! `diesel` workspace from a SQL schema, based on `sql_traits`.
 
-use std::path::Path;
+use std::{collections::HashMap, path::Path, sync::Arc};
 
 use proc_macro2::TokenStream;
 
 mod builder;
+mod column_types;
+mod directives;
+mod identifier_collisions;
+mod type_mappings;
 mod write_crate_lib;
 mod write_crate_toml;
 mod write_sink_crate_lib;
 mod write_sink_crate_toml;
+mod write_types_crate;
 pub use builder::SynQLBuilder;
 use sql_relations::prelude::TableLike;
+use sql_traits::traits::ColumnLike;
 use time_requirements::{prelude::TimeTracker, task::Task};
 
+#[cfg(feature = "rayon")]
+use crate::structs::external_crate::ExternalCrateSource;
 use crate::{
-    structs::{ExternalCrate, TomlDependency, Workspace, external_crate::MaximalNumberOfColumns},
-    traits::{SynQLDatabaseLike, table::TableSynLike},
+    structs::{
+        ExternalCrate, GeneratedWorkspace, GenerationReport, GenerationWarning, SchemaNaming,
+        TableSelector, TemporalBackend, TomlDependency, UserDefinedTypes, Workspace,
+        external_crate::MaximalNumberOfColumns, external_type::array_dimensions,
+    },
+    traits::{ColumnSynLike, NamingStrategy, SynQLDatabaseLike, table::TableSynLike},
+    utils::{is_reserved_rust_word, sanitize_identifier},
 };
 
+#[cfg(not(feature = "rayon"))]
 /// Type alias for the callback function used to generate additional code for
 /// tables.
 pub type Callback<'db, T, D> =
     Box<dyn Fn(&T, &D, &Workspace) -> Result<Option<TokenStream>, crate::Error> + 'db>;
 
+#[cfg(feature = "rayon")]
+/// Type alias for the callback function used to generate additional code for
+/// tables, which must be `Sync` as crates are generated in parallel.
+pub type Callback<'db, T, D> =
+    Box<dyn Fn(&T, &D, &Workspace) -> Result<Option<TokenStream>, crate::Error> + Sync + 'db>;
+
+#[cfg(not(feature = "rayon"))]
 /// Type alias for the callback function used to generate additional
 /// dependencies for tables.
 pub type TomlCallback<'db, T, D> =
     Box<dyn Fn(&T, &D) -> Result<Option<TomlDependency>, crate::Error> + 'db>;
 
+#[cfg(feature = "rayon")]
+/// Type alias for the callback function used to generate additional
+/// dependencies for tables, which must be `Sync` as crates are generated in
+/// parallel.
+pub type TomlCallback<'db, T, D> =
+    Box<dyn Fn(&T, &D) -> Result<Option<TomlDependency>, crate::Error> + Sync + 'db>;
+
 /// Struct representing a SQL workspace.
 pub struct SynQL<'db, DB: SynQLDatabaseLike> {
     /// The underlying database which will be used to generate the workspace.
@@ -42,6 +70,42 @@ pub struct SynQL<'db, DB: SynQLDatabaseLike> {
     /// List of tables to be excluded from the workspace, which also imply
     /// excluding all of the tables that depend on them via foreign keys.
     deny_list: Vec<&'db DB::Table>,
+    /// List of tables to be excluded from the workspace while keeping the
+    /// tables that depend on them, whose foreign keys referencing the
+    /// soft-denied tables are elided.
+    soft_deny_list: Vec<&'db DB::Table>,
+    /// Selectors of the tables to be included in the workspace, alongside the
+    /// tables they depend on via foreign keys. When empty, all tables are
+    /// included.
+    allow_list: Vec<TableSelector>,
+    /// The tables selected by the allow list and their dependencies, if an
+    /// allow list was provided.
+    allowed_tables: Option<Vec<&'db DB::Table>>,
+    /// Whether unsupported constructs are skipped with a warning instead of
+    /// aborting the generation.
+    lenient: bool,
+    /// Whether the elements of the arrays are nullable.
+    nullable_array_elements: bool,
+    /// List of tables excluded in lenient mode as they have a column whose type
+    /// is not provided by any external crate, which also imply excluding all
+    /// of the tables that depend on them via foreign keys.
+    excluded_tables: Vec<&'db DB::Table>,
+    /// Warnings describing the tables excluded in lenient mode.
+    warnings: Vec<GenerationWarning>,
+    /// Scheme used to qualify the names of the crates with the schema of the
+    /// corresponding tables.
+    schema_naming: SchemaNaming,
+    /// Crate the date and time columns are mapped to.
+    temporal_backend: TemporalBackend,
+    /// Postgres types pinned to the name of the crate providing them.
+    pinned_postgres_types: Vec<(String, String)>,
+    /// Strategy deriving the names of the crates, structs and fields.
+    naming_strategy: Arc<dyn NamingStrategy>,
+    /// Name of the table, optionally schema-qualified, name of the column and
+    /// Rust type of the columns whose Rust type is overridden.
+    column_types: Vec<(String, String, String)>,
+    /// User-defined types generated in the types crate of the workspace.
+    user_defined_types: UserDefinedTypes,
     /// Version of the generated workspace.
     version: (u8, u8, u8),
     /// Edition of the generated workspace.
@@ -55,9 +119,17 @@ pub struct SynQL<'db, DB: SynQLDatabaseLike> {
     /// Prefix for sink crates generated for each table DAG.
     dag_sink_crate_prefix: Option<String>,
     /// External rust crates to include in the workspace.
+    #[cfg(not(feature = "rayon"))]
     external_crates: Vec<ExternalCrate>,
+    /// External rust crates to include in the workspace, stored in a
+    /// thread-safe representation so that each thread generating crates can
+    /// rebuild its own workspace.
+    #[cfg(feature = "rayon")]
+    external_crates: Vec<ExternalCrateSource>,
     /// Whether to clear workspace directory if it already exists.
     clear_existing: bool,
+    /// Whether to overwrite generated files which were modified by hand.
+    force_overwrite: bool,
     /// Additional workspace members.
     members: Vec<TomlDependency>,
     /// Callbacks to generate additional code for each table.
@@ -86,25 +158,133 @@ impl<'db, DB: SynQLDatabaseLike> SynQL<'db, DB> {
     }
 
     fn skip_table(&self, table: &DB::Table) -> bool {
-        for deny_table in &self.deny_list {
+        if self.allowed_tables.as_ref().is_some_and(|allowed_tables| {
+            !allowed_tables.iter().any(|allowed_table| std::ptr::eq(*allowed_table, table))
+        }) {
+            return true;
+        }
+        for deny_table in self.deny_list.iter().chain(&self.excluded_tables) {
             if table.depends_on(self.database, deny_table) {
                 return true;
             }
         }
+        // The tables extending a soft-denied table cannot be generated without
+        // their ancestor, so they are excluded as well.
+        for soft_deny_table in &self.soft_deny_list {
+            if table == *soft_deny_table
+                || table.ancestral_extended_tables(self.database).contains(soft_deny_table)
+            {
+                return true;
+            }
+        }
         false
     }
 
+    /// Returns whether the table is matched by any of the selectors of the
+    /// allow list.
+    fn is_selected(&self, table: &DB::Table) -> bool {
+        self.allow_list
+            .iter()
+            .any(|selector| selector.matches(table.table_schema(), table.table_name()))
+    }
+
+    /// Collects the tables selected by the allow list, alongside the tables
+    /// they transitively depend on, if an allow list was provided.
+    fn select_allowed_tables(&mut self) {
+        if self.allow_list.is_empty() {
+            return;
+        }
+        let database = self.database;
+        let selected_tables: Vec<&'db DB::Table> =
+            database.tables().filter(|table| self.is_selected(table)).collect();
+        self.allowed_tables = Some(
+            database
+                .tables()
+                .filter(|table| {
+                    selected_tables.iter().any(|selected_table| {
+                        std::ptr::eq(*selected_table, *table)
+                            || selected_table.depends_on(database, table)
+                    })
+                })
+                .collect(),
+        );
+    }
+
+    /// Excludes the tables with a column whose type is not provided by any of
+    /// the external crates, alongside the tables depending on them, recording
+    /// a warning for each of the excluded tables.
+    fn exclude_unmappable_tables(&mut self) {
+        let database = self.database;
+        // The maximal number of columns does not affect the type mapping, while
+        // the external crates which cannot be rebuilt are reported when the
+        // workspace is generated.
+        let Ok(workspace) = self
+            .build_workspace(MaximalNumberOfColumns::Columns16, MaximalNumberOfColumns::Columns16)
+        else {
+            return;
+        };
+
+        let mut unmappable_tables: Vec<&'db DB::Table> = Vec::new();
+        for table in database.tables() {
+            if self.skip_table(table) {
+                continue;
+            }
+            let Some(column) = table
+                .generated_columns(database)
+                .find(|column| column.external_postgres_type(&workspace, database).is_none())
+            else {
+                continue;
+            };
+            let table_name = table.table_name().to_string();
+            let column_name = column.column_name().to_string();
+            let sql_type = column.data_type(database).to_string();
+            self.warnings.push(if array_dimensions(&sql_type) > 1 {
+                GenerationWarning::MultiDimensionalArray { table_name, column_name, sql_type }
+            } else {
+                GenerationWarning::UnmappableColumnType { table_name, column_name, sql_type }
+            });
+            unmappable_tables.push(table);
+        }
+
+        for table in database.tables() {
+            if self.skip_table(table)
+                || unmappable_tables.iter().any(|unmappable| std::ptr::eq(*unmappable, table))
+            {
+                continue;
+            }
+            if let Some(dependency) =
+                unmappable_tables.iter().find(|unmappable| table.depends_on(database, unmappable))
+            {
+                self.warnings.push(GenerationWarning::ExcludedDependency {
+                    table_name: table.table_name().to_string(),
+                    dependency: dependency.table_name().to_string(),
+                });
+            }
+        }
+
+        self.excluded_tables = unmappable_tables;
+    }
+
     /// Writes the workspace TOML.
     ///
+    /// # Arguments
+    ///
+    /// * `workspace` - The workspace being generated.
+    /// * `generated` - The generated workspace the file is written into.
+    ///
     /// # Errors
     ///
-    /// Returns an `std::io::Error` if writing to the file fails.
+    /// Returns an error if a workspace member has no path or if formatting
+    /// the file contents fails.
     #[allow(clippy::too_many_lines)]
-    pub fn write_toml(&self, workspace: &Workspace) -> std::io::Result<()> {
-        use std::io::Write;
+    pub fn write_toml(
+        &self,
+        workspace: &Workspace,
+        generated: &mut GeneratedWorkspace,
+    ) -> Result<(), crate::Error> {
+        use std::fmt::Write;
 
-        let toml_path = self.path.join("Cargo.toml");
-        let mut buffer = std::fs::File::create(toml_path)?;
+        let mut buffer = String::new();
 
         // Write [workspace] section
         writeln!(buffer, "[workspace]")?;
@@ -121,7 +301,7 @@ impl<'db, DB: SynQLDatabaseLike> SynQL<'db, DB> {
             if let Some(path) = member.get_path() {
                 write!(buffer, "\"{path}\"")?;
             } else {
-                return Err(std::io::Error::other("Workspace member MUST start with a path"));
+                return Err(std::io::Error::other("Workspace member MUST start with a path").into());
             }
             wrote = true;
         }
@@ -138,6 +318,14 @@ impl<'db, DB: SynQLDatabaseLike> SynQL<'db, DB> {
             wrote = true;
         }
 
+        if !workspace.user_defined_types().is_empty() {
+            if wrote {
+                write!(buffer, ", ")?;
+            }
+            write!(buffer, "\"{}\"", workspace.types_crate_relative_path().display())?;
+            wrote = true;
+        }
+
         if let Some(sink_crate_name) = &self.sink_crate_name {
             if wrote {
                 write!(buffer, ", ")?;
@@ -150,7 +338,7 @@ impl<'db, DB: SynQLDatabaseLike> SynQL<'db, DB> {
                 if self.skip_table(root_table) {
                     continue;
                 }
-                let root_name = root_table.table_snake_name();
+                let root_name = root_table.qualified_table_snake_name(workspace);
                 let sink_crate_name = format!("{prefix}{root_name}");
                 if wrote {
                     write!(buffer, ", ")?;
@@ -220,16 +408,127 @@ impl<'db, DB: SynQLDatabaseLike> SynQL<'db, DB> {
         writeln!(buffer, "redundant_explicit_links = \"forbid\"")?;
         writeln!(buffer, "invalid_rust_codeblocks = \"forbid\"")?;
 
+        generated.insert("Cargo.toml", buffer);
+
         Ok(())
     }
 
-    #[allow(clippy::too_many_lines)]
-    /// Executes the workspace generation.
+    /// Returns the `Workspace` describing the crates to be generated.
+    ///
+    /// # Errors
+    ///
+    /// * Returns [`UnmatchedTableSelector`](crate::Error::UnmatchedTableSelector)
+    ///   if a selector of the allow list does not match any table.
+    /// * Returns [`DuplicateType`](crate::Error::DuplicateType) if two
+    ///   user-defined types share the same name once lowercased.
+    /// * Returns [`InvalidDirective`](crate::Error::InvalidDirective) if the
+    ///   comment of a table or column contains an invalid directive.
+    /// * Returns [`InvalidColumnType`](crate::Error::InvalidColumnType) if the
+    ///   Rust type overriding the one of a column is invalid.
+    /// * Returns [`MultiDimensionalArray`](crate::Error::MultiDimensionalArray)
+    ///   if a column is a multi-dimensional array, unless the workspace is
+    ///   lenient.
+    /// * Returns [`CrateNameCollision`](crate::Error::CrateNameCollision) if
+    ///   two of the crates to be generated share the same name.
+    /// * Returns [`IdentifierCollision`](crate::Error::IdentifierCollision) if
+    ///   two items of the schema would be generated with the same identifier.
+    /// * Returns an error if the database has more columns than supported.
+    fn workspace(&self) -> Result<Workspace, crate::Error> {
+        for selector in &self.allow_list {
+            if !self
+                .database
+                .tables()
+                .any(|table| selector.matches(table.table_schema(), table.table_name()))
+            {
+                return Err(crate::Error::UnmatchedTableSelector(selector.to_string()));
+            }
+        }
+        self.user_defined_types.check()?;
+        let (maximum_number_of_columns, maximum_number_of_columns_in_hierarchy) =
+            self.maximum_number_of_columns()?;
+        let workspace = self
+            .build_workspace(maximum_number_of_columns, maximum_number_of_columns_in_hierarchy)
+            .map_err(crate::Error::InvalidExternalCrate)?;
+        self.check_directives(&workspace)?;
+        self.check_column_types(&workspace)?;
+        self.check_array_dimensions()?;
+        self.check_type_mappings(&workspace)?;
+        self.check_crate_name_collisions(&workspace)?;
+        self.check_identifier_collisions(&workspace)?;
+        Ok(workspace)
+    }
+
+    /// Checks that the crates to be generated have valid and distinct names,
+    /// which may not be the case when the naming strategy derives names which
+    /// are not identifiers, or when homonymous tables of different schemas are
+    /// not qualified by the schema naming scheme.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace` - The workspace being generated.
     ///
     /// # Errors
     ///
-    /// Returns an error if the workspace cannot be written to disk.
-    pub fn generate(&self) -> Result<TimeTracker, crate::Error> {
+    /// * Returns [`InvalidCrateName`](crate::Error::InvalidCrateName) if a
+    ///   crate name is not a valid identifier once its hyphens are replaced
+    ///   with underscores.
+    /// * Returns [`CrateNameCollision`](crate::Error::CrateNameCollision) if
+    ///   two crates share the same name.
+    fn check_crate_name_collisions(&self, workspace: &Workspace) -> Result<(), crate::Error> {
+        let mut crate_names: HashMap<String, String> = HashMap::new();
+        let mut register = |crate_name: String, origin: String| {
+            let crate_ident = crate_name.replace('-', "_");
+            if sanitize_identifier(&crate_ident) != crate_ident
+                || is_reserved_rust_word(&crate_ident)
+            {
+                return Err(crate::Error::InvalidCrateName { crate_name, origin });
+            }
+            match crate_names.insert(crate_name.clone(), origin.clone()) {
+                Some(first) => {
+                    Err(crate::Error::CrateNameCollision { crate_name, first, second: origin })
+                }
+                None => Ok(()),
+            }
+        };
+
+        for table in self.database.tables() {
+            if self.skip_table(table) {
+                continue;
+            }
+            register(
+                table.crate_name(workspace),
+                format!("table `{}`", qualified_sql_name(table)),
+            )?;
+        }
+        if !workspace.user_defined_types().is_empty() {
+            register(workspace.types_crate_name(), "types crate".to_string())?;
+        }
+        if let Some(sink_crate_name) = &self.sink_crate_name {
+            register(sink_crate_name.clone(), "sink crate".to_string())?;
+        }
+        if let Some(prefix) = &self.dag_sink_crate_prefix {
+            for root_table in self.database.root_tables() {
+                if self.skip_table(root_table) {
+                    continue;
+                }
+                register(
+                    format!("{prefix}{}", root_table.qualified_table_snake_name(workspace)),
+                    format!("sink crate of table `{}`", qualified_sql_name(root_table)),
+                )?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the maximal number of columns of the tables, and of the tables
+    /// alongside their ancestors, to be generated.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database has more columns than supported.
+    fn maximum_number_of_columns(
+        &self,
+    ) -> Result<(MaximalNumberOfColumns, MaximalNumberOfColumns), crate::Error> {
         let maximum_number_of_columns: MaximalNumberOfColumns = self
             .database
             .tables()
@@ -265,14 +564,35 @@ impl<'db, DB: SynQLDatabaseLike> SynQL<'db, DB> {
             .unwrap_or(0)
             .try_into()?;
 
-        let workspace: Workspace = Workspace::new()
+        Ok((maximum_number_of_columns, maximum_number_of_columns_in_hierarchy))
+    }
+
+    /// Builds the `Workspace` describing the crates to be generated.
+    ///
+    /// # Arguments
+    ///
+    /// * `maximum_number_of_columns` - The maximal number of columns of a
+    ///   table.
+    /// * `maximum_number_of_columns_in_hierarchy` - The maximal number of
+    ///   columns of a table alongside its ancestors.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the external crates registered on the builder
+    /// cannot be rebuilt from their thread-safe representation.
+    fn build_workspace(
+        &self,
+        maximum_number_of_columns: MaximalNumberOfColumns,
+        maximum_number_of_columns_in_hierarchy: MaximalNumberOfColumns,
+    ) -> Result<Workspace, syn::Error> {
+        let mut workspace = Workspace::new()
             .path(self.path.to_path_buf())
             .crate_base_path(self.crate_base_path.to_path_buf())
             .name(self.name.as_deref().unwrap_or_else(|| self.database.catalog_name()))
             .expect("Invalid workspace name")
-            .external_crates(self.external_crates.iter().cloned())
-            .rosetta_utc()
-            .chrono()
+            .external_crates(self.registered_external_crates()?)
+            .temporal_backend(self.temporal_backend)
+            .bigdecimal()
             .core()
             .std()
             .pgrx_validation()
@@ -284,64 +604,203 @@ impl<'db, DB: SynQLDatabaseLike> SynQL<'db, DB> {
             .rosetta_uuid()
             .version(self.version.0, self.version.1, self.version.2)
             .edition(self.edition)
-            .into();
-
-        if self.clear_existing {
-            // Clear up any directory or file that may already exist at the workspace path
-            if workspace.path().exists() {
-                // We remove all contents of the directory, but we do not remove the directory
-                // itself
-                for entry in std::fs::read_dir(workspace.path())? {
-                    let entry = entry?;
-                    let path = entry.path();
-                    if path.is_dir() {
-                        std::fs::remove_dir_all(path)?;
-                    } else {
-                        std::fs::remove_file(path)?;
-                    }
-                }
-            }
+            .lenient(self.lenient)
+            .nullable_array_elements(self.nullable_array_elements)
+            .schema_naming(self.schema_naming.clone())
+            .naming_strategy(Arc::clone(&self.naming_strategy))
+            .user_defined_types(self.user_defined_types.clone());
+        for (postgres_type, crate_name) in &self.pinned_postgres_types {
+            workspace = workspace.pin_postgres_type(postgres_type, crate_name);
         }
+        for soft_deny_table in &self.soft_deny_list {
+            workspace =
+                workspace.soft_deny(soft_deny_table.table_schema(), soft_deny_table.table_name());
+        }
+        Ok(self.register_column_types(workspace).into())
+    }
 
-        let mut time_tracker = TimeTracker::new("SQL Workspace Generation");
+    #[cfg(not(feature = "rayon"))]
+    #[allow(clippy::unnecessary_wraps)]
+    /// Returns the external crates registered on the builder.
+    ///
+    /// # Errors
+    ///
+    /// Never fails, as the crates are stored as they were registered.
+    fn registered_external_crates(&self) -> Result<Vec<ExternalCrate>, syn::Error> {
+        Ok(self.external_crates.clone())
+    }
 
+    #[cfg(feature = "rayon")]
+    /// Rebuilds the external crates registered on the builder from their
+    /// thread-safe representation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the representation of any of the crates cannot be
+    /// parsed back.
+    fn registered_external_crates(&self) -> Result<Vec<ExternalCrate>, syn::Error> {
+        self.external_crates.iter().map(ExternalCrate::try_from).collect()
+    }
+
+    #[cfg(not(feature = "rayon"))]
+    /// Generates the crates of the tables into the provided
+    /// `GeneratedWorkspace`, one table at a time.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace` - The workspace being generated.
+    /// * `time_tracker` - The time tracker recording the generation tasks.
+    /// * `generated` - The generated workspace the crates are written into.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the code of any of the crates cannot be generated.
+    fn render_table_crates(
+        &self,
+        workspace: &Workspace,
+        time_tracker: &mut TimeTracker,
+        generated: &mut GeneratedWorkspace,
+    ) -> Result<(), crate::Error> {
         for table in self.database.table_dag() {
             if self.skip_table(table) {
                 continue;
             }
 
-            // Create the directory for the crate
-            let crate_path = table.crate_absolute_path(&workspace);
-            std::fs::create_dir_all(&crate_path)?;
-
             let writing_toml = Task::new("writing_crate_toml");
-            self.write_crate_toml(table, &workspace)?;
+            self.write_crate_toml(table, workspace, generated)?;
             time_tracker.add_or_extend_completed_task(writing_toml);
             let writing_lib = Task::new("writing_crate_lib");
-            self.write_crate_lib(table, &workspace)?;
+            self.write_crate_lib(table, workspace, generated)?;
             time_tracker.add_or_extend_completed_task(writing_lib);
         }
+        Ok(())
+    }
+
+    #[cfg(feature = "rayon")]
+    /// Generates the crates of the tables into the provided
+    /// `GeneratedWorkspace`, in parallel.
+    ///
+    /// Since the `syn` types are not thread-safe, each thread builds its own
+    /// copy of the `Workspace`. The `writing_crate_toml` and
+    /// `writing_crate_lib` tasks of the different threads are merged into the
+    /// time tracker, which therefore records the time spent on each of them
+    /// across all threads rather than the wall-clock time.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace` - The workspace being generated, which collects the
+    ///   warnings of the different threads.
+    /// * `time_tracker` - The time tracker recording the generation tasks.
+    /// * `generated` - The generated workspace the crates are written into.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the code of any of the crates cannot be generated.
+    fn render_table_crates(
+        &self,
+        workspace: &Workspace,
+        time_tracker: &mut TimeTracker,
+        generated: &mut GeneratedWorkspace,
+    ) -> Result<(), crate::Error> {
+        use rayon::prelude::*;
+
+        let (maximum_number_of_columns, maximum_number_of_columns_in_hierarchy) =
+            self.maximum_number_of_columns()?;
+        let mut tables: Vec<&DB::Table> = Vec::new();
+        for table in self.database.table_dag() {
+            if !self.skip_table(table) {
+                tables.push(table);
+            }
+        }
+
+        let time_tracker = std::sync::Mutex::new(time_tracker);
+        let complete_task = |task: Task| {
+            time_tracker
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner)
+                .add_or_extend_completed_task(task);
+        };
+        let table_crates = tables
+            .par_iter()
+            .map_init(
+                || {
+                    self.build_workspace(
+                        maximum_number_of_columns,
+                        maximum_number_of_columns_in_hierarchy,
+                    )
+                },
+                |thread_workspace, table| {
+                    let thread_workspace = thread_workspace
+                        .as_ref()
+                        .map_err(|error| crate::Error::InvalidExternalCrate(error.clone()))?;
+                    let mut table_crate = GeneratedWorkspace::new();
+                    let writing_toml = Task::new("writing_crate_toml");
+                    self.write_crate_toml(table, thread_workspace, &mut table_crate)?;
+                    complete_task(writing_toml);
+                    let writing_lib = Task::new("writing_crate_lib");
+                    self.write_crate_lib(table, thread_workspace, &mut table_crate)?;
+                    complete_task(writing_lib);
+                    Ok((table_crate, thread_workspace.take_warnings()))
+                },
+            )
+            .collect::<Result<Vec<(GeneratedWorkspace, Vec<GenerationWarning>)>, crate::Error>>()?;
+        for (table_crate, table_warnings) in table_crates {
+            generated.extend(table_crate);
+            for warning in table_warnings {
+                workspace.warn(warning);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Generates the files of the workspace into a `GeneratedWorkspace`,
+    /// alongside the warnings recorded in lenient mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace` - The workspace being generated.
+    /// * `time_tracker` - The time tracker recording the generation tasks.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the code of any of the crates cannot be generated.
+    #[allow(clippy::too_many_lines)]
+    fn render(
+        &self,
+        workspace: &Workspace,
+        time_tracker: &mut TimeTracker,
+    ) -> Result<GeneratedWorkspace, crate::Error> {
+        let mut generated = GeneratedWorkspace::new();
+
+        if !workspace.user_defined_types().is_empty() {
+            let writing_types_crate = Task::new("writing_types_crate");
+            self.write_types_crate(workspace, &mut generated)?;
+            time_tracker.add_or_extend_completed_task(writing_types_crate);
+        }
+
+        self.render_table_crates(workspace, time_tracker, &mut generated)?;
 
         if let Some(sink_crate_name) = &self.sink_crate_name {
-            let sink_crate_path =
-                workspace.path().join(workspace.crate_base_path()).join(sink_crate_name);
-            std::fs::create_dir_all(&sink_crate_path)?;
+            let sink_crate_path = workspace.crate_base_path().join(sink_crate_name);
 
             let writing_sink_toml = Task::new("writing_sink_crate_toml");
             self.write_sink_crate_toml(
-                &workspace,
+                workspace,
                 sink_crate_name,
                 &sink_crate_path,
                 self.database.tables(),
+                &mut generated,
             )?;
             time_tracker.add_or_extend_completed_task(writing_sink_toml);
 
             let writing_sink_lib = Task::new("writing_sink_crate_lib");
             self.write_sink_crate_lib(
-                &workspace,
+                workspace,
                 sink_crate_name,
                 &sink_crate_path,
                 self.database.tables(),
+                &mut generated,
             )?;
             time_tracker.add_or_extend_completed_task(writing_sink_lib);
         }
@@ -351,37 +810,36 @@ impl<'db, DB: SynQLDatabaseLike> SynQL<'db, DB> {
                 if self.skip_table(root_table) {
                     continue;
                 }
-                let root_name = root_table.table_snake_name();
+                let root_name = root_table.qualified_table_snake_name(workspace);
                 let sink_crate_name = format!("{prefix}{root_name}");
-                let sink_crate_path =
-                    workspace.path().join(workspace.crate_base_path()).join(&sink_crate_name);
-                std::fs::create_dir_all(&sink_crate_path)?;
+                let sink_crate_path = workspace.crate_base_path().join(&sink_crate_name);
 
                 // We identify the tables which are part of the DAG rooted at `root_table`.
                 let dag_tables = || {
                     self.database.tables().filter(|table| {
-                        table.table_name() == root_table.table_name()
-                            || table.depends_on(self.database, root_table)
+                        table == root_table || table.depends_on(self.database, root_table)
                     })
                 };
 
                 let writing_sink_toml =
                     Task::new(&format!("writing_sink_crate_toml_{sink_crate_name}"));
                 self.write_sink_crate_toml(
-                    &workspace,
+                    workspace,
                     &sink_crate_name,
                     &sink_crate_path,
                     dag_tables(),
+                    &mut generated,
                 )?;
                 time_tracker.add_or_extend_completed_task(writing_sink_toml);
 
                 let writing_sink_lib =
                     Task::new(&format!("writing_sink_crate_lib_{sink_crate_name}"));
                 self.write_sink_crate_lib(
-                    &workspace,
+                    workspace,
                     &sink_crate_name,
                     &sink_crate_path,
                     dag_tables(),
+                    &mut generated,
                 )?;
                 time_tracker.add_or_extend_completed_task(writing_sink_lib);
             }
@@ -389,16 +847,108 @@ impl<'db, DB: SynQLDatabaseLike> SynQL<'db, DB> {
 
         if self.generate_workspace_toml {
             let workspace_toml_task = Task::new("workspace_toml");
-            self.write_toml(&workspace)?;
+            self.write_toml(workspace, &mut generated)?;
             time_tracker.add_or_extend_completed_task(workspace_toml_task);
         }
 
         if self.generate_rustfmt {
             let workspace_rustfmt_task = Task::new("workspace_rustfmt");
-            workspace.write_rustfmt()?;
+            workspace.write_rustfmt(&mut generated)?;
             time_tracker.add_or_extend_completed_task(workspace_rustfmt_task);
         }
 
-        Ok(time_tracker)
+        generated.extend_warnings(self.warnings.iter().cloned());
+        generated.extend_warnings(workspace.take_warnings());
+
+        Ok(generated)
+    }
+
+    /// Executes the workspace generation in memory, returning the generated
+    /// files without writing anything to disk, alongside the constructs which
+    /// were skipped in lenient mode.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the code of any of the crates cannot be generated.
+    pub fn generate_in_memory(&self) -> Result<GeneratedWorkspace, crate::Error> {
+        let workspace = self.workspace()?;
+        let mut time_tracker = TimeTracker::new("SQL Workspace Generation");
+        self.render(&workspace, &mut time_tracker)
+    }
+
+    /// Executes the workspace generation.
+    ///
+    /// Files whose contents did not change since the previous generation are
+    /// not rewritten, so that cargo can reuse its build cache.
+    ///
+    /// # Errors
+    ///
+    /// * Returns [`HandEditedFiles`](crate::Error::HandEditedFiles) if any of
+    ///   the previously generated files was modified by hand, unless the
+    ///   `force_overwrite` or `clear_existing` option is set.
+    /// * Returns an error if the workspace cannot be written to disk.
+    pub fn generate(&self) -> Result<GenerationReport, crate::Error> {
+        let workspace = self.workspace()?;
+
+        let mut time_tracker = TimeTracker::new("SQL Workspace Generation");
+
+        let generated = self.render(&workspace, &mut time_tracker)?;
+
+        let mut deleted_files = 0;
+        if self.clear_existing {
+            // Clear up any directory or file that may already exist at the workspace path
+            if workspace.path().exists() {
+                // We remove all contents of the directory, but we do not remove the directory
+                // itself
+                deleted_files = remove_dir_contents(workspace.path())?;
+            }
+        }
+
+        let writing_files = Task::new("writing_files");
+        // The hand-edited files are only preserved when the previous generation
+        // is neither discarded nor explicitly overwritten.
+        let mut file_statistics = if self.force_overwrite || self.clear_existing {
+            generated.overwrite(workspace.path())?
+        } else {
+            generated.write(workspace.path())?
+        };
+        file_statistics.record_deleted(deleted_files);
+        time_tracker.add_or_extend_completed_task(writing_files);
+
+        Ok(GenerationReport::new(time_tracker, file_statistics, generated.warnings().to_vec()))
+    }
+}
+
+/// Returns the name of the provided table, qualified with its schema if any.
+///
+/// # Arguments
+///
+/// * `table` - The table whose name is returned.
+fn qualified_sql_name<T: TableLike>(table: &T) -> String {
+    match table.table_schema() {
+        Some(schema) => format!("{schema}.{}", table.table_name()),
+        None => table.table_name().to_string(),
+    }
+}
+
+/// Removes all the contents of the provided directory, returning the number of
+/// files which were deleted.
+///
+/// # Arguments
+///
+/// * `path` - The directory to clear.
+fn remove_dir_contents(path: &Path) -> std::io::Result<usize> {
+    let mut deleted_files = 0;
+    for entry in std::fs::read_dir(path)? {
+        let entry = entry?;
+        let path = entry.path();
+        if entry.file_type()?.is_dir() {
+            deleted_files += remove_dir_contents(&path)?;
+            std::fs::remove_dir(path)?;
+        } else {
+            std::fs::remove_file(path)?;
+            deleted_files += 1;
+        }
     }
+    Ok(deleted_files)
 }
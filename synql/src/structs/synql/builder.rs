@@ -1,13 +1,18 @@
 //! Submodule providing a builder for the `SynQL` struct.
 
-use std::path::Path;
+use std::{path::Path, sync::Arc};
 
 use proc_macro2::TokenStream;
 
 use super::{Callback, SynQL, TomlCallback};
+#[cfg(feature = "rayon")]
+use crate::structs::external_crate::ExternalCrateSource;
 use crate::{
-    structs::{ExternalCrate, TomlDependency, Workspace},
-    traits::SynQLDatabaseLike,
+    structs::{
+        DefaultNamingStrategy, ExternalCrate, SchemaNaming, TableSelector, TemporalBackend,
+        TomlDependency, UserDefinedTypes, Workspace,
+    },
+    traits::{MaybeSync, NamingStrategy, SynQLDatabaseLike},
 };
 
 /// Struct to build `SynQL` instances.
@@ -16,8 +21,20 @@ pub struct SynQLBuilder<'db, DB: SynQLDatabaseLike> {
     path: &'db Path,
     crate_base_path: &'db Path,
     clear_existing: bool,
+    force_overwrite: bool,
+    lenient: bool,
+    nullable_array_elements: bool,
+    disambiguate_identifiers: bool,
     name: Option<String>,
     deny_list: Vec<&'db DB::Table>,
+    soft_deny_list: Vec<&'db DB::Table>,
+    allow_list: Vec<TableSelector>,
+    schema_naming: SchemaNaming,
+    temporal_backend: TemporalBackend,
+    pinned_postgres_types: Vec<(String, String)>,
+    naming_strategy: Arc<dyn NamingStrategy>,
+    column_types: Vec<(String, String, String)>,
+    user_defined_types: UserDefinedTypes,
     version: (u8, u8, u8),
     edition: u16,
     generate_workspace_toml: bool,
@@ -41,8 +58,20 @@ impl<'db, DB: SynQLDatabaseLike> SynQLBuilder<'db, DB> {
             path,
             crate_base_path,
             clear_existing: false,
+            force_overwrite: false,
+            lenient: false,
+            nullable_array_elements: false,
+            disambiguate_identifiers: false,
             name: None,
             deny_list: Vec::new(),
+            soft_deny_list: Vec::new(),
+            allow_list: Vec::new(),
+            schema_naming: SchemaNaming::default(),
+            temporal_backend: TemporalBackend::default(),
+            pinned_postgres_types: Vec::new(),
+            naming_strategy: Arc::new(DefaultNamingStrategy),
+            column_types: Vec::new(),
+            user_defined_types: UserDefinedTypes::default(),
             version: (0, 1, 0),
             edition: 2024,
             generate_workspace_toml: false,
@@ -72,6 +101,192 @@ impl<'db, DB: SynQLDatabaseLike> SynQLBuilder<'db, DB> {
         self
     }
 
+    /// Sets the soft deny list for the `SynQL` instance.
+    ///
+    /// Unlike the deny list, the tables depending on a soft-denied table are
+    /// kept in the workspace: the foreign keys referencing the soft-denied
+    /// table become plain columns, without any `foreign_key` or `belongs_to`
+    /// decorator nor crate dependency, and their documentation notes the
+    /// elided foreign key. Tables extending a soft-denied table are still
+    /// excluded, as they cannot be generated without their ancestor.
+    #[must_use]
+    #[inline]
+    pub fn soft_deny_list(mut self, soft_deny_list: Vec<&'db DB::Table>) -> Self {
+        self.soft_deny_list = soft_deny_list;
+        self
+    }
+
+    /// Adds a table to the soft deny list.
+    #[must_use]
+    #[inline]
+    pub fn soft_deny(mut self, table: &'db DB::Table) -> Self {
+        self.soft_deny_list.push(table);
+        self
+    }
+
+    /// Sets the allow list for the `SynQL` instance.
+    ///
+    /// When the allow list is not empty, only the selected tables and the
+    /// tables they transitively depend on via foreign keys are generated.
+    #[must_use]
+    #[inline]
+    pub fn allow_list<I>(mut self, allow_list: I) -> Self
+    where
+        I: IntoIterator<Item = TableSelector>,
+    {
+        self.allow_list = allow_list.into_iter().collect();
+        self
+    }
+
+    /// Adds a selector to the allow list.
+    #[must_use]
+    #[inline]
+    pub fn allow_selector(mut self, selector: TableSelector) -> Self {
+        self.allow_list.push(selector);
+        self
+    }
+
+    /// Adds the table with the provided name, optionally schema-qualified, to
+    /// the allow list.
+    #[must_use]
+    #[inline]
+    pub fn allow(self, table_name: &str) -> Self {
+        self.allow_selector(TableSelector::Name(table_name.to_string()))
+    }
+
+    /// Adds all of the tables of the provided schema to the allow list.
+    #[must_use]
+    #[inline]
+    pub fn allow_schema(self, schema: &str) -> Self {
+        self.allow_selector(TableSelector::Schema(schema.to_string()))
+    }
+
+    /// Adds the tables whose name matches the provided glob pattern to the
+    /// allow list.
+    #[must_use]
+    #[inline]
+    pub fn allow_glob(self, pattern: &str) -> Self {
+        self.allow_selector(TableSelector::Glob(pattern.to_string()))
+    }
+
+    /// Adds the tables whose whole name matches the provided regular
+    /// expression to the allow list.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidTableSelector`](crate::Error::InvalidTableSelector) if
+    /// the pattern is not a valid regular expression.
+    #[inline]
+    pub fn allow_regex(self, pattern: &str) -> Result<Self, crate::Error> {
+        Ok(self.allow_selector(TableSelector::regex(pattern)?))
+    }
+
+    /// Sets the scheme used to qualify the names of the crates with the schema
+    /// of the corresponding tables.
+    ///
+    /// By default, the tables of the `public` schema are not qualified, while
+    /// a table `users` in the `auth` schema is generated in the
+    /// `{workspace}-auth_users` crate.
+    #[must_use]
+    #[inline]
+    pub fn schema_naming(mut self, schema_naming: SchemaNaming) -> Self {
+        self.schema_naming = schema_naming;
+        self
+    }
+
+    /// Sets the crate the `timestamp`, `timestamptz`, `date` and `time` columns
+    /// are mapped to, alongside the `CURRENT_TIMESTAMP` and `now()` defaults
+    /// and comparisons.
+    ///
+    /// By default, the `timestamptz` columns are mapped to
+    /// `rosetta_utc::TimestampUTC`, while the other columns are mapped to the
+    /// `chrono` types.
+    #[must_use]
+    #[inline]
+    pub fn temporal_backend(mut self, temporal_backend: TemporalBackend) -> Self {
+        self.temporal_backend = temporal_backend;
+        self
+    }
+
+    /// Pins a postgres type, alongside its aliases and arrays, to the external
+    /// crate with the provided name, such as `interval` to `diesel` rather
+    /// than `chrono`.
+    ///
+    /// When several crates provide the same postgres type, it is mapped to
+    /// the crate it is pinned to, or otherwise to the crate with the highest
+    /// [`priority`](crate::structs::ExternalCrateBuilder::priority). The
+    /// generation fails if several crates with the same priority provide a
+    /// type which is not pinned, unless it is lenient.
+    ///
+    /// # Arguments
+    ///
+    /// * `postgres_type` - The postgres type to pin.
+    /// * `crate_name` - The name of the crate providing the type.
+    #[must_use]
+    #[inline]
+    pub fn pin_postgres_type(mut self, postgres_type: &str, crate_name: &str) -> Self {
+        self.pinned_postgres_types.push((postgres_type.to_owned(), crate_name.to_owned()));
+        self
+    }
+
+    /// Sets the strategy deriving the names of the generated crates, structs
+    /// and fields from the names of the tables and columns.
+    ///
+    /// # Arguments
+    ///
+    /// * `naming_strategy` - The naming strategy, such as
+    ///   [`NamingOverrides`](crate::structs::NamingOverrides).
+    #[must_use]
+    #[inline]
+    pub fn naming_strategy<N: NamingStrategy + 'static>(mut self, naming_strategy: N) -> Self {
+        self.naming_strategy = Arc::new(naming_strategy);
+        self
+    }
+
+    /// Overrides the Rust type of a column, such as an `Email` newtype for the
+    /// `email` column of the `users` table.
+    ///
+    /// The type must be provided by one of the external crates of the
+    /// workspace, which becomes a dependency of the crate of the table. It
+    /// must implement the diesel traits of the SQL type of the column, while
+    /// the traits it supports determine the derives of the struct of the
+    /// table. The default value of the column is converted with `From` from
+    /// the one of the type it replaces. A type overridden on the builder takes
+    /// precedence over the one of a `type` directive.
+    ///
+    /// # Arguments
+    ///
+    /// * `table_name` - The name of the table, optionally schema-qualified.
+    /// * `column_name` - The name of the column.
+    /// * `rust_type` - The Rust type of the column, such as `email::Email`.
+    #[must_use]
+    #[inline]
+    pub fn column_type(mut self, table_name: &str, column_name: &str, rust_type: &str) -> Self {
+        self.column_types.push((
+            table_name.to_string(),
+            column_name.to_string(),
+            rust_type.to_string(),
+        ));
+        self
+    }
+
+    /// Sets the user-defined types of the database, such as the enumerated
+    /// types declared with `CREATE TYPE mood AS ENUM (...)`.
+    ///
+    /// The types are generated in the `{workspace}-types` crate, on which
+    /// the crates of the tables using them depend.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_defined_types` - The user-defined types, usually parsed from the
+    ///   schema with [`UserDefinedTypes::parse`].
+    #[must_use]
+    #[inline]
+    pub fn user_defined_types(mut self, user_defined_types: UserDefinedTypes) -> Self {
+        self.user_defined_types = user_defined_types;
+        self
+    }
+
     /// Sets the name of the workspace.
     #[must_use]
     #[inline]
@@ -120,6 +335,59 @@ impl<'db, DB: SynQLDatabaseLike> SynQLBuilder<'db, DB> {
         self
     }
 
+    #[must_use]
+    #[inline]
+    /// Sets to overwrite generated files even if they were modified by hand
+    /// since their generation, discarding the manual changes.
+    pub fn force_overwrite(mut self) -> Self {
+        self.force_overwrite = true;
+        self
+    }
+
+    #[must_use]
+    #[inline]
+    /// Sets to skip the unsupported constructs instead of aborting the
+    /// generation.
+    ///
+    /// Check constraints which cannot be translated are dropped from the
+    /// validation impls and replaced by a `// TODO` comment, while tables with
+    /// a column whose type is not provided by any external crate are excluded
+    /// alongside their dependents, as the deny list does. Everything skipped
+    /// is reported in the warnings of the
+    /// [`GenerationReport`](crate::structs::GenerationReport).
+    pub fn lenient(mut self) -> Self {
+        self.lenient = true;
+        self
+    }
+
+    #[must_use]
+    #[inline]
+    /// Sets to map the arrays to vectors of optional elements, such as
+    /// `Vec<Option<i32>>` for an `integer[]` column, as Postgres allows arrays
+    /// to contain `NULL` elements.
+    ///
+    /// By default, the elements of the arrays are assumed not to be `NULL`,
+    /// and the arrays are mapped to vectors such as `Vec<i32>`.
+    pub fn nullable_array_elements(mut self) -> Self {
+        self.nullable_array_elements = true;
+        self
+    }
+
+    #[must_use]
+    #[inline]
+    /// Sets to rename the fields and structs whose identifiers collide once
+    /// their names are normalized, instead of aborting the generation.
+    ///
+    /// For instance, when a table has both a `MyColumn` and a `my_column`
+    /// column, the field of the latter is generated as `my_column_2`. Every
+    /// rename is reported in the warnings of the
+    /// [`GenerationReport`](crate::structs::GenerationReport), while the
+    /// collisions with the external crates are always reported as errors.
+    pub fn disambiguate_identifiers(mut self) -> Self {
+        self.disambiguate_identifiers = true;
+        self
+    }
+
     /// Adds several external crates to the workspace.
     #[must_use]
     pub fn external_crates<I>(mut self, external_crates: I) -> Self
@@ -185,10 +453,15 @@ impl<'db, DB: SynQLDatabaseLike> SynQLBuilder<'db, DB> {
     }
 
     /// Registers a callback to generate code for a given table.
+    ///
+    /// When the `rayon` feature is enabled, the callback must be `Sync` as the
+    /// crates are generated in parallel.
     #[must_use]
     pub fn callback<F>(mut self, callback: F) -> Self
     where
-        F: Fn(&DB::Table, &DB, &Workspace) -> Result<Option<TokenStream>, crate::Error> + 'db,
+        F: Fn(&DB::Table, &DB, &Workspace) -> Result<Option<TokenStream>, crate::Error>
+            + MaybeSync
+            + 'db,
     {
         self.callbacks.push(Box::new(callback));
         self
@@ -196,10 +469,13 @@ impl<'db, DB: SynQLDatabaseLike> SynQLBuilder<'db, DB> {
 
     /// Registers a callback to generate additional dependencies for a given
     /// table.
+    ///
+    /// When the `rayon` feature is enabled, the callback must be `Sync` as the
+    /// crates are generated in parallel.
     #[must_use]
     pub fn toml_callback<F>(mut self, callback: F) -> Self
     where
-        F: Fn(&DB::Table, &DB) -> Result<Option<TomlDependency>, crate::Error> + 'db,
+        F: Fn(&DB::Table, &DB) -> Result<Option<TomlDependency>, crate::Error> + MaybeSync + 'db,
     {
         self.toml_callbacks.push(Box::new(callback));
         self
@@ -208,23 +484,54 @@ impl<'db, DB: SynQLDatabaseLike> SynQLBuilder<'db, DB> {
 
 impl<'db, DB: SynQLDatabaseLike> From<SynQLBuilder<'db, DB>> for SynQL<'db, DB> {
     fn from(builder: SynQLBuilder<'db, DB>) -> Self {
-        SynQL {
+        let disambiguate_identifiers = builder.disambiguate_identifiers;
+        let mut synql = SynQL {
             database: builder.database,
             clear_existing: builder.clear_existing,
+            force_overwrite: builder.force_overwrite,
             path: builder.path,
             crate_base_path: builder.crate_base_path,
             name: builder.name,
             deny_list: builder.deny_list,
+            soft_deny_list: builder.soft_deny_list,
+            allow_list: builder.allow_list,
+            schema_naming: builder.schema_naming,
+            temporal_backend: builder.temporal_backend,
+            pinned_postgres_types: builder.pinned_postgres_types,
+            naming_strategy: builder.naming_strategy,
+            column_types: builder.column_types,
+            user_defined_types: builder.user_defined_types,
+            allowed_tables: None,
+            lenient: builder.lenient,
+            nullable_array_elements: builder.nullable_array_elements,
+            excluded_tables: Vec::new(),
+            warnings: Vec::new(),
             version: builder.version,
             edition: builder.edition,
             generate_workspace_toml: builder.generate_workspace_toml,
             generate_rustfmt: builder.generate_rustfmt,
             sink_crate_name: builder.sink_crate_name,
             dag_sink_crate_prefix: builder.dag_sink_crate_prefix,
+            #[cfg(not(feature = "rayon"))]
             external_crates: builder.external_crates,
+            #[cfg(feature = "rayon")]
+            external_crates: builder
+                .external_crates
+                .iter()
+                .map(ExternalCrateSource::from)
+                .collect(),
             members: builder.members,
             callbacks: builder.callbacks,
             toml_callbacks: builder.toml_callbacks,
+        };
+        synql.apply_directives();
+        synql.select_allowed_tables();
+        if synql.lenient {
+            synql.exclude_unmappable_tables();
+        }
+        if disambiguate_identifiers {
+            synql.disambiguate_identifiers();
         }
+        synql
     }
 }
@@ -0,0 +1,95 @@
+//! Submodule implementing the resolution and the validation of the Rust types
+//! overriding the ones mapped from the SQL types of specific columns.
+
+use sql_traits::traits::TableLike;
+
+use crate::{
+    structs::{SynQL, TableSelector, Workspace, WorkspaceBuilder},
+    traits::SynQLDatabaseLike,
+};
+
+impl<'db, DB: SynQLDatabaseLike> SynQL<'db, DB> {
+    /// Returns the tables, among those matching the provided name, which have
+    /// a column with the provided name.
+    ///
+    /// # Arguments
+    ///
+    /// * `table_name` - The name of the table, optionally schema-qualified.
+    /// * `column_name` - The name of the column.
+    fn tables_with_column(
+        &self,
+        table_name: &str,
+        column_name: &str,
+    ) -> impl Iterator<Item = &'db DB::Table> {
+        let database = self.database;
+        let selector = TableSelector::Name(table_name.to_owned());
+        database.tables().filter(move |table| {
+            selector.matches(table.table_schema(), table.table_name())
+                && table.column(column_name, database).is_some()
+        })
+    }
+
+    /// Registers the overridden Rust types of the columns in the provided
+    /// workspace builder, skipping those which are not valid types.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace` - The builder of the workspace being generated.
+    pub(super) fn register_column_types(
+        &self,
+        mut workspace: WorkspaceBuilder,
+    ) -> WorkspaceBuilder {
+        for (table_name, column_name, rust_type) in &self.column_types {
+            let Ok(rust_type) = syn::parse_str::<syn::Type>(rust_type) else {
+                continue;
+            };
+            for table in self.tables_with_column(table_name, column_name) {
+                workspace = workspace.column_type(
+                    table.table_schema(),
+                    table.table_name(),
+                    column_name,
+                    rust_type.clone(),
+                );
+            }
+        }
+        workspace
+    }
+
+    /// Checks that the overridden Rust types of the columns are valid types,
+    /// provided by the external crates of the workspace, for columns which
+    /// exist in the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace` - The workspace being generated.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidColumnType`](crate::Error::InvalidColumnType) if:
+    ///
+    /// * The type is not a valid Rust type.
+    /// * No table with the provided name has a column with the provided name.
+    /// * The type is not provided by any of the external crates.
+    pub(super) fn check_column_types(&self, workspace: &Workspace) -> Result<(), crate::Error> {
+        for (table_name, column_name, rust_type) in &self.column_types {
+            let invalid = |reason: String| {
+                crate::Error::InvalidColumnType {
+                    target: format!("column `{column_name}` of table `{table_name}`"),
+                    reason,
+                }
+            };
+            let Ok(parsed_rust_type) = syn::parse_str::<syn::Type>(rust_type) else {
+                return Err(invalid(format!("`{rust_type}` is not a valid Rust type")));
+            };
+            if self.tables_with_column(table_name, column_name).next().is_none() {
+                return Err(invalid("the column does not exist in the database".to_owned()));
+            }
+            if workspace.external_type(&parsed_rust_type).is_none() {
+                return Err(invalid(format!(
+                    "the type `{rust_type}` is not provided by any of the external crates of the workspace"
+                )));
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,150 @@
+//! Submodule implementing the application and the validation of the
+//! `@synql(...)` directives declared in the comments of the tables and
+//! columns.
+
+use std::{borrow::Borrow, sync::Arc};
+
+use sql_traits::traits::{ColumnLike, DatabaseLike, ForeignKeyLike, TableLike, UniqueIndexLike};
+
+use crate::{
+    structs::{NamingOverrides, SynQL, Trait, Workspace},
+    traits::{ColumnSynLike, SynQLDatabaseLike, table::TableSynLike},
+};
+
+impl<DB: SynQLDatabaseLike> SynQL<'_, DB> {
+    /// Applies the directives of the tables and columns: the skipped tables
+    /// are denied, while the renames override the naming strategy.
+    ///
+    /// The invalid directives are ignored, and left to be reported by
+    /// [`check_directives`](Self::check_directives).
+    pub(super) fn apply_directives(&mut self) {
+        let database = self.database;
+        let mut overrides = NamingOverrides::with_base(Arc::clone(&self.naming_strategy));
+        let mut renamed = false;
+        for table in database.tables() {
+            let Ok(directives) = table.table_directives(database) else {
+                continue;
+            };
+            if directives.is_skipped() {
+                self.deny_list.push(table);
+                continue;
+            }
+            if let Some(rename) = directives.rename() {
+                overrides =
+                    overrides.rename_struct(table.table_schema(), table.table_name(), rename);
+                renamed = true;
+            }
+            for column in table.columns(database) {
+                if let Some(rename) =
+                    column.column_directives(database).ok().as_ref().and_then(|d| d.rename())
+                {
+                    overrides = overrides.rename_field(
+                        table.table_schema(),
+                        table.table_name(),
+                        column.column_name(),
+                        rename,
+                    );
+                    renamed = true;
+                }
+            }
+        }
+        if renamed {
+            self.naming_strategy = Arc::new(overrides);
+        }
+    }
+
+    /// Checks that the directives of the tables and columns to be generated
+    /// are well-formed and applicable.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidDirective`](crate::Error::InvalidDirective) if:
+    ///
+    /// * An annotation is malformed or contains an unknown directive.
+    /// * A directive is declared on a table while it only applies to columns,
+    ///   or vice versa.
+    /// * A skipped column is neither nullable nor has a default value, as rows
+    ///   could not be inserted without it, or is part of a key or constraint.
+    /// * A sensitive column is neither nullable nor of a type implementing
+    ///   `Default`, as it could not be deserialized once omitted from the
+    ///   serialized rows.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace` - The workspace being generated.
+    pub(super) fn check_directives(&self, workspace: &Workspace) -> Result<(), crate::Error> {
+        let database = self.database;
+        for table in database.tables() {
+            if self.skip_table(table) {
+                continue;
+            }
+            let table_target = || format!("table `{}`", table.table_name());
+            let directives = table.table_directives(database)?;
+            if let Some(directive) = directives.column_only_directives().first() {
+                return Err(crate::Error::InvalidDirective {
+                    target: table_target(),
+                    reason: format!("the `{directive}` directive only applies to columns"),
+                });
+            }
+
+            for column in table.columns(database) {
+                let column_target = || {
+                    format!("column `{}` of table `{}`", column.column_name(), table.table_name())
+                };
+                let directives = column.column_directives(database)?;
+                if let Some(directive) = directives.table_only_directives().first() {
+                    return Err(crate::Error::InvalidDirective {
+                        target: column_target(),
+                        reason: format!("the `{directive}` directive only applies to tables"),
+                    });
+                }
+                if directives.is_sensitive()
+                    && !column.is_nullable(database)
+                    && !column.supports(Trait::Default, workspace, database)
+                {
+                    return Err(crate::Error::InvalidDirective {
+                        target: column_target(),
+                        reason: "a sensitive column must be nullable or of a type implementing `Default`, as it deserializes to its default value".to_owned(),
+                    });
+                }
+                if !directives.is_skipped() {
+                    continue;
+                }
+                if !column.is_nullable(database) && column.default_value().is_none() {
+                    return Err(crate::Error::InvalidDirective {
+                        target: column_target(),
+                        reason: "a column which is neither nullable nor has a default value cannot be skipped".to_owned(),
+                    });
+                }
+                if self.is_constrained_column(column) {
+                    return Err(crate::Error::InvalidDirective {
+                        target: column_target(),
+                        reason: "a column which is part of a key or constraint cannot be skipped"
+                            .to_owned(),
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns whether the provided column is part of a primary key, foreign
+    /// key, unique index or check constraint.
+    fn is_constrained_column(&self, column: &<DB as DatabaseLike>::Column) -> bool {
+        let database = self.database;
+        let is_column = |other: &<DB as DatabaseLike>::Column| other == column;
+        column.is_primary_key(database)
+            || column.has_non_tautological_check_constraints(database)
+            || database.tables().any(|table| {
+                table.foreign_keys(database).any(|foreign_key| {
+                    foreign_key.host_columns(database).map(Borrow::borrow).any(is_column)
+                        || foreign_key
+                            .referenced_columns(database)
+                            .map(Borrow::borrow)
+                            .any(is_column)
+                }) || table.unique_indices(database).any(|unique_index| {
+                    unique_index.columns(database).map(Borrow::borrow).any(is_column)
+                })
+            })
+    }
+}
@@ -0,0 +1,310 @@
+//! Submodule implementing the detection, and optionally the disambiguation,
+//! of the identifiers which would be generated for distinct items of the
+//! schema once their names are normalized.
+
+use std::{collections::HashMap, sync::Arc};
+
+use sql_traits::traits::{ColumnLike, TableLike};
+
+use super::qualified_sql_name;
+use crate::{
+    structs::{
+        GenerationWarning, NamingOverrides, SynQL, UserDefinedTypes, Workspace,
+        external_crate::MaximalNumberOfColumns,
+    },
+    traits::{ColumnSynLike, NamingStrategy, SynQLDatabaseLike, table::TableSynLike},
+    utils::sanitize_identifier,
+};
+
+#[derive(Debug, Default)]
+/// Namespace of the generated code, mapping each of its identifiers to the
+/// description of the item it was generated for.
+struct Namespace {
+    identifiers: HashMap<String, String>,
+}
+
+impl Namespace {
+    /// Registers an identifier, returning the description of the item which
+    /// already uses it, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `identifier` - The identifier to register.
+    /// * `origin` - Description of the item the identifier is generated for.
+    fn register(&mut self, identifier: &str, origin: String) -> Option<String> {
+        if let Some(first) = self.identifiers.get(identifier) {
+            return Some(first.clone());
+        }
+        self.identifiers.insert(identifier.to_owned(), origin);
+        None
+    }
+
+    /// Returns the description of the item using the provided identifier, if
+    /// any.
+    fn get(&self, identifier: &str) -> Option<&str> {
+        self.identifiers.get(identifier).map(String::as_str)
+    }
+
+    /// Returns the smallest numeric suffix, starting from `2`, which appended
+    /// to the provided identifier with the provided separator yields an
+    /// identifier which is not yet used.
+    fn free_suffix(&self, identifier: &str, separator: &str) -> String {
+        (2_usize..)
+            .map(|index| format!("{separator}{index}"))
+            .find(|suffix| !self.identifiers.contains_key(&format!("{identifier}{suffix}")))
+            .expect("There is always a free suffix")
+    }
+}
+
+/// Renames resolving the collisions of the identifiers, alongside a warning
+/// for each of them.
+struct Disambiguation {
+    /// The naming strategy renaming the fields and the structs.
+    overrides: NamingOverrides<Arc<dyn NamingStrategy>>,
+    /// The user-defined types, with their enum variants renamed.
+    user_defined_types: UserDefinedTypes,
+    /// The warnings recorded for the renames.
+    warnings: Vec<GenerationWarning>,
+}
+
+impl<DB: SynQLDatabaseLike> SynQL<'_, DB> {
+    /// Checks that distinct items of the schema are not generated with the
+    /// same identifier once their names are normalized.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace` - The workspace being generated.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IdentifierCollision`](crate::Error::IdentifierCollision) if
+    /// two items would be generated with the same identifier.
+    pub(super) fn check_identifier_collisions(
+        &self,
+        workspace: &Workspace,
+    ) -> Result<(), crate::Error> {
+        self.identifier_collisions(workspace, false).map(|_| ())
+    }
+
+    /// Renames the fields, the structs and the enum variants which would be
+    /// generated with the same identifier as another item, recording a
+    /// warning for each of them.
+    ///
+    /// The collisions which cannot be resolved by renaming, such as a table
+    /// named after one of the external crates, are left to be reported by
+    /// [`check_identifier_collisions`](Self::check_identifier_collisions).
+    pub(super) fn disambiguate_identifiers(&mut self) {
+        // The maximal number of columns does not affect the naming, while the
+        // external crates which cannot be rebuilt are reported when the
+        // workspace is generated.
+        let Ok(workspace) = self
+            .build_workspace(MaximalNumberOfColumns::Columns16, MaximalNumberOfColumns::Columns16)
+        else {
+            return;
+        };
+        let Ok(disambiguation) = self.identifier_collisions(&workspace, true) else {
+            return;
+        };
+        if !disambiguation.warnings.is_empty() {
+            self.naming_strategy = Arc::new(disambiguation.overrides);
+            self.user_defined_types = disambiguation.user_defined_types;
+            self.warnings.extend(disambiguation.warnings);
+        }
+    }
+
+    /// Detects the items of the schema which would be generated with the same
+    /// identifier, returning the renames resolving them alongside a warning
+    /// for each rename when the collisions are to be disambiguated.
+    ///
+    /// The following namespaces are checked:
+    ///
+    /// * The crates of the tables and the sink crates, against the external
+    ///   crates of the workspace.
+    /// * The diesel modules of the tables, against the external crates of the
+    ///   workspace, as they would shadow them in the generated paths.
+    /// * The fields of each table, against each other and against the diesel
+    ///   module of the table, as diesel does not allow a column to be named
+    ///   after its table.
+    /// * The user-defined types, which are defined side by side in the types
+    ///   crate.
+    /// * The variants of each enum, against each other.
+    /// * The structs of the tables, when they are re-exported side by side by
+    ///   the sink crates.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace` - The workspace being generated.
+    /// * `disambiguate` - Whether the fields, structs and enum variants are
+    ///   renamed instead of reporting their collisions.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IdentifierCollision`](crate::Error::IdentifierCollision) if
+    /// two items would be generated with the same identifier, and the
+    /// collision is not disambiguated.
+    fn identifier_collisions(
+        &self,
+        workspace: &Workspace,
+        disambiguate: bool,
+    ) -> Result<Disambiguation, crate::Error> {
+        let mut overrides = NamingOverrides::with_base(Arc::clone(&self.naming_strategy));
+        let mut user_defined_types = workspace.user_defined_types().clone();
+        let mut warnings = Vec::new();
+        let collision = |identifier: &str, first: &str, second: String| {
+            crate::Error::IdentifierCollision {
+                identifier: identifier.to_owned(),
+                first: first.to_owned(),
+                second,
+            }
+        };
+
+        let mut external_crates = Namespace::default();
+        for external_crate in workspace.external_crates() {
+            external_crates.register(
+                &external_crate.name().replace('-', "_"),
+                format!("external crate `{}`", external_crate.name()),
+            );
+        }
+        if let Some(sink_crate_name) = &self.sink_crate_name {
+            let sink_crate_ident = sink_crate_name.replace('-', "_");
+            if let Some(first) = external_crates.get(&sink_crate_ident) {
+                return Err(collision(&sink_crate_ident, first, "sink crate".to_owned()));
+            }
+        }
+
+        // The user-defined types are defined side by side in the types crate.
+        let mut types = Namespace::default();
+        for postgres_enum in workspace.user_defined_types().enums() {
+            let origin = format!("type `{}`", postgres_enum.name());
+            for identifier in [postgres_enum.ident(), postgres_enum.sql_type_ident()] {
+                let identifier = identifier.to_string();
+                if let Some(first) = types.register(&identifier, origin.clone()) {
+                    return Err(collision(&identifier, &first, origin));
+                }
+            }
+
+            let mut variants = Namespace::default();
+            let mut renamed_enum = postgres_enum.clone();
+            for label in postgres_enum.labels() {
+                let variant_name = postgres_enum.variant_ident(label).to_string();
+                let origin = format!("label `{label}` of type `{}`", postgres_enum.name());
+                let Some(first) = variants.register(&variant_name, origin.clone()) else {
+                    continue;
+                };
+                if !disambiguate {
+                    return Err(collision(&variant_name, &first, origin));
+                }
+                let disambiguated =
+                    format!("{variant_name}{}", variants.free_suffix(&variant_name, ""));
+                renamed_enum = renamed_enum.rename_variant(label, &disambiguated);
+                variants.register(&disambiguated, origin.clone());
+                warnings.push(GenerationWarning::DisambiguatedIdentifier {
+                    renamed: origin,
+                    other: first,
+                    identifier: variant_name,
+                    disambiguated,
+                });
+            }
+            if renamed_enum != *postgres_enum {
+                user_defined_types = user_defined_types.enum_type(renamed_enum);
+            }
+        }
+        for domain in workspace.user_defined_types().domains() {
+            let origin = format!("domain `{}`", domain.name());
+            let identifier = domain.ident().to_string();
+            if let Some(first) = types.register(&identifier, origin.clone()) {
+                return Err(collision(&identifier, &first, origin));
+            }
+        }
+        for composite in workspace.user_defined_types().composites() {
+            let origin = format!("type `{}`", composite.name());
+            for identifier in [composite.ident(), composite.sql_type_ident()] {
+                let identifier = identifier.to_string();
+                if let Some(first) = types.register(&identifier, origin.clone()) {
+                    return Err(collision(&identifier, &first, origin));
+                }
+            }
+        }
+
+        let mut structs = Namespace::default();
+        let has_sink_crates =
+            self.sink_crate_name.is_some() || self.dag_sink_crate_prefix.is_some();
+
+        for table in self.database.tables() {
+            if self.skip_table(table) {
+                continue;
+            }
+            let table_name = qualified_sql_name(table);
+
+            let crate_ident = table.crate_ident(workspace).to_string();
+            if let Some(first) = external_crates.get(&crate_ident) {
+                return Err(collision(
+                    &crate_ident,
+                    first,
+                    format!("crate of table `{table_name}`"),
+                ));
+            }
+            let module_name = sanitize_identifier(table.table_name());
+            let module_origin = format!("diesel module of table `{table_name}`");
+            if let Some(first) = external_crates.get(&module_name) {
+                return Err(collision(&module_name, first, module_origin));
+            }
+
+            let mut fields = Namespace::default();
+            fields.register(&module_name, module_origin);
+            for column in table.generated_columns(self.database) {
+                let field_name = column.column_field_name(workspace, self.database);
+                let origin = format!("column `{}` of table `{table_name}`", column.column_name());
+                let Some(first) = fields.register(&field_name, origin.clone()) else {
+                    continue;
+                };
+                if !disambiguate {
+                    return Err(collision(&field_name, &first, origin));
+                }
+                let disambiguated = format!("{field_name}{}", fields.free_suffix(&field_name, "_"));
+                overrides = overrides.rename_field(
+                    table.table_schema(),
+                    table.table_name(),
+                    column.column_name(),
+                    &disambiguated,
+                );
+                fields.register(&disambiguated, origin.clone());
+                warnings.push(GenerationWarning::DisambiguatedIdentifier {
+                    renamed: origin,
+                    other: first,
+                    identifier: field_name,
+                    disambiguated,
+                });
+            }
+
+            if !has_sink_crates {
+                continue;
+            }
+            let struct_name = table.qualified_struct_ident(workspace).to_string();
+            let origin = format!("struct of table `{table_name}`");
+            let Some(first) = structs.register(&struct_name, origin.clone()) else {
+                continue;
+            };
+            if !disambiguate {
+                return Err(collision(&struct_name, &first, origin));
+            }
+            let suffix = structs.free_suffix(&struct_name, "");
+            let renamed_struct = format!(
+                "{}{suffix}",
+                workspace.naming_strategy().struct_name(table.table_schema(), table.table_name())
+            );
+            overrides =
+                overrides.rename_struct(table.table_schema(), table.table_name(), &renamed_struct);
+            let disambiguated = format!("{struct_name}{suffix}");
+            structs.register(&disambiguated, origin.clone());
+            warnings.push(GenerationWarning::DisambiguatedIdentifier {
+                renamed: origin,
+                other: first,
+                identifier: struct_name,
+                disambiguated,
+            });
+        }
+
+        Ok(Disambiguation { overrides, user_defined_types, warnings })
+    }
+}
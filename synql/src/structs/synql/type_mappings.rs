@@ -0,0 +1,102 @@
+//! Submodule implementing the validation of the mappings of the postgres
+//! types to the types provided by the external crates.
+
+use sql_traits::traits::{ColumnLike, TableLike};
+
+use crate::{
+    structs::{GenerationWarning, SynQL, Workspace, external_type::array_dimensions},
+    traits::{ColumnSynLike, SynQLDatabaseLike},
+};
+
+impl<DB: SynQLDatabaseLike> SynQL<'_, DB> {
+    /// Checks that the pinned postgres types are provided by the crates they
+    /// are pinned to, and that no postgres type is provided by several crates
+    /// with the same priority, unless it is pinned to one of them. Both the
+    /// postgres types listed by the external types and the types of the
+    /// columns of the generated tables are checked, as the latter may only be
+    /// matched by the patterns of the external types.
+    ///
+    /// In lenient mode, the ambiguous postgres types are mapped to the first
+    /// crate providing them, and a warning is recorded for each of them.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace` - The workspace being generated.
+    ///
+    /// # Errors
+    ///
+    /// * Returns
+    ///   [`InvalidPostgresTypePin`](crate::Error::InvalidPostgresTypePin) if a
+    ///   postgres type is pinned to a crate which does not provide it.
+    /// * Returns [`AmbiguousPostgresType`](crate::Error::AmbiguousPostgresType)
+    ///   if a postgres type is provided by several crates with the same
+    ///   priority, unless the workspace is lenient.
+    pub(super) fn check_type_mappings(&self, workspace: &Workspace) -> Result<(), crate::Error> {
+        for (postgres_type, crate_name) in workspace.pinned_postgres_types() {
+            if !workspace.external_crates().any(|external_crate| {
+                external_crate.name() == crate_name
+                    && external_crate.external_postgres_type(postgres_type).is_some()
+            }) {
+                return Err(crate::Error::InvalidPostgresTypePin {
+                    postgres_type: postgres_type.clone(),
+                    crate_name: crate_name.clone(),
+                });
+            }
+        }
+        let mut ambiguous_postgres_types = workspace.ambiguous_postgres_types();
+        for table in self.database.tables() {
+            if self.skip_table(table) {
+                continue;
+            }
+            for column in table.columns(self.database) {
+                let postgres_type = column.postgres_type(self.database).to_string();
+                if ambiguous_postgres_types.iter().any(|(ambiguous, _)| *ambiguous == postgres_type)
+                {
+                    continue;
+                }
+                if let Some(crates) = workspace.ambiguous_postgres_type(&postgres_type) {
+                    ambiguous_postgres_types.push((postgres_type, crates));
+                }
+            }
+        }
+        for (postgres_type, crates) in ambiguous_postgres_types {
+            let crates = crates.into_iter().map(ToOwned::to_owned).collect();
+            if !workspace.is_lenient() {
+                return Err(crate::Error::AmbiguousPostgresType { postgres_type, crates });
+            }
+            workspace.warn(GenerationWarning::AmbiguousPostgresType { postgres_type, crates });
+        }
+        Ok(())
+    }
+
+    /// Checks that none of the columns of the generated tables is a
+    /// multi-dimensional array, such as `integer[][]`, which cannot be decoded
+    /// into a vector of its element type.
+    ///
+    /// In lenient mode, the tables with such a column are excluded beforehand,
+    /// and a warning is recorded for each of them.
+    ///
+    /// # Errors
+    ///
+    /// Returns
+    /// [`MultiDimensionalArray`](crate::Error::MultiDimensionalArray) if a
+    /// column of a generated table is a multi-dimensional array.
+    pub(super) fn check_array_dimensions(&self) -> Result<(), crate::Error> {
+        for table in self.database.tables() {
+            if self.skip_table(table) {
+                continue;
+            }
+            for column in table.columns(self.database) {
+                let sql_type = column.data_type(self.database).to_string();
+                if array_dimensions(&sql_type) > 1 {
+                    return Err(crate::Error::MultiDimensionalArray {
+                        table_name: table.table_name().to_string(),
+                        column_name: column.column_name().to_string(),
+                        sql_type,
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+}
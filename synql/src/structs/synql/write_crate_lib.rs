@@ -1,13 +1,12 @@
 //! Submodule implementing the writing of the crate library files.
 
-use std::io::Write;
-
-use quote::quote;
+use quote::{ToTokens, quote};
 use sql_relations::prelude::{ColumnLike, TableLike};
 
 use crate::{
-    structs::{SynQL, Workspace},
+    structs::{GeneratedWorkspace, SynQL, Workspace},
     traits::{SynQLDatabaseLike, column::ColumnSynLike, table::TableSynLike},
+    utils::sanitize_identifier,
 };
 
 impl<DB: SynQLDatabaseLike> SynQL<'_, DB> {
@@ -16,24 +15,46 @@ impl<DB: SynQLDatabaseLike> SynQL<'_, DB> {
         &self,
         table: &DB::Table,
         workspace: &Workspace,
+        generated: &mut GeneratedWorkspace,
     ) -> Result<(), crate::Error> {
-        // We create the `src` directory if it does not exist
-        let crate_path = table.crate_absolute_path(workspace);
-        let src_path = crate_path.join("src");
-        std::fs::create_dir_all(&src_path)?;
-        // We create the `lib.rs` file
-        let lib_rs_path = src_path.join("lib.rs");
-        let mut buffer = std::fs::File::create(lib_rs_path)?;
-
-        let core_derives = table.supported_core_derives(self.database, workspace);
+        let lib_rs_path = table.crate_relative_path(workspace).join("src").join("lib.rs");
+
+        let mut core_derives = table.supported_core_derives(self.database, workspace);
+        // The `Debug` implementation of the tables with sensitive columns is
+        // generated by hand, so that their fields are redacted.
+        let mut redacted_debug_impl = None;
+        if table.generated_columns(self.database).any(|column| column.is_sensitive(self.database))
+            && core_derives.iter().any(|derive| derive.is_ident("Debug"))
+        {
+            core_derives.retain(|derive| !derive.is_ident("Debug"));
+            redacted_debug_impl =
+                Some(table.generate_redacted_debug_impl(workspace, self.database));
+        }
+        // The derives requested by the directives which are already supported
+        // are not repeated.
+        let mut directive_derives = table.table_directives(self.database)?.derives();
+        directive_derives.retain(|directive_derive| {
+            !core_derives.iter().any(|derive| {
+                derive.to_token_stream().to_string()
+                    == directive_derive.to_token_stream().to_string()
+            })
+        });
+        let directive_derive_decorator = (!directive_derives.is_empty()).then(|| {
+            quote! {
+                #[derive(#(#directive_derives),*)]
+            }
+        });
         let table_name = table.table_name();
-        let camel_case_name = table.table_singular_camel_ident();
+        let camel_case_name = table.struct_ident(workspace);
         let table_ident = table.table_ident();
+        // Tables whose name is not a valid Rust identifier are mapped to the
+        // sanitized ident of their diesel module.
+        let table_sql_name_decorator = (sanitize_identifier(table_name) != table_name)
+            .then(|| quote! {#[table_model(sql_name = #table_name)]});
         let crate_documentation = format!("Auto-generated crate for the `{table_name}` table.");
-        let struct_documentation = table.table_doc(self.database).map_or_else(
-            || format!("Struct representing a row in the `{table_name}` table."),
-            ToString::to_string,
-        );
+        let struct_documentation = table
+            .table_documentation(self.database)
+            .unwrap_or_else(|| format!("Struct representing a row in the `{table_name}` table."));
 
         let mut ancestor_decorator = None;
         let ancestors = table.ancestral_extended_tables_topological(self.database);
@@ -66,8 +87,10 @@ impl<DB: SynQLDatabaseLike> SynQL<'_, DB> {
         if !primary_key_columns.is_empty()
             && (primary_key_columns.len() > 1 || primary_key_columns[0].column_name() != "id")
         {
-            let primary_key_idents: Vec<syn::Ident> =
-                primary_key_columns.iter().map(ColumnSynLike::column_snake_ident).collect();
+            let primary_key_idents: Vec<syn::Ident> = primary_key_columns
+                .iter()
+                .map(|column| column.column_field_ident(workspace, self.database))
+                .collect();
             primary_key_decorator = Some(quote! {
                 #[diesel(primary_key(#(#primary_key_idents),*))]
             });
@@ -93,7 +116,10 @@ impl<DB: SynQLDatabaseLike> SynQL<'_, DB> {
             table.spouses(self.database).filter_map(|spouse_table| {
                 // We only need to include the upper triagular set of these relations,
                 // as the macro is symmetric.
-                if table > spouse_table || table.depends_on(self.database, spouse_table) {
+                if table > spouse_table
+                    || table.depends_on(self.database, spouse_table)
+                    || self.skip_table(spouse_table)
+                {
                     return None;
                 }
 
@@ -111,7 +137,7 @@ impl<DB: SynQLDatabaseLike> SynQL<'_, DB> {
             table.ancestral_table_list_decorator(self.database, workspace);
 
         let fields = table.generate_struct_fields(workspace, self.database)?;
-        let unique_indices = table.unique_indices_macros(self.database);
+        let unique_indices = table.unique_indices_macros(self.database, workspace);
         let foreign_key_decorators = table.foreign_key_decorators(self.database, workspace);
         let check_constraint_impls = table.generate_validation_impls(workspace, self.database)?;
         let belonging_to_decorators =
@@ -141,6 +167,7 @@ impl<DB: SynQLDatabaseLike> SynQL<'_, DB> {
             #[derive(#(#core_derives),*)]
             #[derive(::serde::Serialize, ::serde::Deserialize)]
             #[derive(::diesel::Queryable, ::diesel::Selectable, ::diesel::Identifiable, #derive_associations ::diesel_builders::prelude::TableModel)]
+            #directive_derive_decorator
             #[doc=#struct_documentation]
             #ancestor_decorator
             #error_decorator
@@ -150,9 +177,11 @@ impl<DB: SynQLDatabaseLike> SynQL<'_, DB> {
             #(#foreign_key_decorators)*
             #(#ancestral_table_list_decorator)*
             #[diesel(table_name = #table_ident)]
+            #table_sql_name_decorator
             pub struct #camel_case_name {
                 #(#fields),*
             }
+            #redacted_debug_impl
             #(#unique_indices)*
             #(#check_constraint_impls)*
             #(#ancestral_primary_key_column_getters)*
@@ -160,8 +189,6 @@ impl<DB: SynQLDatabaseLike> SynQL<'_, DB> {
             #(#missing_allow_tables_to_appear_in_same_query)*
         };
 
-        write!(buffer, "{content}")?;
-
-        Ok(())
+        generated.insert_rust(lib_rs_path, content)
     }
 }
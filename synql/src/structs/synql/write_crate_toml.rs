@@ -1,11 +1,11 @@
 //! Submodule implementing the writing of the crate toml files.
 
-use std::{borrow::Borrow, io::Write};
+use std::{borrow::Borrow, fmt::Write};
 
 use sql_relations::prelude::TableLike;
 
 use crate::{
-    structs::{SynQL, Workspace},
+    structs::{GeneratedWorkspace, SynQL, Workspace},
     traits::{SynQLDatabaseLike, table::TableSynLike},
 };
 
@@ -14,9 +14,10 @@ impl<DB: SynQLDatabaseLike> SynQL<'_, DB> {
         &self,
         table: &DB::Table,
         workspace: &Workspace,
+        generated: &mut GeneratedWorkspace,
     ) -> Result<(), crate::Error> {
-        let cargo_toml_path = table.crate_absolute_path(workspace).join("Cargo.toml");
-        let mut buffer = std::fs::File::create(cargo_toml_path)?;
+        let cargo_toml_path = table.crate_relative_path(workspace).join("Cargo.toml");
+        let mut buffer = String::new();
         let name = table.crate_name(workspace);
         let (major, minor, patch) = workspace.version();
 
@@ -89,6 +90,8 @@ edition.workspace = true
         writeln!(buffer, "\n[lints]")?;
         writeln!(buffer, "workspace = true")?;
 
+        generated.insert(cargo_toml_path, buffer);
+
         Ok(())
     }
 }
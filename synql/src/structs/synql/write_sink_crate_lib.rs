@@ -1,11 +1,12 @@
 //! Submodule implementing the writing of the sink crate library files.
 
-use std::{io::Write, path::Path};
+use std::path::Path;
 
 use quote::quote;
+use sql_traits::traits::TableLike;
 
 use crate::{
-    structs::{SynQL, Workspace},
+    structs::{GeneratedWorkspace, SynQL, Workspace},
     traits::{SynQLDatabaseLike, table::TableSynLike},
 };
 
@@ -16,16 +17,12 @@ impl<DB: SynQLDatabaseLike> SynQL<'_, DB> {
         sink_crate_name: &str,
         sink_crate_path: &Path,
         tables: impl Iterator<Item = &'a DB::Table>,
+        generated: &mut GeneratedWorkspace,
     ) -> Result<(), crate::Error>
     where
         DB::Table: 'a,
     {
-        // We create the `src` directory if it does not exist
-        let src_path = sink_crate_path.join("src");
-        std::fs::create_dir_all(&src_path)?;
-        // We create the `lib.rs` file
-        let lib_rs_path = src_path.join("lib.rs");
-        let mut buffer = std::fs::File::create(lib_rs_path)?;
+        let lib_rs_path = sink_crate_path.join("src").join("lib.rs");
 
         let crate_documentation = format!(
             "Auto-generated sink crate `{sink_crate_name}` which re-exports all table crates."
@@ -37,13 +34,25 @@ impl<DB: SynQLDatabaseLike> SynQL<'_, DB> {
                 continue;
             }
             let crate_ident = table.crate_ident(workspace);
-            let table_struct_ident = table.table_singular_camel_ident();
+            let table_struct_ident = table.struct_ident(workspace);
             let table_ident = table.table_ident();
-            re_exports.push(quote! {
-                pub use #crate_ident;
-                pub use #crate_ident::#table_ident;
-                pub use #crate_ident::#table_struct_ident;
-            });
+            // Tables of qualified schemas are re-exported under their
+            // qualified names, so that homonymous tables do not collide.
+            if workspace.schema_naming().qualifier(table.table_schema()).is_some() {
+                let qualified_table_ident = table.qualified_table_snake_ident(workspace);
+                let qualified_struct_ident = table.qualified_struct_ident(workspace);
+                re_exports.push(quote! {
+                    pub use #crate_ident;
+                    pub use #crate_ident::#table_ident as #qualified_table_ident;
+                    pub use #crate_ident::#table_struct_ident as #qualified_struct_ident;
+                });
+            } else {
+                re_exports.push(quote! {
+                    pub use #crate_ident;
+                    pub use #crate_ident::#table_ident;
+                    pub use #crate_ident::#table_struct_ident;
+                });
+            }
         }
 
         let content = quote! {
@@ -52,8 +61,6 @@ impl<DB: SynQLDatabaseLike> SynQL<'_, DB> {
             #(#re_exports)*
         };
 
-        write!(buffer, "{content}")?;
-
-        Ok(())
+        generated.insert_rust(lib_rs_path, content)
     }
 }
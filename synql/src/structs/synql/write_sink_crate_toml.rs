@@ -1,9 +1,9 @@
 //! Submodule implementing the writing of the sink crate toml files.
 
-use std::{io::Write, path::Path};
+use std::{fmt::Write, path::Path};
 
 use crate::{
-    structs::{SynQL, Workspace},
+    structs::{GeneratedWorkspace, SynQL, Workspace},
     traits::{SynQLDatabaseLike, table::TableSynLike},
 };
 
@@ -14,12 +14,13 @@ impl<DB: SynQLDatabaseLike> SynQL<'_, DB> {
         sink_crate_name: &str,
         sink_crate_path: &Path,
         tables: impl Iterator<Item = &'a DB::Table>,
+        generated: &mut GeneratedWorkspace,
     ) -> Result<(), crate::Error>
     where
         DB::Table: 'a,
     {
         let cargo_toml_path = sink_crate_path.join("Cargo.toml");
-        let mut buffer = std::fs::File::create(cargo_toml_path)?;
+        let mut buffer = String::new();
         let (major, minor, patch) = workspace.version();
 
         writeln!(
@@ -46,6 +47,8 @@ edition.workspace = true
         writeln!(buffer, "\n[lints]")?;
         writeln!(buffer, "workspace = true")?;
 
+        generated.insert(cargo_toml_path, buffer);
+
         Ok(())
     }
 }
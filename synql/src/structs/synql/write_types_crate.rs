@@ -0,0 +1,132 @@
+//! Submodule implementing the writing of the types crate, which defines the
+//! user-defined types of the database.
+
+use std::fmt::Write;
+
+use quote::quote;
+
+use crate::{
+    structs::{GeneratedWorkspace, PostgresEnum, SynQL, Workspace},
+    traits::SynQLDatabaseLike,
+};
+
+impl<DB: SynQLDatabaseLike> SynQL<'_, DB> {
+    /// Writes the TOML and the library files of the types crate.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace` - The workspace being generated.
+    /// * `generated` - The generated workspace the files are written into.
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if formatting the files contents fails.
+    /// * Returns an error if the base type of a domain is not provided by any
+    ///   of the external crates, or if one of its checks cannot be translated.
+    pub(super) fn write_types_crate(
+        &self,
+        workspace: &Workspace,
+        generated: &mut GeneratedWorkspace,
+    ) -> Result<(), crate::Error> {
+        let crate_name = workspace.types_crate_name();
+        let crate_path = workspace.types_crate_relative_path();
+        let (major, minor, patch) = workspace.version();
+
+        let mut buffer = String::new();
+        writeln!(
+            buffer,
+            r#"[package]
+name = "{crate_name}"
+version = "{major}.{minor}.{patch}"
+edition.workspace = true
+"#
+        )?;
+        writeln!(buffer, "\n[dependencies]")?;
+        writeln!(buffer, "serde.workspace = true")?;
+        writeln!(buffer, "diesel.workspace = true")?;
+
+        // The domains are validated, and might wrap the types of external
+        // crates, as might the attributes of the composite types.
+        let domains = workspace.user_defined_types().domains().collect::<Vec<_>>();
+        let composites = workspace.user_defined_types().composites().collect::<Vec<_>>();
+        if !domains.is_empty() {
+            writeln!(buffer, "validation-errors.workspace = true")?;
+        }
+        let mut base_types = domains
+            .iter()
+            .map(|domain| domain.base_type(workspace))
+            .collect::<Result<Vec<_>, _>>()?;
+        for composite in &composites {
+            base_types.extend(composite.attribute_types(workspace)?);
+        }
+        let types_crate_name = workspace.types_crate_name();
+        let mut base_crates = base_types
+            .into_iter()
+            .filter(|base_type| base_type.is_dependency())
+            .map(|base_type| base_type.external_crate().name())
+            .filter(|crate_name| {
+                *crate_name != types_crate_name
+                    && !["serde", "diesel", "validation-errors"].contains(crate_name)
+            })
+            .collect::<Vec<_>>();
+        base_crates.sort_unstable();
+        base_crates.dedup();
+        for crate_name in base_crates {
+            writeln!(buffer, "{crate_name}.workspace = true")?;
+        }
+        writeln!(buffer, "\n[lints]")?;
+        writeln!(buffer, "workspace = true")?;
+        generated.insert(crate_path.join("Cargo.toml"), buffer);
+
+        let crate_documentation = format!(
+            "Auto-generated crate `{crate_name}` defining the user-defined types of the database."
+        );
+        let enums = workspace.user_defined_types().enums().map(PostgresEnum::to_syn);
+        let domains = domains
+            .into_iter()
+            .map(|domain| domain.to_syn(workspace, self.database))
+            .collect::<Result<Vec<_>, _>>()?;
+        let composites = composites
+            .into_iter()
+            .map(|composite| composite.to_syn(workspace))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let content = quote! {
+            #![doc = #crate_documentation]
+
+            #[derive(Debug, Clone, PartialEq, Eq)]
+            /// Error raised when parsing a label which does not belong to an
+            /// enumerated type.
+            pub struct UnknownLabelError {
+                /// The name of the enumerated type.
+                type_name: &'static str,
+                /// The unknown label.
+                label: String,
+            }
+
+            impl UnknownLabelError {
+                /// Creates a new `UnknownLabelError`.
+                #[must_use]
+                pub fn new(type_name: &'static str, label: &str) -> Self {
+                    Self { type_name, label: label.to_owned() }
+                }
+            }
+
+            impl ::core::fmt::Display for UnknownLabelError {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    write!(f, "`{}` is not a label of the `{}` type", self.label, self.type_name)
+                }
+            }
+
+            impl ::core::error::Error for UnknownLabelError {}
+
+            #(#enums)*
+
+            #(#domains)*
+
+            #(#composites)*
+        };
+
+        generated.insert_rust(crate_path.join("src").join("lib.rs"), content)
+    }
+}
@@ -0,0 +1,126 @@
+//! Submodule defining the `TableSelector` enum, used to select the tables to
+//! generate by name, by schema or by pattern.
+
+use std::fmt::Display;
+
+use regex::Regex;
+
+#[derive(Debug, Clone)]
+/// Selector of the tables to be included in the generated workspace.
+///
+/// Name, glob and regex selectors match either the name of a table or, for
+/// tables belonging to a schema, their schema-qualified name such as
+/// `billing.invoices`.
+pub enum TableSelector {
+    /// Selects the table with the provided name.
+    Name(String),
+    /// Selects all of the tables of the provided schema.
+    Schema(String),
+    /// Selects the tables whose name matches the provided glob pattern, where
+    /// `*` matches any sequence of characters and `?` any single character.
+    Glob(String),
+    /// Selects the tables whose whole name matches the provided regular
+    /// expression.
+    Regex(Regex),
+}
+
+impl TableSelector {
+    /// Creates a selector matching the tables whose whole name matches the
+    /// provided regular expression.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - The regular expression.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidTableSelector`](crate::Error::InvalidTableSelector) if
+    /// the pattern is not a valid regular expression.
+    pub fn regex(pattern: &str) -> Result<Self, crate::Error> {
+        Regex::new(&format!("^(?:{pattern})$")).map(Self::Regex).map_err(|error| {
+            crate::Error::InvalidTableSelector {
+                selector: pattern.to_string(),
+                reason: error.to_string(),
+            }
+        })
+    }
+
+    /// Returns whether the selector matches the table with the provided
+    /// schema and name.
+    ///
+    /// # Arguments
+    ///
+    /// * `schema` - The schema of the table, if any.
+    /// * `table_name` - The name of the table.
+    #[must_use]
+    pub fn matches(&self, schema: Option<&str>, table_name: &str) -> bool {
+        let qualified_name = schema.map(|schema| format!("{schema}.{table_name}"));
+        let matches_name = |predicate: &dyn Fn(&str) -> bool| {
+            predicate(table_name) || qualified_name.as_deref().is_some_and(predicate)
+        };
+        match self {
+            Self::Name(name) => matches_name(&|candidate| candidate == name),
+            Self::Schema(expected_schema) => schema == Some(expected_schema.as_str()),
+            Self::Glob(pattern) => matches_name(&|candidate| glob_match(pattern, candidate)),
+            Self::Regex(regex) => matches_name(&|candidate| regex.is_match(candidate)),
+        }
+    }
+}
+
+impl Display for TableSelector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Name(name) => write!(f, "table `{name}`"),
+            Self::Schema(schema) => write!(f, "schema `{schema}`"),
+            Self::Glob(pattern) => write!(f, "glob `{pattern}`"),
+            Self::Regex(regex) => {
+                // We strip the anchors added when the regex was compiled.
+                let pattern = regex.as_str();
+                let pattern = pattern
+                    .strip_prefix("^(?:")
+                    .and_then(|pattern| pattern.strip_suffix(")$"))
+                    .unwrap_or(pattern);
+                write!(f, "regex `{pattern}`")
+            }
+        }
+    }
+}
+
+/// Returns whether the provided candidate matches the glob pattern, where `*`
+/// matches any sequence of characters and `?` any single character.
+///
+/// # Arguments
+///
+/// * `pattern` - The glob pattern.
+/// * `candidate` - The string to match.
+fn glob_match(pattern: &str, candidate: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let candidate: Vec<char> = candidate.chars().collect();
+    let (mut pattern_index, mut candidate_index) = (0, 0);
+    // Position of the last `*` in the pattern, and of the candidate character
+    // it was matched against, to backtrack to when a mismatch occurs.
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while candidate_index < candidate.len() {
+        match pattern.get(pattern_index) {
+            Some('*') => {
+                backtrack = Some((pattern_index, candidate_index));
+                pattern_index += 1;
+            }
+            Some(&character) if character == '?' || character == candidate[candidate_index] => {
+                pattern_index += 1;
+                candidate_index += 1;
+            }
+            _ => {
+                let Some((star_index, star_candidate_index)) = backtrack else {
+                    return false;
+                };
+                backtrack = Some((star_index, star_candidate_index + 1));
+                pattern_index = star_index + 1;
+                candidate_index = star_candidate_index + 1;
+            }
+        }
+    }
+
+    pattern[pattern_index..].iter().all(|&character| character == '*')
+}
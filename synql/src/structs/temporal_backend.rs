@@ -0,0 +1,44 @@
+//! Submodule defining the `TemporalBackend` enum, describing the crate the
+//! date and time columns are mapped to.
+
+use crate::structs::ExternalCrate;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+/// Crate providing the Rust types of the `timestamp`, `timestamptz`, `date`
+/// and `time` columns, alongside the current values their `CURRENT_TIMESTAMP`
+/// and `now()` defaults and comparisons are translated to.
+pub enum TemporalBackend {
+    /// The `timestamptz` columns are mapped to `rosetta_utc::TimestampUTC`,
+    /// while the other columns are mapped to the `chrono` types.
+    #[default]
+    RosettaUtc,
+    /// The columns are mapped to the `chrono` types, such as
+    /// `chrono::DateTime<Utc>`.
+    Chrono,
+    /// The columns are mapped to the `time` types, such as
+    /// `time::OffsetDateTime`.
+    Time,
+}
+
+impl TemporalBackend {
+    /// Returns the external crates providing the date and time types, in
+    /// their order of precedence.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use synql::prelude::*;
+    ///
+    /// let crates = TemporalBackend::Time.external_crates();
+    /// assert_eq!(crates.len(), 1);
+    /// assert_eq!(crates[0].name(), "time");
+    /// ```
+    #[must_use]
+    pub fn external_crates(self) -> Vec<ExternalCrate> {
+        match self {
+            Self::RosettaUtc => vec![ExternalCrate::rosetta_utc(), ExternalCrate::chrono()],
+            Self::Chrono => vec![ExternalCrate::chrono()],
+            Self::Time => vec![ExternalCrate::time()],
+        }
+    }
+}
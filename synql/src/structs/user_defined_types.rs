@@ -0,0 +1,439 @@
+//! Submodule defining the `UserDefinedTypes` struct, collecting the types
+//! declared with `CREATE TYPE` which are generated in the types crate of the
+//! workspace.
+
+use std::{collections::HashMap, path::Path};
+
+use sqlparser::{
+    ast::{ObjectName, Statement, TableConstraint, UserDefinedTypeRepresentation},
+    dialect::Dialect,
+    parser::Parser,
+};
+use strum::IntoEnumIterator;
+
+use crate::{
+    structs::{
+        ExternalCrate, ExternalType, PostgresComposite, PostgresDomain, PostgresEnum, PostgresType,
+        Trait, external_type::array_element, workspace::resolve_postgres_type,
+    },
+    utils::sanitized_ident,
+};
+
+#[derive(Debug, Clone, Default)]
+/// The user-defined types of the database, which are generated in a types
+/// crate shared by the crates of the tables using them.
+///
+/// The enumerated and composite types are declared with `CREATE TYPE ... AS
+/// ENUM` and `CREATE TYPE ... AS (...)`, while the domains are declared with
+/// `CREATE DOMAIN`.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use sqlparser::dialect::GenericDialect;
+/// use synql::prelude::*;
+///
+/// let types = UserDefinedTypes::parse::<GenericDialect>(
+///     "CREATE TYPE mood AS ENUM ('sad', 'ok', 'happy');",
+/// )?;
+/// assert_eq!(types.enums().next().unwrap().labels(), ["sad", "ok", "happy"]);
+/// # Ok(())
+/// # }
+/// ```
+pub struct UserDefinedTypes {
+    /// The enumerated types.
+    enums: Vec<PostgresEnum>,
+    /// The domains.
+    domains: Vec<PostgresDomain>,
+    /// The composite types, in their declaration order.
+    composites: Vec<PostgresComposite>,
+}
+
+/// Returns the schema, if any, and the name of the provided object name.
+///
+/// # Arguments
+///
+/// * `name` - The possibly qualified name of the object.
+fn split_name(name: &ObjectName) -> Option<(Option<String>, String)> {
+    let mut parts =
+        name.0.iter().map(|part| part.to_string().trim_matches('"').to_owned()).collect::<Vec<_>>();
+    let type_name = parts.pop()?;
+    Some((parts.pop(), type_name))
+}
+
+/// Replaces the first of the provided items which is the same as the provided
+/// one, or adds it if there is none.
+///
+/// # Arguments
+///
+/// * `items` - The items.
+/// * `item` - The item to add.
+/// * `is_same` - Whether an existing item is the same as the added one.
+fn replace_or_push<T>(items: &mut Vec<T>, item: T, is_same: impl Fn(&T, &T) -> bool) {
+    match items.iter_mut().find(|existing| is_same(existing, &item)) {
+        Some(existing) => *existing = item,
+        None => items.push(item),
+    }
+}
+
+/// Adds the provided type to the types of the crate, unless it could not be
+/// described or one of its postgres types is already provided, in which case
+/// the type is reported by [`check`](UserDefinedTypes::check).
+///
+/// # Arguments
+///
+/// * `types` - The types of the crate.
+/// * `external_type` - The type to add, if it could be described.
+fn push_type(types: &mut Vec<ExternalType>, external_type: Option<ExternalType>) {
+    let Some(external_type) = external_type else {
+        return;
+    };
+    if !external_type
+        .patterns()
+        .iter()
+        .any(|pattern| types.iter().flat_map(ExternalType::patterns).any(|other| other == pattern))
+    {
+        types.push(external_type);
+    }
+}
+
+impl UserDefinedTypes {
+    /// Parses the user-defined types declared in the provided SQL, ignoring
+    /// any other statement.
+    ///
+    /// # Arguments
+    ///
+    /// * `sql` - The SQL declaring the types, usually the whole schema.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidUserDefinedTypes`](crate::Error::InvalidUserDefinedTypes)
+    /// if the SQL cannot be parsed.
+    pub fn parse<D: Dialect + Default>(sql: &str) -> Result<Self, crate::Error> {
+        let statements = Parser::parse_sql(&D::default(), sql)
+            .map_err(|error| crate::Error::InvalidUserDefinedTypes(error.to_string()))?;
+        let mut types = Self::default();
+        for statement in statements {
+            match statement {
+                Statement::CreateType {
+                    name,
+                    representation: Some(UserDefinedTypeRepresentation::Enum { labels }),
+                    ..
+                } => {
+                    let Some((schema, type_name)) = split_name(&name) else {
+                        continue;
+                    };
+                    let mut postgres_enum =
+                        PostgresEnum::new(&type_name, labels.into_iter().map(|label| label.value));
+                    if let Some(schema) = &schema {
+                        postgres_enum = postgres_enum.schema(schema);
+                    }
+                    types = types.enum_type(postgres_enum);
+                }
+                Statement::CreateType {
+                    name,
+                    representation: Some(UserDefinedTypeRepresentation::Composite { attributes }),
+                    ..
+                } => {
+                    let Some((schema, type_name)) = split_name(&name) else {
+                        continue;
+                    };
+                    let mut composite = PostgresComposite::new(
+                        &type_name,
+                        attributes.into_iter().map(|attribute| {
+                            (attribute.name.value, attribute.data_type.to_string())
+                        }),
+                    );
+                    if let Some(schema) = &schema {
+                        composite = composite.schema(schema);
+                    }
+                    types = types.composite(composite);
+                }
+                Statement::CreateDomain(create_domain) => {
+                    let Some((schema, domain_name)) = split_name(&create_domain.name) else {
+                        continue;
+                    };
+                    let mut domain =
+                        PostgresDomain::new(&domain_name, &create_domain.data_type.to_string());
+                    if let Some(schema) = &schema {
+                        domain = domain.schema(schema);
+                    }
+                    for constraint in create_domain.constraints {
+                        if let TableConstraint::Check(check) = constraint {
+                            domain = domain.check(*check.expr);
+                        }
+                    }
+                    types = types.domain(domain);
+                }
+                _ => {}
+            }
+        }
+        Ok(types)
+    }
+
+    /// Adds an enumerated type, replacing in place any type with the same
+    /// schema and name.
+    ///
+    /// # Arguments
+    ///
+    /// * `postgres_enum` - The enumerated type.
+    #[must_use]
+    pub fn enum_type(mut self, postgres_enum: PostgresEnum) -> Self {
+        replace_or_push(&mut self.enums, postgres_enum, |existing, postgres_enum| {
+            existing.get_schema() == postgres_enum.get_schema()
+                && existing.name() == postgres_enum.name()
+        });
+        self
+    }
+
+    /// Adds a domain, replacing in place any domain with the same schema and
+    /// name.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain` - The domain.
+    #[must_use]
+    pub fn domain(mut self, domain: PostgresDomain) -> Self {
+        replace_or_push(&mut self.domains, domain, |existing, domain| {
+            existing.get_schema() == domain.get_schema() && existing.name() == domain.name()
+        });
+        self
+    }
+
+    /// Adds a composite type, replacing in place any type with the same
+    /// schema and name, so that the types declared after it may still depend
+    /// on it.
+    ///
+    /// # Arguments
+    ///
+    /// * `composite` - The composite type.
+    #[must_use]
+    pub fn composite(mut self, composite: PostgresComposite) -> Self {
+        replace_or_push(&mut self.composites, composite, |existing, composite| {
+            existing.get_schema() == composite.get_schema() && existing.name() == composite.name()
+        });
+        self
+    }
+
+    /// Iterates over the enumerated types.
+    pub fn enums(&self) -> impl Iterator<Item = &PostgresEnum> {
+        self.enums.iter()
+    }
+
+    /// Iterates over the domains.
+    pub fn domains(&self) -> impl Iterator<Item = &PostgresDomain> {
+        self.domains.iter()
+    }
+
+    /// Iterates over the composite types, in their declaration order.
+    pub fn composites(&self) -> impl Iterator<Item = &PostgresComposite> {
+        self.composites.iter()
+    }
+
+    /// Returns whether there are no user-defined types.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.enums.is_empty() && self.domains.is_empty() && self.composites.is_empty()
+    }
+
+    /// Returns the domain matching the provided SQL type, either by its
+    /// qualified name or, when no homonymous type exists in another schema,
+    /// by its unqualified name.
+    ///
+    /// # Arguments
+    ///
+    /// * `sql_type` - The SQL type of a column, such as `public.positive_int`.
+    pub(crate) fn domain_of(&self, sql_type: &str) -> Option<&PostgresDomain> {
+        let sql_type = sql_type.trim().replace('"', "").to_lowercase();
+        self.domains.iter().find(|domain| {
+            domain.qualified_name().to_lowercase() == sql_type
+                || ((domain.get_schema().is_none()
+                    || !self.is_homonymous(domain.get_schema(), domain.name()))
+                    && domain.name().to_lowercase() == sql_type)
+        })
+    }
+
+    /// Returns whether a user-defined type other than the provided one has
+    /// the same unqualified name, in which case the types are only matched by
+    /// their qualified names.
+    ///
+    /// # Arguments
+    ///
+    /// * `schema` - The schema of the type.
+    /// * `name` - The name of the type.
+    fn is_homonymous(&self, schema: Option<&str>, name: &str) -> bool {
+        self.enums
+            .iter()
+            .map(|postgres_enum| (postgres_enum.get_schema(), postgres_enum.name()))
+            .chain(self.domains.iter().map(|domain| (domain.get_schema(), domain.name())))
+            .chain(
+                self.composites.iter().map(|composite| (composite.get_schema(), composite.name())),
+            )
+            .filter(|(_, other_name)| other_name.eq_ignore_ascii_case(name))
+            .any(|(other_schema, _)| other_schema != schema)
+    }
+
+    /// Returns the external crate providing the user-defined types, which is
+    /// generated within the workspace.
+    ///
+    /// # Arguments
+    ///
+    /// * `crate_name` - The name of the types crate.
+    /// * `crate_path` - The path of the types crate, relative to the workspace.
+    /// * `base_crates` - The other external crates of the workspace, which
+    ///   provide the base types of the domains and the types of the attributes
+    ///   of the composite types. The types depending on types which are not
+    ///   provided are not mapped, and reported when the types crate is
+    ///   generated.
+    /// * `pinned_postgres_types` - The postgres types pinned to the name of the
+    ///   crate providing them.
+    /// * `nullable_array_elements` - Whether the elements of the arrays are
+    ///   nullable, which the arrays of the user-defined types used as
+    ///   attributes of the composite types follow.
+    pub(crate) fn external_crate(
+        &self,
+        crate_name: &str,
+        crate_path: &Path,
+        base_crates: &[ExternalCrate],
+        pinned_postgres_types: &[(String, String)],
+        nullable_array_elements: bool,
+    ) -> ExternalCrate {
+        let crate_ident = sanitized_ident(crate_name);
+        let mut types = Vec::new();
+        for postgres_enum in &self.enums {
+            push_type(
+                &mut types,
+                postgres_enum.external_type(
+                    &crate_ident,
+                    !self.is_homonymous(postgres_enum.get_schema(), postgres_enum.name()),
+                ),
+            );
+        }
+        for domain in &self.domains {
+            let Some(base_type) =
+                resolve_postgres_type(base_crates, pinned_postgres_types, domain.data_type())
+            else {
+                continue;
+            };
+            push_type(
+                &mut types,
+                domain.external_type(
+                    &crate_ident,
+                    !self.is_homonymous(domain.get_schema(), domain.name()),
+                    base_type,
+                ),
+            );
+        }
+        // The composite types are mapped in their declaration order, so that
+        // their attributes may be typed by the types declared before them.
+        for composite in &self.composites {
+            let attribute_traits = composite
+                .attributes()
+                .iter()
+                .map(|(_, data_type)| {
+                    let traits: Vec<Trait> = match resolve_postgres_type(
+                        base_crates,
+                        pinned_postgres_types,
+                        data_type,
+                    ) {
+                        Some(attribute_type) => {
+                            Trait::iter()
+                                .filter(|core_trait| attribute_type.supports_trait(*core_trait))
+                                .collect()
+                        }
+                        None => {
+                            let local_type = types
+                                .iter()
+                                .find(|local_type| local_type.is_compatible_with(data_type))
+                                .cloned()
+                                .or_else(|| {
+                                    let element = array_element(data_type)?;
+                                    types
+                                        .iter()
+                                        .find(|local_type| local_type.is_compatible_with(&element))?
+                                        .array(nullable_array_elements)
+                                })?;
+                            Trait::iter()
+                                .filter(|core_trait| local_type.supports(*core_trait))
+                                .collect()
+                        }
+                    };
+                    Some((data_type.as_str(), traits))
+                })
+                .collect::<Option<Vec<_>>>();
+            let Some(attribute_traits) = attribute_traits else {
+                continue;
+            };
+            let traits = composite.supported_traits(|data_type, core_trait| {
+                attribute_traits.iter().any(|(attribute_data_type, traits)| {
+                    *attribute_data_type == data_type && traits.contains(&core_trait)
+                })
+            });
+            push_type(
+                &mut types,
+                composite.external_type(
+                    &crate_ident,
+                    !self.is_homonymous(composite.get_schema(), composite.name()),
+                    &traits,
+                ),
+            );
+        }
+        ExternalCrate::new(crate_name)
+            .expect("The name of the types crate is valid")
+            .path(&crate_path.display())
+            .expect("The types crate is not a workspace dependency")
+            .types(types)
+            .expect("The types sharing a postgres type with a previous one are left out")
+            .into()
+    }
+
+    /// Checks that the user-defined types can be mapped, which is not the
+    /// case when their name is not a valid Postgres type name, or when two
+    /// types share the same name once lowercased, such as the `"Mood"` enum
+    /// and the `mood` domain of the same schema, as the Postgres types are
+    /// matched case-insensitively.
+    ///
+    /// # Errors
+    ///
+    /// * Returns [`InvalidUserDefinedTypes`](crate::Error::InvalidUserDefinedTypes)
+    ///   if the name of a type is not a valid Postgres type name.
+    /// * Returns [`DuplicateType`](crate::Error::DuplicateType) if two types
+    ///   share the same name once lowercased.
+    pub(crate) fn check(&self) -> Result<(), crate::Error> {
+        let mut postgres_types: HashMap<String, String> = HashMap::new();
+        let types = self
+            .enums
+            .iter()
+            .map(|postgres_enum| ("enum", postgres_enum.get_schema(), postgres_enum.name()))
+            .chain(self.domains.iter().map(|domain| ("domain", domain.get_schema(), domain.name())))
+            .chain(
+                self.composites
+                    .iter()
+                    .map(|composite| ("composite type", composite.get_schema(), composite.name())),
+            );
+        for (kind, schema, name) in types {
+            let qualified_name = match schema {
+                Some(schema) => format!("{schema}.{name}"),
+                None => name.to_owned(),
+            };
+            let postgres_type = qualified_name.to_lowercase();
+            let description = format!("{kind} `{qualified_name}`");
+            if !PostgresType::parse(&postgres_type).is_valid_pattern()
+                || postgres_type.contains(['(', ')'])
+            {
+                return Err(crate::Error::InvalidUserDefinedTypes(format!(
+                    "the name of the {description} is not a valid Postgres type name"
+                )));
+            }
+            if let Some(first) = postgres_types.insert(postgres_type.clone(), description.clone()) {
+                return Err(crate::Error::DuplicateType {
+                    postgres_type,
+                    first,
+                    second: description,
+                });
+            }
+        }
+        Ok(())
+    }
+}
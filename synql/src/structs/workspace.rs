@@ -2,13 +2,23 @@
 //! workspace.
 
 mod builder;
-use std::path::{Path, PathBuf};
+use std::{
+    cell::RefCell,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 mod core_types;
 
 pub use builder::WorkspaceBuilder;
 use syn::Type;
 
-use crate::structs::{ExternalCrate, ExternalFunctionRef, ExternalTypeRef};
+use crate::{
+    structs::{
+        ExternalCrate, ExternalFunctionRef, ExternalType, ExternalTypeRef, GeneratedWorkspace,
+        GenerationWarning, SchemaNaming, UserDefinedTypes, external_type::array_element,
+    },
+    traits::NamingStrategy,
+};
 
 #[derive(Debug, Clone)]
 /// Struct defining a Cargo workspace.
@@ -25,6 +35,119 @@ pub struct Workspace {
     version: (u8, u8, u8),
     /// Edition of the workspace.
     edition: u16,
+    /// Whether unsupported constructs are skipped instead of aborting the
+    /// generation.
+    lenient: bool,
+    /// Constructs skipped while generating the workspace in lenient mode.
+    warnings: RefCell<Vec<GenerationWarning>>,
+    /// Schema and name of the soft-denied tables, which are not generated
+    /// while the foreign keys referencing them are elided.
+    soft_denied_tables: Vec<(Option<String>, String)>,
+    /// Schema, table name and column name of the columns whose Rust type is
+    /// overridden, alongside the overriding type.
+    column_types: Vec<((Option<String>, String, String), Type)>,
+    /// Scheme used to qualify the names of the crates with the schema of the
+    /// corresponding tables.
+    schema_naming: SchemaNaming,
+    /// Strategy deriving the names of the crates, structs and fields.
+    naming_strategy: Arc<dyn NamingStrategy>,
+    /// User-defined types generated in the types crate of the workspace.
+    user_defined_types: UserDefinedTypes,
+    /// Postgres types pinned to the name of the crate providing them,
+    /// whatever the priorities of the crates.
+    pinned_postgres_types: Vec<(String, String)>,
+}
+
+/// Returns the name of the crate defining the user-defined types of the
+/// workspace with the provided name.
+///
+/// # Arguments
+/// * `workspace_name` - The name of the workspace.
+pub(crate) fn types_crate_name(workspace_name: &str) -> String {
+    format!("{workspace_name}-types")
+}
+
+/// Returns whether the provided crate maps the provided postgres type, or the
+/// type of its elements if it is an array, to the same type as the pinned
+/// postgres type, so that pinning `timestamptz` also pins its aliases such as
+/// `timestamp with time zone` and its arrays.
+///
+/// # Arguments
+/// * `external_crate` - The crate the postgres type is pinned to.
+/// * `pinned_type` - The pinned postgres type.
+/// * `postgres_type` - The postgres type to resolve.
+fn is_pinned_to(external_crate: &ExternalCrate, pinned_type: &str, postgres_type: &str) -> bool {
+    let element_type = array_element(postgres_type);
+    [Some(postgres_type), element_type.as_deref()].into_iter().flatten().any(|candidate| {
+        external_crate.external_postgres_type(candidate).is_some_and(|external_type| {
+            external_crate
+                .external_postgres_type(pinned_type)
+                .is_some_and(|pinned| pinned == external_type)
+        })
+    })
+}
+
+/// Returns the crate, among the provided ones, the provided postgres type is
+/// pinned to, if any.
+///
+/// # Arguments
+/// * `external_crates` - The crates which may provide the type.
+/// * `pinned_postgres_types` - The postgres types pinned to the name of the
+///   crate providing them.
+/// * `postgres_type` - The postgres type to resolve.
+fn pinned_crate<'a, I>(
+    external_crates: I,
+    pinned_postgres_types: &[(String, String)],
+    postgres_type: &str,
+) -> Option<&'a ExternalCrate>
+where
+    I: IntoIterator<Item = &'a ExternalCrate> + Clone,
+{
+    pinned_postgres_types.iter().find_map(|(pinned_type, crate_name)| {
+        external_crates.clone().into_iter().find(|external_crate| {
+            external_crate.name() == crate_name
+                && is_pinned_to(external_crate, pinned_type, postgres_type)
+        })
+    })
+}
+
+/// Returns the type the provided postgres type is mapped to among the
+/// provided crates, if any.
+///
+/// The type is provided by the crate it is pinned to, if any, and otherwise by
+/// the crate with the highest priority among those providing it. Crates with
+/// the same priority are considered in their registration order.
+///
+/// # Arguments
+/// * `external_crates` - The crates which may provide the type.
+/// * `pinned_postgres_types` - The postgres types pinned to the name of the
+///   crate providing them.
+/// * `postgres_type` - The postgres type to resolve.
+pub(crate) fn resolve_postgres_type<'a, I>(
+    external_crates: I,
+    pinned_postgres_types: &[(String, String)],
+    postgres_type: &str,
+) -> Option<ExternalTypeRef<'a>>
+where
+    I: IntoIterator<Item = &'a ExternalCrate> + Clone,
+{
+    if let Some(external_crate) =
+        pinned_crate(external_crates.clone(), pinned_postgres_types, postgres_type)
+    {
+        return external_crate.external_postgres_type(postgres_type);
+    }
+    let mut resolved: Option<ExternalTypeRef<'a>> = None;
+    for external_crate in external_crates {
+        if resolved.is_some_and(|resolved| {
+            resolved.external_crate().priority() >= external_crate.priority()
+        }) {
+            continue;
+        }
+        if let Some(external_type) = external_crate.external_postgres_type(postgres_type) {
+            resolved = Some(external_type);
+        }
+    }
+    resolved
 }
 
 impl Workspace {
@@ -69,6 +192,102 @@ impl Workspace {
         &self.name
     }
 
+    #[inline]
+    #[must_use]
+    /// Returns the scheme used to qualify the names of the crates with the
+    /// schema of the corresponding tables.
+    pub fn schema_naming(&self) -> &SchemaNaming {
+        &self.schema_naming
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the strategy deriving the names of the crates, structs and
+    /// fields.
+    pub fn naming_strategy(&self) -> &dyn NamingStrategy {
+        self.naming_strategy.as_ref()
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the user-defined types generated in the types crate of the
+    /// workspace.
+    pub fn user_defined_types(&self) -> &UserDefinedTypes {
+        &self.user_defined_types
+    }
+
+    #[must_use]
+    /// Returns the name of the crate defining the user-defined types.
+    pub fn types_crate_name(&self) -> String {
+        types_crate_name(&self.name)
+    }
+
+    #[must_use]
+    /// Returns the path of the crate defining the user-defined types,
+    /// relative to the workspace.
+    pub fn types_crate_relative_path(&self) -> PathBuf {
+        self.crate_base_path.join(self.types_crate_name())
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns whether unsupported constructs are skipped, with a warning,
+    /// instead of aborting the generation.
+    pub fn is_lenient(&self) -> bool {
+        self.lenient
+    }
+
+    /// Returns whether the table with the provided schema and name is
+    /// soft-denied, meaning that it is not generated and that the foreign keys
+    /// referencing it are elided.
+    ///
+    /// # Arguments
+    /// * `schema` - The schema of the table, if any.
+    /// * `table_name` - The name of the table.
+    #[must_use]
+    pub fn is_soft_denied(&self, schema: Option<&str>, table_name: &str) -> bool {
+        self.soft_denied_tables.iter().any(|(denied_schema, denied_table_name)| {
+            denied_schema.as_deref() == schema && denied_table_name == table_name
+        })
+    }
+
+    /// Returns the external type overriding the Rust type of the column with
+    /// the provided schema, table name and column name, if any.
+    ///
+    /// # Arguments
+    /// * `schema` - The schema of the table, if any.
+    /// * `table_name` - The name of the table.
+    /// * `column_name` - The name of the column.
+    #[must_use]
+    pub fn column_type(
+        &self,
+        schema: Option<&str>,
+        table_name: &str,
+        column_name: &str,
+    ) -> Option<ExternalTypeRef<'_>> {
+        self.column_types
+            .iter()
+            .find(|((overridden_schema, overridden_table_name, overridden_column_name), _)| {
+                overridden_schema.as_deref() == schema
+                    && overridden_table_name == table_name
+                    && overridden_column_name == column_name
+            })
+            .and_then(|(_, rust_type)| self.external_type(rust_type))
+    }
+
+    /// Records a construct which was skipped in lenient mode.
+    ///
+    /// # Arguments
+    /// * `warning` - The warning describing the skipped construct.
+    pub(crate) fn warn(&self, warning: GenerationWarning) {
+        self.warnings.borrow_mut().push(warning);
+    }
+
+    /// Returns the warnings recorded so far, leaving none behind.
+    pub(crate) fn take_warnings(&self) -> Vec<GenerationWarning> {
+        self.warnings.take()
+    }
+
     /// Returns the external type ref corresponding to the provided Postgres
     /// name, if any.
     ///
@@ -76,12 +295,73 @@ impl Workspace {
     /// * `postgres_type` - A string slice representing the postgres type.
     #[must_use]
     pub fn external_postgres_type(&self, postgres_type: &str) -> Option<ExternalTypeRef<'_>> {
-        for ext_crate in &self.external_crates {
-            if let Some(ext_type) = ext_crate.external_postgres_type(postgres_type) {
-                return Some(ext_type);
+        resolve_postgres_type(&self.external_crates, &self.pinned_postgres_types, postgres_type)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the postgres types pinned to the name of the crate providing
+    /// them.
+    pub fn pinned_postgres_types(&self) -> &[(String, String)] {
+        &self.pinned_postgres_types
+    }
+
+    /// Returns the postgres types listed by the external types which are
+    /// provided by several crates with the same priority, alongside the names
+    /// of these crates in their registration order, unless the types are
+    /// pinned to one of them.
+    ///
+    /// The first of the crates is the one the type is mapped to. As the
+    /// postgres types of the external types are patterns, the types which are
+    /// only matched by the patterns of several crates, such as `varchar(255)`
+    /// for the patterns `varchar(*)` and `varchar(255)`, are reported by
+    /// [`ambiguous_postgres_type`](Self::ambiguous_postgres_type).
+    #[must_use]
+    pub fn ambiguous_postgres_types(&self) -> Vec<(String, Vec<&str>)> {
+        let mut ambiguous_postgres_types: Vec<(String, Vec<&str>)> = Vec::new();
+        let postgres_types = self
+            .external_crates
+            .iter()
+            .flat_map(ExternalCrate::types)
+            .flat_map(ExternalType::postgres_types);
+        for postgres_type in postgres_types {
+            if ambiguous_postgres_types.iter().any(|(ambiguous, _)| ambiguous == postgres_type) {
+                continue;
+            }
+            if let Some(providers) = self.ambiguous_postgres_type(postgres_type) {
+                ambiguous_postgres_types.push((postgres_type.clone(), providers));
             }
         }
-        None
+        ambiguous_postgres_types
+    }
+
+    /// Returns the names of the crates with the same priority which provide
+    /// the provided postgres type, in their registration order, if there are
+    /// several of them and the type is not pinned to one of them.
+    ///
+    /// The first of the crates is the one the type is mapped to.
+    ///
+    /// # Arguments
+    /// * `postgres_type` - The postgres type to resolve, possibly with
+    ///   modifiers.
+    #[must_use]
+    pub fn ambiguous_postgres_type(&self, postgres_type: &str) -> Option<Vec<&str>> {
+        if pinned_crate(&self.external_crates, &self.pinned_postgres_types, postgres_type).is_some()
+        {
+            return None;
+        }
+        let providers = self
+            .external_crates
+            .iter()
+            .filter(|external_crate| external_crate.external_postgres_type(postgres_type).is_some())
+            .collect::<Vec<_>>();
+        let priority = providers.iter().map(|provider| provider.priority()).max()?;
+        let providers = providers
+            .into_iter()
+            .filter(|provider| provider.priority() == priority)
+            .map(ExternalCrate::name)
+            .collect::<Vec<_>>();
+        (providers.len() > 1).then_some(providers)
     }
 
     /// Returns the external type ref corresponding to the provided name, if
@@ -122,14 +402,17 @@ impl Workspace {
 
     /// Writes the formatting rules for the workspace.
     ///
+    /// # Arguments
+    ///
+    /// * `generated` - The generated workspace the file is written into.
+    ///
     /// # Errors
     ///
-    /// Returns an `std::io::Error` if writing to the file fails.
-    pub fn write_rustfmt(&self) -> std::io::Result<()> {
-        use std::io::Write;
+    /// Returns an error if formatting the file contents fails.
+    pub fn write_rustfmt(&self, generated: &mut GeneratedWorkspace) -> Result<(), crate::Error> {
+        use std::fmt::Write;
 
-        let rustfmt_path = self.path.join("rustfmt.toml");
-        let mut buffer = std::fs::File::create(rustfmt_path)?;
+        let mut buffer = String::new();
 
         writeln!(buffer, "edition = \"{}\"", self.edition)?;
         writeln!(buffer, "max_width = 100")?;
@@ -147,6 +430,8 @@ impl Workspace {
         writeln!(buffer, "fn_single_line = false")?;
         writeln!(buffer, "where_single_line = false")?;
 
+        generated.insert("rustfmt.toml", buffer);
+
         Ok(())
     }
 }
@@ -1,8 +1,14 @@
 //! Submodule defining a builder for the `Workspace` struct.
 
-use std::path::PathBuf;
+use std::{cell::RefCell, path::PathBuf, sync::Arc};
 
-use crate::structs::{ExternalCrate, Workspace, external_crate::MaximalNumberOfColumns};
+use crate::{
+    structs::{
+        DefaultNamingStrategy, ExternalCrate, SchemaNaming, TemporalBackend, UserDefinedTypes,
+        Workspace, external_crate::MaximalNumberOfColumns, workspace::types_crate_name,
+    },
+    traits::NamingStrategy,
+};
 
 /// Builder for the `Workspace` struct.
 pub struct WorkspaceBuilder {
@@ -18,6 +24,25 @@ pub struct WorkspaceBuilder {
     version: (u8, u8, u8),
     /// Edition of the workspace.
     edition: u16,
+    /// Whether unsupported constructs are skipped instead of aborting the
+    /// generation.
+    lenient: bool,
+    /// Whether the elements of the arrays are nullable.
+    nullable_array_elements: bool,
+    /// Schema and name of the soft-denied tables.
+    soft_denied_tables: Vec<(Option<String>, String)>,
+    /// Schema, table name and column name of the columns whose Rust type is
+    /// overridden, alongside the overriding type.
+    column_types: Vec<((Option<String>, String, String), syn::Type)>,
+    /// Scheme used to qualify the names of the crates with the schema of the
+    /// corresponding tables.
+    schema_naming: SchemaNaming,
+    /// Strategy deriving the names of the crates, structs and fields.
+    naming_strategy: Arc<dyn NamingStrategy>,
+    /// User-defined types generated in the types crate of the workspace.
+    user_defined_types: UserDefinedTypes,
+    /// Postgres types pinned to the name of the crate providing them.
+    pinned_postgres_types: Vec<(String, String)>,
 }
 
 impl Default for WorkspaceBuilder {
@@ -29,6 +54,14 @@ impl Default for WorkspaceBuilder {
             crate_base_path: PathBuf::from("."),
             version: (0, 1, 0),
             edition: 2024,
+            lenient: false,
+            nullable_array_elements: false,
+            soft_denied_tables: Vec::new(),
+            column_types: Vec::new(),
+            schema_naming: SchemaNaming::default(),
+            naming_strategy: Arc::new(DefaultNamingStrategy),
+            user_defined_types: UserDefinedTypes::default(),
+            pinned_postgres_types: Vec::new(),
         }
     }
 }
@@ -105,6 +138,115 @@ impl WorkspaceBuilder {
         self
     }
 
+    /// Sets whether unsupported constructs, such as check constraints which
+    /// cannot be translated, are skipped with a warning instead of aborting
+    /// the generation.
+    ///
+    /// # Arguments
+    /// * `lenient` - Whether the generation is lenient.
+    #[must_use]
+    pub fn lenient(mut self, lenient: bool) -> Self {
+        self.lenient = lenient;
+        self
+    }
+
+    /// Sets whether the elements of the arrays are nullable, in which case
+    /// the arrays are mapped to `Vec<Option<T>>` instead of `Vec<T>`.
+    ///
+    /// # Arguments
+    /// * `nullable_array_elements` - Whether the elements of the arrays are
+    ///   nullable.
+    #[must_use]
+    pub fn nullable_array_elements(mut self, nullable_array_elements: bool) -> Self {
+        self.nullable_array_elements = nullable_array_elements;
+        self
+    }
+
+    /// Sets the scheme used to qualify the names of the crates with the schema
+    /// of the corresponding tables.
+    ///
+    /// # Arguments
+    /// * `schema_naming` - The naming scheme.
+    #[must_use]
+    pub fn schema_naming(mut self, schema_naming: SchemaNaming) -> Self {
+        self.schema_naming = schema_naming;
+        self
+    }
+
+    /// Sets the strategy deriving the names of the crates, structs and fields.
+    ///
+    /// # Arguments
+    /// * `naming_strategy` - The naming strategy.
+    #[must_use]
+    pub fn naming_strategy(mut self, naming_strategy: Arc<dyn NamingStrategy>) -> Self {
+        self.naming_strategy = naming_strategy;
+        self
+    }
+
+    /// Sets the user-defined types, which are generated in the types crate of
+    /// the workspace and provided to the crates of the tables using them.
+    ///
+    /// # Arguments
+    /// * `user_defined_types` - The user-defined types.
+    #[must_use]
+    pub fn user_defined_types(mut self, user_defined_types: UserDefinedTypes) -> Self {
+        self.user_defined_types = user_defined_types;
+        self
+    }
+
+    /// Soft-denies a table: the table is not part of the workspace, and the
+    /// foreign keys referencing it are elided from the generated models.
+    ///
+    /// # Arguments
+    /// * `schema` - The schema of the table, if any.
+    /// * `table_name` - The name of the table.
+    #[must_use]
+    pub fn soft_deny(mut self, schema: Option<&str>, table_name: &str) -> Self {
+        let table = (schema.map(ToOwned::to_owned), table_name.to_owned());
+        if !self.soft_denied_tables.contains(&table) {
+            self.soft_denied_tables.push(table);
+        }
+        self
+    }
+
+    /// Overrides the Rust type of a column, which must be provided by one of
+    /// the external crates of the workspace. Overriding the same column twice
+    /// keeps the latest type.
+    ///
+    /// # Arguments
+    /// * `schema` - The schema of the table, if any.
+    /// * `table_name` - The name of the table.
+    /// * `column_name` - The name of the column.
+    /// * `rust_type` - The Rust type of the column.
+    #[must_use]
+    pub fn column_type(
+        mut self,
+        schema: Option<&str>,
+        table_name: &str,
+        column_name: &str,
+        rust_type: syn::Type,
+    ) -> Self {
+        let column = (schema.map(ToOwned::to_owned), table_name.to_owned(), column_name.to_owned());
+        self.column_types.retain(|(overridden, _)| overridden != &column);
+        self.column_types.push((column, rust_type));
+        self
+    }
+
+    /// Pins a postgres type, alongside its aliases and arrays, to the crate
+    /// with the provided name, whatever the priorities of the crates
+    /// providing it. Pinning the same type twice keeps the latest crate.
+    ///
+    /// # Arguments
+    /// * `postgres_type` - The postgres type to pin, such as `interval`.
+    /// * `crate_name` - The name of the crate providing the type.
+    #[must_use]
+    pub fn pin_postgres_type(mut self, postgres_type: &str, crate_name: &str) -> Self {
+        let postgres_type = postgres_type.trim().to_lowercase();
+        self.pinned_postgres_types.retain(|(pinned_type, _)| pinned_type != &postgres_type);
+        self.pinned_postgres_types.push((postgres_type, crate_name.to_owned()));
+        self
+    }
+
     /// Adds an external crate to the workspace.
     ///
     /// # Arguments
@@ -129,6 +271,29 @@ impl WorkspaceBuilder {
         self.external_crate(ExternalCrate::chrono())
     }
 
+    /// Adds the `time` external crate to the workspace.
+    #[must_use]
+    pub fn time(self) -> Self {
+        self.external_crate(ExternalCrate::time())
+    }
+
+    /// Adds the external crates of the provided temporal backend to the
+    /// workspace.
+    ///
+    /// # Arguments
+    /// * `temporal_backend` - The crate the date and time columns are mapped
+    ///   to.
+    #[must_use]
+    pub fn temporal_backend(self, temporal_backend: TemporalBackend) -> Self {
+        self.external_crates(temporal_backend.external_crates())
+    }
+
+    /// Adds the `bigdecimal` external crate to the workspace.
+    #[must_use]
+    pub fn bigdecimal(self) -> Self {
+        self.external_crate(ExternalCrate::bigdecimal())
+    }
+
     /// Adds the `serde` external crate to the workspace.
     #[must_use]
     pub fn serde(self) -> Self {
@@ -206,7 +371,23 @@ impl WorkspaceBuilder {
 }
 
 impl From<WorkspaceBuilder> for Workspace {
-    fn from(builder: WorkspaceBuilder) -> Self {
+    fn from(mut builder: WorkspaceBuilder) -> Self {
+        for external_crate in &mut builder.external_crates {
+            external_crate.derive_types(builder.nullable_array_elements);
+        }
+        if !builder.user_defined_types.is_empty() {
+            let types_crate_name = types_crate_name(&builder.name);
+            let types_crate_path = builder.crate_base_path.join(&types_crate_name);
+            let mut types_crate = builder.user_defined_types.external_crate(
+                &types_crate_name,
+                &types_crate_path,
+                &builder.external_crates,
+                &builder.pinned_postgres_types,
+                builder.nullable_array_elements,
+            );
+            types_crate.derive_types(builder.nullable_array_elements);
+            builder = builder.external_crate(types_crate);
+        }
         Workspace {
             external_crates: builder.external_crates,
             name: builder.name,
@@ -214,6 +395,14 @@ impl From<WorkspaceBuilder> for Workspace {
             crate_base_path: builder.crate_base_path,
             version: builder.version,
             edition: builder.edition,
+            lenient: builder.lenient,
+            warnings: RefCell::default(),
+            soft_denied_tables: builder.soft_denied_tables,
+            column_types: builder.column_types,
+            schema_naming: builder.schema_naming,
+            naming_strategy: builder.naming_strategy,
+            user_defined_types: builder.user_defined_types,
+            pinned_postgres_types: builder.pinned_postgres_types,
         }
     }
 }
@@ -1,5 +1,7 @@
 //! Submodule defining traits used to generate Rust code from SQL schema.
 
+pub mod maybe_sync;
+pub use maybe_sync::MaybeSync;
 pub mod synql_database_like;
 pub use synql_database_like::SynQLDatabaseLike;
 pub mod table;
@@ -14,3 +16,5 @@ pub mod unique_index;
 pub use unique_index::UniqueIndexSynLike;
 pub mod foreign_key;
 pub use foreign_key::ForeignKeySynLike;
+pub mod naming_strategy;
+pub use naming_strategy::NamingStrategy;
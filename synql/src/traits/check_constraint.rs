@@ -6,13 +6,14 @@
 use proc_macro2::TokenStream;
 use quote::quote;
 use sql_traits::traits::{CheckConstraintLike, DatabaseLike};
+use sqlparser::ast::{Expr, Ident};
 
 mod sub_expressions;
 mod translate_expression;
 use translate_expression::TranslateExpression;
 
 use crate::{
-    structs::Workspace,
+    structs::{ExternalTypeRef, PostgresDomain, Workspace},
     traits::{TableSynLike, column::ColumnSynLike},
 };
 
@@ -28,19 +29,25 @@ pub trait CheckConstraintSynLike: CheckConstraintLike {
     /// * `workspace` - The workspace where the generated code will be placed.
     /// * `contextual_columns` - The columns that are in the context where the
     ///   check constraint is applied.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnsupportedCheckConstraint`](crate::Error::UnsupportedCheckConstraint)
+    /// if any of the sub-expressions of the check constraint cannot be
+    /// translated into Rust code.
     fn to_syn<'db>(
         &'db self,
         database: &'db Self::DB,
         workspace: &Workspace,
         contextual_columns: &[&'db <Self::DB as DatabaseLike>::Column],
-    ) -> TokenStream {
+    ) -> Result<TokenStream, crate::Error> {
         let translator: TranslateExpression<'_, 'db, <Self as CheckConstraintLike>::DB> =
             TranslateExpression::new(self.borrow(), workspace, contextual_columns, database);
 
         let mut translated_expressions: Vec<TokenStream> = Vec::new();
 
         for sub_expression in sub_expressions::sub_expressions(self.expression(database)) {
-            translated_expressions.push(translator.parse(sub_expression));
+            translated_expressions.push(translator.parse(sub_expression)?);
         }
 
         let relevant_optional_columns = self
@@ -48,12 +55,12 @@ pub trait CheckConstraintSynLike: CheckConstraintLike {
             .filter(|column| !contextual_columns.iter().any(|c| c == column))
             .collect::<Vec<_>>();
 
-        if relevant_optional_columns.is_empty() {
+        Ok(if relevant_optional_columns.is_empty() {
             translated_expressions.into_iter().collect()
         } else {
             let column_idents = relevant_optional_columns
                 .iter()
-                .map(ColumnSynLike::column_snake_ident)
+                .map(|column| column.column_field_ident(workspace, database))
                 .collect::<Vec<_>>();
             let table_ident = self.table(database).table_snake_ident();
             if column_idents.len() == 1 {
@@ -69,8 +76,53 @@ pub trait CheckConstraintSynLike: CheckConstraintLike {
                     }
                 }
             }
-        }
+        })
     }
 }
 
 impl<T> CheckConstraintSynLike for T where T: CheckConstraintLike {}
+
+/// Returns the tokenstream validating the value of a domain against the
+/// provided check, where the value is referenced by the `value` variable.
+///
+/// The `VALUE IS NOT NULL` sub-expressions are skipped, as the value wrapped
+/// by the newtype of the domain is never null.
+///
+/// # Arguments
+///
+/// * `domain` - The domain of the check.
+/// * `check` - The check expression, referencing the value as `VALUE`.
+/// * `value_type` - The type of the value of the domain.
+/// * `workspace` - The workspace where the domain is generated.
+/// * `database` - The database defining the domain.
+///
+/// # Errors
+///
+/// Returns [`UnsupportedDomainCheck`](crate::Error::UnsupportedDomainCheck)
+/// if any of the sub-expressions of the check cannot be translated into Rust
+/// code.
+pub(crate) fn domain_check_to_syn<DB: DatabaseLike>(
+    domain: &PostgresDomain,
+    check: &Expr,
+    value_type: ExternalTypeRef<'_>,
+    workspace: &Workspace,
+    database: &DB,
+) -> Result<TokenStream, crate::Error> {
+    let translator: TranslateExpression<'_, '_, DB> =
+        TranslateExpression::domain(domain, check, value_type, workspace, database);
+
+    let mut translated_expressions: Vec<TokenStream> = Vec::new();
+
+    for sub_expression in sub_expressions::sub_expressions(check) {
+        if matches!(
+            sub_expression,
+            Expr::IsNotNull(inner)
+                if matches!(inner.as_ref(), Expr::Identifier(Ident { value, .. }) if value.eq_ignore_ascii_case("value"))
+        ) {
+            continue;
+        }
+        translated_expressions.push(translator.parse(sub_expression)?);
+    }
+
+    Ok(translated_expressions.into_iter().collect())
+}
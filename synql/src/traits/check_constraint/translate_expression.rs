@@ -1,22 +1,71 @@
 //! Submodule providing the `TranslateExpression` struct for translating SQL
 //! check constraint expressions into Rust code.
 use proc_macro2::TokenStream;
-use quote::quote;
+use quote::{ToTokens, quote};
 use sql_traits::traits::{CheckConstraintLike, ColumnLike, DatabaseLike, FunctionLike, TableLike};
 use sqlparser::ast::{
     BinaryOperator, Expr, FunctionArg, FunctionArgExpr, FunctionArgumentList, FunctionArguments,
     Ident, Value, ValueWithSpan,
 };
 
+mod ranges;
+
 use crate::{
-    structs::{ExternalFunctionRef, ExternalTypeRef, Workspace},
+    structs::{
+        CheckConstraintErrorKind, ExternalFunctionRef, ExternalTypeRef, PostgresDomain, Workspace,
+    },
     traits::{column::ColumnSynLike, function::FunctionSynLike, table::TableSynLike},
+    utils::is_current_time,
 };
 
+/// Type alias for the result of the translation of a sub-expression, composed
+/// of the generated code, the scoped operands and the returned type.
+type Translation<'workspace> =
+    (TokenStream, Vec<Operand<'workspace>>, Option<ExternalTypeRef<'workspace>>);
+
+/// The subject whose check expressions are being translated.
+enum Subject<'workspace, 'db, DB: DatabaseLike> {
+    /// A check constraint of a table, whose expression references the columns
+    /// of the table by name.
+    CheckConstraint {
+        /// The check constraint being translated.
+        check_constraint: &'db DB::CheckConstraint,
+        /// The columns in the context where the check constraint is applied.
+        contextual_columns: &'workspace [&'db DB::Column],
+    },
+    /// A check of a domain, whose expression references the value of the
+    /// domain as `VALUE`.
+    Domain {
+        /// The domain being translated.
+        domain: &'workspace PostgresDomain,
+        /// The check being translated.
+        check: &'workspace Expr,
+        /// The type of the value of the domain.
+        value_type: ExternalTypeRef<'workspace>,
+    },
+}
+
+/// Value referenced by an identifier of a check expression, either a column of
+/// the table of a check constraint or the value of a domain.
+#[derive(Clone)]
+struct Operand<'workspace> {
+    /// The ident of the variable holding a reference to the value.
+    ident: syn::Ident,
+    /// The expression of the name reported in the validation errors.
+    name: TokenStream,
+    /// Whether the value is optional.
+    nullable: bool,
+    /// Whether the value is known from the context, and thus not optional.
+    contextual: bool,
+    /// Whether the value is textual.
+    textual: bool,
+    /// The type of the value.
+    value_type: ExternalTypeRef<'workspace>,
+}
+
 pub(super) struct TranslateExpression<'workspace, 'db, DB: DatabaseLike> {
-    check_constraint: &'db DB::CheckConstraint,
+    subject: Subject<'workspace, 'db, DB>,
     workspace: &'workspace Workspace,
-    contextual_columns: &'workspace [&'db DB::Column],
     database: &'db DB,
 }
 
@@ -25,43 +74,37 @@ pub(super) struct TranslateExpression<'workspace, 'db, DB: DatabaseLike> {
 /// # Arguments
 ///
 /// * `kind` - The [`CastKind`](sqlparser::ast::CastKind) to verify
-fn verify_cast_kind(kind: &sqlparser::ast::CastKind) {
+fn verify_cast_kind(kind: &sqlparser::ast::CastKind) -> Result<(), CheckConstraintErrorKind> {
     match kind {
-        sqlparser::ast::CastKind::DoubleColon => {}
-        _ => {
-            unimplemented!("Unsupported cast kind: {kind:?}");
-        }
+        sqlparser::ast::CastKind::DoubleColon => Ok(()),
+        _ => Err(CheckConstraintErrorKind::UnsupportedCastKind(format!("{kind:?}"))),
     }
 }
 
 /// Returns the direction-inverted operator for the provided binary operator.
-fn invert_operator(op: &BinaryOperator) -> BinaryOperator {
-    match op {
+fn invert_operator(op: &BinaryOperator) -> Result<BinaryOperator, CheckConstraintErrorKind> {
+    Ok(match op {
         BinaryOperator::Eq => BinaryOperator::Eq,
         BinaryOperator::NotEq => BinaryOperator::NotEq,
         BinaryOperator::Gt => BinaryOperator::Lt,
         BinaryOperator::Lt => BinaryOperator::Gt,
         BinaryOperator::GtEq => BinaryOperator::LtEq,
         BinaryOperator::LtEq => BinaryOperator::GtEq,
-        _ => {
-            unimplemented!("Cannot invert unsupported operator: {op:?}");
-        }
-    }
+        _ => return Err(CheckConstraintErrorKind::UnsupportedOperator(op.to_string())),
+    })
 }
 
 /// Returns the syn version of the provided binary operator.
-fn syn_operator(op: &BinaryOperator) -> TokenStream {
-    match op {
+fn syn_operator(op: &BinaryOperator) -> Result<TokenStream, CheckConstraintErrorKind> {
+    Ok(match op {
         BinaryOperator::Eq => quote! { == },
         BinaryOperator::NotEq => quote! { != },
         BinaryOperator::Gt => quote! { > },
         BinaryOperator::Lt => quote! { < },
         BinaryOperator::GtEq => quote! { >= },
         BinaryOperator::LtEq => quote! { <= },
-        _ => {
-            unimplemented!("Unsupported operator: {op:?}");
-        }
-    }
+        _ => return Err(CheckConstraintErrorKind::UnsupportedOperator(op.to_string())),
+    })
 }
 
 impl<'workspace, 'db, DB> TranslateExpression<'workspace, 'db, DB>
@@ -74,56 +117,134 @@ where
         contextual_columns: &'workspace [&'db DB::Column],
         database: &'db DB,
     ) -> Self {
-        Self { check_constraint, workspace, contextual_columns, database }
+        Self {
+            subject: Subject::CheckConstraint { check_constraint, contextual_columns },
+            workspace,
+            database,
+        }
+    }
+
+    /// Creates a translator for the provided check of a domain.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain` - The domain of the check.
+    /// * `check` - The check expression, referencing the value as `VALUE`.
+    /// * `value_type` - The type of the value of the domain.
+    /// * `workspace` - The workspace where the domain is generated.
+    /// * `database` - The database defining the domain.
+    pub(super) fn domain(
+        domain: &'workspace PostgresDomain,
+        check: &'workspace Expr,
+        value_type: ExternalTypeRef<'workspace>,
+        workspace: &'workspace Workspace,
+        database: &'db DB,
+    ) -> Self {
+        Self { subject: Subject::Domain { domain, check, value_type }, workspace, database }
+    }
+
+    /// Returns the error describing why the provided sub-expression of the
+    /// check constraint could not be translated.
+    ///
+    /// # Arguments
+    ///
+    /// * `sub_expression` - The offending sub-expression.
+    /// * `reason` - The reason why the sub-expression could not be translated.
+    fn error(
+        &self,
+        sub_expression: &(impl std::fmt::Display + ?Sized),
+        reason: CheckConstraintErrorKind,
+    ) -> crate::Error {
+        match &self.subject {
+            Subject::CheckConstraint { check_constraint, .. } => {
+                crate::Error::UnsupportedCheckConstraint {
+                    table_name: check_constraint.table(self.database).table_name().to_string(),
+                    constraint: check_constraint.expression(self.database).to_string(),
+                    sub_expression: sub_expression.to_string(),
+                    reason,
+                }
+            }
+            Subject::Domain { domain, check, .. } => {
+                crate::Error::UnsupportedDomainCheck {
+                    domain: domain.qualified_name(),
+                    check: check.to_string(),
+                    sub_expression: sub_expression.to_string(),
+                    reason,
+                }
+            }
+        }
+    }
+
+    /// Returns the expression of the name of the table, or of the domain,
+    /// reported in the validation errors.
+    fn subject_name(&self) -> TokenStream {
+        match &self.subject {
+            Subject::CheckConstraint { check_constraint, .. } => {
+                let table_ident = check_constraint.table(self.database).table_snake_ident();
+                quote! { <crate::#table_ident::table as ::diesel_builders::TableExt>::TABLE_NAME }
+            }
+            Subject::Domain { domain, .. } => {
+                let qualified_name = domain.qualified_name();
+                quote! { #qualified_name }
+            }
+        }
     }
 
     /// Maps the provided expression to a validation error, when applicable.
-    fn map_expr_to_validation_error(&self, expr: &Expr) -> Option<TokenStream> {
-        match expr {
+    fn map_expr_to_validation_error(
+        &self,
+        expr: &Expr,
+    ) -> Result<Option<TokenStream>, crate::Error> {
+        if let Some(validation_error_token) = self.map_range_expr_to_validation_error(expr)? {
+            return Ok(Some(validation_error_token));
+        }
+        let unsupported = |reason| self.error(expr, reason);
+        Ok(match expr {
             Expr::BinaryOp { left, right, op } => {
                 match (left.as_ref(), right.as_ref()) {
                     (
                         Expr::Identifier(Ident { value: ident, .. }),
                         Expr::Value(ValueWithSpan { value, .. }),
-                    ) => Some(self.map_value_expr_to_single_field_error(ident, value, op)),
-                    (Expr::Identifier(Ident { value: ident, .. }), Expr::Function(func))
-                        if func.name.to_string() == "NOW" =>
-                    {
-                        let column = self.column(ident);
-                        let column_ident = column.column_snake_ident();
-                        let table_ident = self.table().table_snake_ident();
-
-                        assert!(matches!(op, BinaryOperator::LtEq | BinaryOperator::Lt));
-
-                        let operator = syn_operator(&invert_operator(op));
-
-                        Some(quote! {
-                            if *#column_ident #operator ::rosetta_utc::TimestampUTC::now() {
-                                return Err(::validation_errors::ValidationError::in_the_future(
-                                    <crate::#table_ident::table as ::diesel_builders::TableExt>::TABLE_NAME,
-                                    crate::#table_ident::#column_ident::NAME,
-                                ));
-                            }
-                        })
+                    ) => Some(self.map_value_expr_to_single_field_error(expr, ident, value, op)?),
+                    (
+                        Expr::Identifier(Ident { value: ident, .. }),
+                        current_time @ Expr::Function(_),
+                    ) if is_current_time(&current_time.to_string()) => {
+                        Some(self.map_current_time_expr_to_validation_error(expr, ident, op)?)
+                    }
+                    (
+                        current_time @ Expr::Function(_),
+                        Expr::Identifier(Ident { value: ident, .. }),
+                    ) if is_current_time(&current_time.to_string()) => {
+                        Some(self.map_current_time_expr_to_validation_error(
+                            expr,
+                            ident,
+                            &invert_operator(op).map_err(unsupported)?,
+                        )?)
                     }
                     (Expr::Function(func), Expr::Value(ValueWithSpan { value, .. }))
                         if func.name.to_string() == "length" =>
                     {
                         let string_type = self.workspace.string();
-                        let (parsed_arguments, columns) =
-                            self.parse_function_arguments(&func.args, &[string_type]);
-                        assert_eq!(columns.len(), 1);
-                        let column = columns[0];
-                        let parsed_argument = &parsed_arguments[0];
-                        let table_ident = self.table().table_snake_ident();
-                        let column_ident = column.column_snake_ident();
-                        let value_usize = self.parse_value(value, Some(self.workspace.usize())).0;
-                        let operator = syn_operator(&invert_operator(op));
+                        let (parsed_arguments, operands) =
+                            self.parse_function_arguments(&func.args, &[string_type])?;
+                        let ([operand], [parsed_argument]) =
+                            (operands.as_slice(), parsed_arguments.as_slice())
+                        else {
+                            return Err(unsupported(
+                                CheckConstraintErrorKind::UnsupportedExpression,
+                            ));
+                        };
+                        let operand_name = &operand.name;
+                        let subject_name = self.subject_name();
+                        let value_usize = self.parse_value(value, Some(self.workspace.usize()))?.0;
+                        let operator = syn_operator(&invert_operator(op).map_err(unsupported)?)
+                            .map_err(unsupported)?;
                         Some(quote! {
                             if #parsed_argument.len() #operator #value_usize {
                                 return Err(::validation_errors::ValidationError::exceeds_max_length(
-                                    <crate::#table_ident::table as ::diesel_builders::TableExt>::TABLE_NAME,
-                                    crate::#table_ident::#column_ident::NAME,
+                                    #subject_name,
+                                    #operand_name,
                                     #value_usize
                                 ));
                             }
@@ -134,81 +255,136 @@ where
                         Expr::Identifier(Ident { value: ident, .. }),
                     ) => {
                         Some(self.map_value_expr_to_single_field_error(
+                            expr,
                             ident,
                             value,
-                            &invert_operator(op),
-                        ))
+                            &invert_operator(op).map_err(unsupported)?,
+                        )?)
                     }
                     (
                         Expr::Identifier(Ident { value: left_ident, .. }),
                         Expr::Identifier(Ident { value: right_ident, .. }),
-                    ) => Some(self.map_expr_to_double_field_error(left_ident, right_ident, op)),
+                    ) => {
+                        Some(self.map_expr_to_double_field_error(
+                            expr,
+                            left_ident,
+                            right_ident,
+                            op,
+                        )?)
+                    }
                     _ => None,
                 }
             }
             _ => None,
-        }
+        })
     }
 
-    fn is_contextual_column(&self, column: &DB::Column) -> bool {
-        self.contextual_columns.contains(&column)
+    /// Maps the comparison of the provided column with the current time, such
+    /// as `created_at <= NOW()`, to an `in_the_future` validation error.
+    ///
+    /// # Arguments
+    ///
+    /// * `expr` - The comparison expression.
+    /// * `ident` - The name of the compared column.
+    /// * `op` - The operator comparing the column with the current time.
+    ///
+    /// # Errors
+    ///
+    /// * If the operator is not `<` nor `<=`.
+    /// * If the type of the column has no current value.
+    fn map_current_time_expr_to_validation_error(
+        &self,
+        expr: &Expr,
+        ident: &str,
+        op: &BinaryOperator,
+    ) -> Result<TokenStream, crate::Error> {
+        let operand = self.operand(expr, ident)?;
+        let operand_ident = &operand.ident;
+        let operand_name = &operand.name;
+        let subject_name = self.subject_name();
+
+        // The check is violated when the negated comparison holds.
+        let operator = match op {
+            BinaryOperator::LtEq => quote! { > },
+            BinaryOperator::Lt => quote! { >= },
+            _ => {
+                return Err(
+                    self.error(expr, CheckConstraintErrorKind::UnsupportedOperator(op.to_string()))
+                );
+            }
+        };
+        let Some(current_value) = operand.value_type.current_value() else {
+            return Err(
+                self.error(expr, CheckConstraintErrorKind::MissingCurrentValue(ident.to_owned()))
+            );
+        };
+
+        Ok(quote! {
+            if *#operand_ident #operator #current_value {
+                return Err(::validation_errors::ValidationError::in_the_future(
+                    #subject_name,
+                    #operand_name,
+                ));
+            }
+        })
     }
 
     fn map_expr_to_double_field_error(
         &self,
+        expr: &Expr,
         left: &str,
         right: &str,
         op: &BinaryOperator,
-    ) -> TokenStream {
-        let left_column = self.column(left);
-        let right_column = self.column(right);
-        let table_ident = self.table().table_snake_ident();
-        let left_column_ident = left_column.column_snake_ident();
-        let right_column_ident = right_column.column_snake_ident();
-        let l_name = quote! { crate::#table_ident::#left_column_ident::NAME };
-        let r_name = quote! { crate::#table_ident::#right_column_ident::NAME };
+    ) -> Result<TokenStream, crate::Error> {
+        let left_operand = self.operand(expr, left)?;
+        let right_operand = self.operand(expr, right)?;
+        let subject_name = self.subject_name();
+        let left_ident = &left_operand.ident;
+        let right_ident = &right_operand.ident;
+        let l_name = &left_operand.name;
+        let r_name = &right_operand.name;
         let validation_error = quote! { ::validation_errors::ValidationError };
         let compare_op = |op: TokenStream| {
             match (
-                left_column.is_nullable(self.database) && !self.is_contextual_column(left_column),
-                right_column.is_nullable(self.database) && !self.is_contextual_column(right_column),
+                left_operand.nullable && !left_operand.contextual,
+                right_operand.nullable && !right_operand.contextual,
             ) {
                 (true, true) => {
                     quote! {
-                        #left_column_ident.as_ref().is_some_and(|#left_column_ident|
-                            #right_column_ident.as_ref().is_some_and(|#right_column_ident|
-                                #left_column_ident #op #right_column_ident
+                        #left_ident.as_ref().is_some_and(|#left_ident|
+                            #right_ident.as_ref().is_some_and(|#right_ident|
+                                #left_ident #op #right_ident
                             )
                         )
                     }
                 }
                 (true, false) => {
                     quote! {
-                        #left_column_ident.as_ref().is_some_and(|#left_column_ident|
-                            #left_column_ident #op #right_column_ident
+                        #left_ident.as_ref().is_some_and(|#left_ident|
+                            #left_ident #op #right_ident
                         )
                     }
                 }
                 (false, true) => {
                     quote! {
-                        #right_column_ident.as_ref().is_some_and(|#right_column_ident|
-                            #left_column_ident #op #right_column_ident
+                        #right_ident.as_ref().is_some_and(|#right_ident|
+                            #left_ident #op #right_ident
                         )
                     }
                 }
                 (false, false) => {
                     quote! {
-                        #left_column_ident #op #right_column_ident
+                        #left_ident #op #right_ident
                     }
                 }
             }
         };
-        match op {
+        Ok(match op {
             BinaryOperator::NotEq => {
                 let compare_op = compare_op(quote! {==});
                 quote! {
                     if #compare_op {
-                        return Err(#validation_error::equal(<crate::#table_ident::table as ::diesel_builders::TableExt>::TABLE_NAME, #l_name, #r_name));
+                        return Err(#validation_error::equal(#subject_name, #l_name, #r_name));
                     }
                 }
             }
@@ -216,7 +392,7 @@ where
                 let compare_op = compare_op(quote! {>});
                 quote! {
                     if #compare_op {
-                        return Err(#validation_error::smaller_than(<crate::#table_ident::table as ::diesel_builders::TableExt>::TABLE_NAME, #l_name, #r_name));
+                        return Err(#validation_error::smaller_than(#subject_name, #l_name, #r_name));
                     }
                 }
             }
@@ -224,7 +400,7 @@ where
                 let compare_op = compare_op(quote! {>=});
                 quote! {
                     if #compare_op {
-                        return Err(#validation_error::strictly_smaller_than(<crate::#table_ident::table as ::diesel_builders::TableExt>::TABLE_NAME, #l_name, #r_name));
+                        return Err(#validation_error::strictly_smaller_than(#subject_name, #l_name, #r_name));
                     }
                 }
             }
@@ -232,7 +408,7 @@ where
                 let compare_op = compare_op(quote! {<=});
                 quote! {
                     if #compare_op {
-                        return Err(#validation_error::strictly_greater_than(<crate::#table_ident::table as ::diesel_builders::TableExt>::TABLE_NAME, #l_name, #r_name));
+                        return Err(#validation_error::strictly_greater_than(#subject_name, #l_name, #r_name));
                     }
                 }
             }
@@ -240,139 +416,215 @@ where
                 let compare_op = compare_op(quote! {<});
                 quote! {
                     if #compare_op {
-                        return Err(#validation_error::greater_than(<crate::#table_ident::table as ::diesel_builders::TableExt>::TABLE_NAME, #l_name, #r_name));
+                        return Err(#validation_error::greater_than(#subject_name, #l_name, #r_name));
                     }
                 }
             }
             _ => {
-                unimplemented!("Operator {op:?} not supported for double field error mapping");
+                return Err(
+                    self.error(expr, CheckConstraintErrorKind::UnsupportedOperator(op.to_string()))
+                );
             }
-        }
+        })
     }
 
     fn map_value_expr_to_single_field_error(
         &self,
+        expr: &Expr,
         ident: &str,
         value: &Value,
         op: &BinaryOperator,
-    ) -> TokenStream {
-        let column = self.column(ident);
-        let column_ident = column.column_snake_ident();
-        let table_ident = self.table().table_snake_ident();
-        match op {
+    ) -> Result<TokenStream, crate::Error> {
+        self.map_value_expr_to_operand_error(expr, &self.operand(expr, ident)?, value, op)
+    }
+
+    /// Maps the comparison of the provided operand with a value to the
+    /// validation error raised when it does not hold.
+    ///
+    /// # Arguments
+    ///
+    /// * `expr` - The comparison expression.
+    /// * `operand` - The compared operand, whose ident holds a reference to its
+    ///   value.
+    /// * `value` - The value the operand is compared with.
+    /// * `op` - The comparison operator, with the operand on its left.
+    fn map_value_expr_to_operand_error(
+        &self,
+        expr: &Expr,
+        operand: &Operand<'workspace>,
+        value: &Value,
+        op: &BinaryOperator,
+    ) -> Result<TokenStream, crate::Error> {
+        let operand_ident = &operand.ident;
+        let operand_name = &operand.name;
+        let subject_name = self.subject_name();
+        let unsupported_operator =
+            || self.error(expr, CheckConstraintErrorKind::UnsupportedOperator(op.to_string()));
+        Ok(match op {
             BinaryOperator::NotEq => {
-                if column.is_textual(self.database)
-                    && value == &Value::SingleQuotedString(String::new())
-                {
+                if operand.textual && value == &Value::SingleQuotedString(String::new()) {
                     quote! {
-                        if #column_ident.is_empty() {
+                        if #operand_ident.is_empty() {
                             return Err(::validation_errors::ValidationError::empty(
-                                <crate::#table_ident::table as ::diesel_builders::TableExt>::TABLE_NAME,
-                                crate::#table_ident::#column_ident::NAME
+                                #subject_name,
+                                #operand_name
                             ));
                         }
                     }
                 } else {
-                    unimplemented!("Operator {op:?} not supported for single field error mapping");
+                    return Err(unsupported_operator());
                 }
             }
             BinaryOperator::LtEq => {
-                let column_value = self.parse_column_value(column, value).0;
-                let float_value = self.parse_value(value, Some(self.workspace.f64())).0;
+                let operand_value = self.parse_value(value, Some(operand.value_type))?.0;
+                let float_value = self.parse_value(value, Some(self.workspace.f64()))?.0;
                 quote! {
-                    if #column_ident > &#column_value {
+                    if #operand_ident > &#operand_value {
                         return Err(::validation_errors::ValidationError::smaller_than_value(
-                            <crate::#table_ident::table as ::diesel_builders::TableExt>::TABLE_NAME,
-                            crate::#table_ident::#column_ident::NAME,
+                            #subject_name,
+                            #operand_name,
                             #float_value
                         ));
                     }
                 }
             }
             BinaryOperator::Lt => {
-                let column_value = self.parse_column_value(column, value).0;
-                let float_value = self.parse_value(value, Some(self.workspace.f64())).0;
+                let operand_value = self.parse_value(value, Some(operand.value_type))?.0;
+                let float_value = self.parse_value(value, Some(self.workspace.f64()))?.0;
                 quote! {
-                    if #column_ident >= &#column_value {
+                    if #operand_ident >= &#operand_value {
                         return Err(::validation_errors::ValidationError::strictly_smaller_than_value(
-                            <crate::#table_ident::table as ::diesel_builders::TableExt>::TABLE_NAME,
-                            crate::#table_ident::#column_ident::NAME,
+                            #subject_name,
+                            #operand_name,
                             #float_value
                         ));
                     }
                 }
             }
             BinaryOperator::Gt => {
-                let column_value = self.parse_column_value(column, value).0;
-                let float_value = self.parse_value(value, Some(self.workspace.f64())).0;
+                let operand_value = self.parse_value(value, Some(operand.value_type))?.0;
+                let float_value = self.parse_value(value, Some(self.workspace.f64()))?.0;
                 quote! {
-                    if #column_ident <= &#column_value {
+                    if #operand_ident <= &#operand_value {
                         return Err(::validation_errors::ValidationError::strictly_greater_than_value(
-                            <crate::#table_ident::table as ::diesel_builders::TableExt>::TABLE_NAME,
-                            crate::#table_ident::#column_ident::NAME,
+                            #subject_name,
+                            #operand_name,
                             #float_value
                         ));
                     }
                 }
             }
             BinaryOperator::GtEq => {
-                let column_value = self.parse_column_value(column, value).0;
-                let float_value = self.parse_value(value, Some(self.workspace.f64())).0;
+                let operand_value = self.parse_value(value, Some(operand.value_type))?.0;
+                let float_value = self.parse_value(value, Some(self.workspace.f64()))?.0;
                 quote! {
-                    if #column_ident < &#column_value {
+                    if #operand_ident < &#operand_value {
                         return Err(::validation_errors::ValidationError::greater_than_value(
-                            <crate::#table_ident::table as ::diesel_builders::TableExt>::TABLE_NAME,
-                            crate::#table_ident::#column_ident::NAME,
+                            #subject_name,
+                            #operand_name,
                             #float_value
                         ));
                     }
                 }
             }
             _ => {
-                unimplemented!("Operator {op:?} not supported for single field error mapping");
+                return Err(unsupported_operator());
             }
-        }
-    }
-
-    /// Returns reference to the table of the check constraint.
-    fn table(&self) -> &DB::Table {
-        self.check_constraint.table(self.database)
+        })
     }
 
     /// Returns reference to the requested function by name.
     ///
     /// # Arguments
     ///
+    /// * `function` - The function call referencing the function
     /// * `name` - The name of the function
     ///
-    /// # Panics
+    /// # Errors
     ///
     /// * If the function does not exist, which should not happen as it would
     ///   mean that the provided SQL defining the database is invalid.
-    fn function(&self, name: &str) -> &DB::Function {
-        self.check_constraint
-            .function(self.database, name)
-            .unwrap_or_else(|| panic!("Function `{name}` not found for check constraint"))
+    /// * If the function is called in the check of a domain, as the types crate
+    ///   does not depend on the crates implementing the functions.
+    fn function(
+        &self,
+        function: &sqlparser::ast::Function,
+        name: &str,
+    ) -> Result<&DB::Function, crate::Error> {
+        match &self.subject {
+            Subject::CheckConstraint { check_constraint, .. } => {
+                check_constraint.function(self.database, name).ok_or_else(|| {
+                    self.error(
+                        function,
+                        CheckConstraintErrorKind::FunctionNotFound(name.to_string()),
+                    )
+                })
+            }
+            Subject::Domain { .. } => {
+                Err(self.error(
+                    function,
+                    CheckConstraintErrorKind::UnsupportedDomainFunction(name.to_string()),
+                ))
+            }
+        }
     }
 
-    /// Returns reference to the requested involved column by name.
+    /// Returns the value referenced by the provided identifier, which is
+    /// either a column of the table of the check constraint or the `VALUE`
+    /// of the domain.
     ///
     /// # Arguments
     ///
-    /// * `name` - The name of the column
+    /// * `expr` - The sub-expression referencing the value
+    /// * `name` - The identifier of the value
     ///
-    /// # Panics
+    /// # Errors
     ///
     /// * If the column does not exist, which should not happen as it would mean
     ///   that the provided SQL defining the database is invalid.
-    fn column(&self, name: &str) -> &DB::Column {
-        self.check_constraint.column(self.database, name).unwrap_or_else(|| {
-            panic!(
-                "Column `{}` not found for check constraint from table `{}`.",
-                name,
-                self.table().table_name()
-            )
-        })
+    /// * If the type of the column cannot be determined
+    fn operand(&self, expr: &Expr, name: &str) -> Result<Operand<'workspace>, crate::Error> {
+        match &self.subject {
+            Subject::CheckConstraint { check_constraint, contextual_columns } => {
+                let column = check_constraint.column(self.database, name).ok_or_else(|| {
+                    self.error(expr, CheckConstraintErrorKind::ColumnNotFound(name.to_string()))
+                })?;
+                let value_type = column
+                    .external_postgres_type(self.workspace, self.database)
+                    .ok_or_else(|| {
+                        self.error(
+                            expr,
+                            CheckConstraintErrorKind::ColumnTypeNotFound(
+                                column.column_name().to_string(),
+                            ),
+                        )
+                    })?;
+                let table_ident = check_constraint.table(self.database).table_snake_ident();
+                let ident = column.column_field_ident(self.workspace, self.database);
+                Ok(Operand {
+                    name: quote! { crate::#table_ident::#ident::NAME },
+                    ident,
+                    nullable: column.is_nullable(self.database),
+                    contextual: contextual_columns.contains(&column),
+                    textual: column.is_textual(self.database),
+                    value_type,
+                })
+            }
+            Subject::Domain { value_type, .. } => {
+                if !name.eq_ignore_ascii_case("value") {
+                    return Err(self
+                        .error(expr, CheckConstraintErrorKind::ColumnNotFound(name.to_string())));
+                }
+                Ok(Operand {
+                    ident: syn::Ident::new("value", proc_macro2::Span::call_site()),
+                    name: quote! { "value" },
+                    nullable: false,
+                    contextual: false,
+                    textual: value_type.is_string(),
+                    value_type: *value_type,
+                })
+            }
+        }
     }
 
     /// Translates the provided function argument to a
@@ -381,21 +633,28 @@ where
         &self,
         arg: &FunctionArgExpr,
         arg_type: ExternalTypeRef<'workspace>,
-    ) -> (TokenStream, Option<&'_ DB::Column>) {
+    ) -> Result<(TokenStream, Option<Operand<'workspace>>), crate::Error> {
         match arg {
             FunctionArgExpr::Expr(expr) => {
                 let (token_stream, mut scoped_columns, _returning_type) =
-                    self.inner_parse(expr, Some(arg_type));
+                    self.inner_parse(expr, Some(arg_type))?;
                 if scoped_columns.len() > 1 {
-                    unimplemented!("Multiple scoped columns not supported");
+                    return Err(self.error(
+                        arg,
+                        CheckConstraintErrorKind::TooManyScopedColumns(scoped_columns.len()),
+                    ));
                 }
-                (token_stream, scoped_columns.pop())
+                Ok((token_stream, scoped_columns.pop()))
             }
             FunctionArgExpr::QualifiedWildcard(_) => {
-                unimplemented!("QualifiedWildcard not supported");
+                Err(self.error(
+                    arg,
+                    CheckConstraintErrorKind::UnsupportedFunctionArgument("qualified wildcard"),
+                ))
             }
             FunctionArgExpr::Wildcard => {
-                unimplemented!("Wildcard not supported");
+                Err(self
+                    .error(arg, CheckConstraintErrorKind::UnsupportedFunctionArgument("wildcard")))
             }
         }
     }
@@ -406,13 +665,10 @@ where
         &self,
         arg: &FunctionArg,
         arg_type: ExternalTypeRef<'workspace>,
-    ) -> (TokenStream, Option<&'_ DB::Column>) {
+    ) -> Result<(TokenStream, Option<Operand<'workspace>>), crate::Error> {
         match arg {
-            FunctionArg::Named { .. } => {
-                unimplemented!("Named arguments not supported");
-            }
-            FunctionArg::ExprNamed { .. } => {
-                unimplemented!("ExprNamed arguments not supported");
+            FunctionArg::Named { .. } | FunctionArg::ExprNamed { .. } => {
+                Err(self.error(arg, CheckConstraintErrorKind::UnsupportedFunctionArgument("named")))
             }
             FunctionArg::Unnamed(arg) => self.parse_function_argument_expr(arg, arg_type),
         }
@@ -424,16 +680,24 @@ where
         &self,
         args: &FunctionArgumentList,
         argument_types: &[ExternalTypeRef<'workspace>],
-    ) -> (Vec<TokenStream>, Vec<&'_ DB::Column>) {
+    ) -> Result<(Vec<TokenStream>, Vec<Operand<'workspace>>), crate::Error> {
         let mut token_stream = Vec::with_capacity(args.args.len());
         let mut columns = Vec::new();
-        assert_eq!(args.args.len(), argument_types.len());
+        if args.args.len() != argument_types.len() {
+            return Err(self.error(
+                args,
+                CheckConstraintErrorKind::ArgumentCountMismatch {
+                    expected: argument_types.len(),
+                    found: args.args.len(),
+                },
+            ));
+        }
         for (arg, arg_type) in args.args.iter().zip(argument_types.iter().copied()) {
-            let (column_token_stream, column) = self.parse_function_argument(arg, arg_type);
+            let (column_token_stream, column) = self.parse_function_argument(arg, arg_type)?;
             token_stream.push(column_token_stream);
             columns.extend(column);
         }
-        (token_stream, columns)
+        Ok((token_stream, columns))
     }
 
     /// Translates the provided function arguments to a
@@ -442,11 +706,12 @@ where
         &self,
         args: &FunctionArguments,
         argument_types: &[ExternalTypeRef<'workspace>],
-    ) -> (Vec<TokenStream>, Vec<&'_ DB::Column>) {
+    ) -> Result<(Vec<TokenStream>, Vec<Operand<'workspace>>), crate::Error> {
         match args {
-            FunctionArguments::None => (Vec::new(), Vec::new()),
+            FunctionArguments::None => Ok((Vec::new(), Vec::new())),
             FunctionArguments::Subquery(_) => {
-                unimplemented!("Subquery arguments not supported");
+                Err(self
+                    .error(args, CheckConstraintErrorKind::UnsupportedFunctionArgument("subquery")))
             }
             FunctionArguments::List(args) => {
                 self.parse_function_argument_list(args, argument_types)
@@ -458,7 +723,9 @@ where
     /// [`TokenStream`]
     fn parse_function(
         &self,
-        sqlparser::ast::Function {
+        sql_function: &sqlparser::ast::Function,
+    ) -> Result<(TokenStream, Option<ExternalTypeRef<'workspace>>), crate::Error> {
+        let sqlparser::ast::Function {
             name,
             uses_odbc_syntax,
             parameters,
@@ -467,53 +734,53 @@ where
             null_treatment,
             over,
             within_group,
-        }: &sqlparser::ast::Function,
-    ) -> (TokenStream, Option<ExternalTypeRef<'workspace>>) {
+        } = sql_function;
+        let unsupported_clause = |clause: &'static str| {
+            self.error(sql_function, CheckConstraintErrorKind::UnsupportedFunctionClause(clause))
+        };
         if !within_group.is_empty() {
-            unimplemented!("WithinGroup not supported");
+            return Err(unsupported_clause("WITHIN GROUP clause"));
         }
         if null_treatment.is_some() {
-            unimplemented!("NullTreatment not supported");
+            return Err(unsupported_clause("null treatment clause"));
         }
         if !matches!(parameters, FunctionArguments::None) {
-            unimplemented!("Parameters not supported");
+            return Err(unsupported_clause("parameters clause"));
         }
         if over.is_some() {
-            unimplemented!("Over not supported");
+            return Err(unsupported_clause("OVER clause"));
         }
         if filter.is_some() {
-            unimplemented!("Filter not supported");
+            return Err(unsupported_clause("FILTER clause"));
         }
         if *uses_odbc_syntax {
-            unimplemented!("ODBC syntax not supported");
+            return Err(unsupported_clause("ODBC syntax"));
         }
-        let function = self.function(&name.to_string());
+        let function = self.function(sql_function, &name.to_string())?;
 
         let argument_types = function
             .argument_types(self.workspace, self.database)
             .map(|arg_type| {
-                arg_type.unwrap_or_else(|| {
-                    panic!("Failed to get type for argument of function `{}`", function.name())
+                arg_type.ok_or_else(|| {
+                    self.error(
+                        sql_function,
+                        CheckConstraintErrorKind::MissingArgumentType(function.name().to_string()),
+                    )
                 })
             })
-            .collect::<Vec<ExternalTypeRef>>();
+            .collect::<Result<Vec<ExternalTypeRef>, crate::Error>>()?;
 
-        let (args, scoped_columns) = self.parse_function_arguments(args, &argument_types);
+        let (args, scoped_columns) = self.parse_function_arguments(args, &argument_types)?;
 
         let function_ref: ExternalFunctionRef =
-            function.external_function_ref(self.workspace).unwrap_or_else(|| {
-                panic!(
-                    "The function `{}` should have an external function reference",
-                    function.name()
+            function.external_function_ref(self.workspace).ok_or_else(|| {
+                self.error(
+                    sql_function,
+                    CheckConstraintErrorKind::MissingExternalFunction(function.name().to_string()),
                 )
-            });
-
-        let table_ident = self.table().table_snake_ident();
+            })?;
 
-        let attributes = scoped_columns.iter().map(|scoped_column| {
-            let column_ident = scoped_column.column_snake_ident();
-            quote! { crate::#table_ident::#column_ident::NAME }
-        });
+        let attributes = scoped_columns.iter().map(|scoped_column| &scoped_column.name);
 
         let map_err = match scoped_columns.len() {
             1 => {
@@ -532,46 +799,20 @@ where
                     })
                 }
             }
-            _ => {
-                unimplemented!("More than two scoped columns not supported");
+            number_of_scoped_columns => {
+                return Err(self.error(
+                    sql_function,
+                    CheckConstraintErrorKind::TooManyScopedColumns(number_of_scoped_columns),
+                ));
             }
         };
 
-        (
+        Ok((
             quote! {
                 #function_ref(#(#args),*)#map_err
             },
             None,
-        )
-    }
-
-    /// Parses the provided [`Value`] for the provided
-    /// column.
-    ///
-    /// # Arguments
-    ///
-    /// * `column` - The column for which the value is being parsed
-    /// * `value` - The [`Value`] to
-    ///
-    /// # Panics
-    ///
-    /// * If the provided [`Value`] is not supported
-    /// * If the type of the provided column cannot be determined
-    fn parse_column_value(
-        &self,
-        column: &DB::Column,
-        value: &Value,
-    ) -> (proc_macro2::TokenStream, ExternalTypeRef<'workspace>) {
-        let column_type =
-            column.external_postgres_type(self.workspace, self.database).unwrap_or_else(|| {
-                panic!(
-                    "Failed to get type for column `{}.{}` ({})",
-                    column.table(self.database).table_name(),
-                    column.column_name(),
-                    column.normalized_data_type(self.database)
-                )
-            });
-        self.parse_value(value, Some(column_type))
+        ))
     }
 
     /// Parses the provided [`Value`] to a
@@ -582,30 +823,34 @@ where
     /// * `value` - The [`Value`] to parse
     /// * `type_hint` - The [`ExternalTypeRef`] of the value
     ///
-    /// # Panics
+    /// # Errors
     ///
     /// * If the provided [`Value`] is not supported
     fn parse_value(
         &self,
         value: &Value,
         type_hint: Option<ExternalTypeRef<'workspace>>,
-    ) -> (proc_macro2::TokenStream, ExternalTypeRef<'workspace>) {
+    ) -> Result<(proc_macro2::TokenStream, ExternalTypeRef<'workspace>), crate::Error> {
         match value {
-            Value::Boolean(value) => (quote! { #value }, self.workspace.bool()),
-            Value::Number(value, _) => {
-                match type_hint {
-                    Some(type_hint) => (type_hint.cast(value).unwrap(), type_hint),
-                    None => {
-                        unimplemented!(
-                            "Number without type hint not supported: {:?}",
-                            self.check_constraint
-                        );
-                    }
-                }
+            Value::Boolean(value) => Ok((quote! { #value }, self.workspace.bool())),
+            Value::Number(number, _) => {
+                let type_hint = type_hint.ok_or_else(|| {
+                    self.error(value, CheckConstraintErrorKind::NumberWithoutTypeHint)
+                })?;
+                let casted = type_hint.cast(number).map_err(|_| {
+                    self.error(
+                        value,
+                        CheckConstraintErrorKind::InvalidLiteral(
+                            type_hint.rust_type().to_token_stream().to_string(),
+                        ),
+                    )
+                })?;
+                Ok((casted, type_hint))
             }
-            Value::SingleQuotedString(value) => (quote! { #value }, self.workspace.string()),
+            Value::SingleQuotedString(value) => Ok((quote! { #value }, self.workspace.string())),
             other => {
-                unimplemented!("Unsupported value: {:?}", other);
+                Err(self
+                    .error(other, CheckConstraintErrorKind::UnsupportedValue(other.to_string())))
             }
         }
     }
@@ -618,136 +863,121 @@ where
     /// * `value` - The [`ValueWithSpan`] to parse
     /// * `type_hint` - The [`ExternalTypeRef`] of the value
     ///
-    /// # Panics
+    /// # Errors
     ///
     /// * If the provided [`ValueWithSpan`] is not supported
     fn parse_value_with_span(
         &self,
         value: &sqlparser::ast::ValueWithSpan,
         type_hint: Option<ExternalTypeRef<'workspace>>,
-    ) -> (proc_macro2::TokenStream, ExternalTypeRef<'workspace>) {
+    ) -> Result<(proc_macro2::TokenStream, ExternalTypeRef<'workspace>), crate::Error> {
         self.parse_value(&value.value, type_hint)
     }
 
-    #[allow(clippy::too_many_lines)]
     /// Translates the provided expression to a
     /// [`TokenStream`]
-    pub(super) fn parse(&self, expr: &Expr) -> TokenStream {
-        if let Some(validation_error_token) = self.map_expr_to_validation_error(expr) {
-            return validation_error_token;
+    ///
+    /// # Errors
+    ///
+    /// * If the provided expression cannot be translated
+    pub(super) fn parse(&self, expr: &Expr) -> Result<TokenStream, crate::Error> {
+        if let Some(validation_error_token) = self.map_expr_to_validation_error(expr)? {
+            return Ok(validation_error_token);
         }
 
-        let (internal_token, scoped_columns, _returning_type) = self.inner_parse(expr, None);
+        let (internal_token, scoped_columns, _returning_type) = self.inner_parse(expr, None)?;
 
         if !scoped_columns.is_empty() {
-            unimplemented!("Scoped columns not supported");
+            return Err(self.error(expr, CheckConstraintErrorKind::UnsupportedColumnReference));
         }
 
-        quote! {
+        Ok(quote! {
             #internal_token?;
-        }
+        })
     }
 
     #[allow(clippy::too_many_lines)]
     /// Translates the provided expression to a
     /// [`TokenStream`]
+    ///
+    /// # Errors
+    ///
+    /// * If the provided expression cannot be translated
     fn inner_parse(
         &self,
         expr: &Expr,
         type_hint: Option<ExternalTypeRef<'workspace>>,
-    ) -> (TokenStream, Vec<&'_ DB::Column>, Option<ExternalTypeRef<'workspace>>) {
-        match expr {
+    ) -> Result<Translation<'workspace>, crate::Error> {
+        let unsupported = |reason| self.error(expr, reason);
+        let typed = |returning_type: Option<ExternalTypeRef<'workspace>>| {
+            returning_type.ok_or_else(|| unsupported(CheckConstraintErrorKind::MissingType))
+        };
+        Ok(match expr {
             Expr::Function(function) => {
-                let (token_stream, return_type) = self.parse_function(function);
+                let (token_stream, return_type) = self.parse_function(function)?;
                 (token_stream, Vec::new(), return_type)
             }
             Expr::Cast { kind, expr, data_type: _, array: _, format } => {
-                verify_cast_kind(kind);
+                verify_cast_kind(kind).map_err(unsupported)?;
                 if format.is_some() {
-                    unimplemented!("Format not supported");
+                    return Err(unsupported(CheckConstraintErrorKind::UnsupportedCastFormat));
                 }
-                self.inner_parse(expr, type_hint)
+                self.inner_parse(expr, type_hint)?
             }
-            Expr::Nested(expr) => self.inner_parse(expr, type_hint),
+            Expr::Nested(expr) => self.inner_parse(expr, type_hint)?,
             Expr::Identifier(ident) => {
-                let column = self.column(&ident.value);
-                let column_ident = column.column_snake_ident();
+                let operand = self.operand(expr, &ident.value)?;
+                let operand_ident = &operand.ident;
+                let value_type = operand.value_type;
                 (
                     quote! {
-                        #column_ident
+                        #operand_ident
                     },
-                    vec![column],
-                    Some(
-                        column
-                            .external_postgres_type(self.workspace, self.database)
-                            .unwrap_or_else(|| {
-                                panic!(
-                                    "Failed to get type for column `{}.{}` ({})",
-                                    column.table(self.database).table_name(),
-                                    column.column_name(),
-                                    column.normalized_data_type(self.database)
-                                )
-                            }),
-                    ),
+                    vec![operand],
+                    Some(value_type),
                 )
             }
             Expr::BinaryOp { left, op, right } => {
                 match op {
-                    BinaryOperator::And => {
+                    BinaryOperator::And | BinaryOperator::Or => {
                         let (left, left_scoped_columns, left_returning_type) =
-                            self.inner_parse(left, None);
+                            self.inner_parse(left, None)?;
                         let (right, right_scoped_columns, right_returning_type) =
-                            self.inner_parse(right, None);
+                            self.inner_parse(right, None)?;
                         if !left_scoped_columns.is_empty() || !right_scoped_columns.is_empty() {
-                            unimplemented!("Scoped columns not supported");
+                            return Err(unsupported(
+                                CheckConstraintErrorKind::UnsupportedColumnReference,
+                            ));
                         }
-                        let left_returning_type =
-                            left_returning_type.expect("Left side of AND must have a type");
-                        let right_returning_type =
-                            right_returning_type.expect("Right side of AND must have a type");
-                        if left_returning_type.is_bool() && right_returning_type.is_bool() {
-                            (
-                                match (left.to_string().as_str(), right.to_string().as_str()) {
-                                    ("true", "true") => quote! { true },
-                                    ("false", _) | (_, "false") => quote! { false },
-                                    ("true", _) => quote! { #right },
-                                    (_, "true") => quote! { #left },
-                                    (_, _) => quote! { #left && #right },
-                                },
-                                Vec::new(),
-                                Some(self.workspace.bool()),
-                            )
-                        } else {
-                            unimplemented!("Unsupported binary operation");
+                        let left_returning_type = typed(left_returning_type)?;
+                        let right_returning_type = typed(right_returning_type)?;
+                        if !left_returning_type.is_bool() || !right_returning_type.is_bool() {
+                            return Err(unsupported(CheckConstraintErrorKind::MismatchedTypes(
+                                format!(
+                                    "`{}` {op} `{}`",
+                                    left_returning_type.rust_type().to_token_stream(),
+                                    right_returning_type.rust_type().to_token_stream()
+                                ),
+                            )));
                         }
-                    }
-                    BinaryOperator::Or => {
-                        let (left, left_scoped_columns, left_returning_type) =
-                            self.inner_parse(left, None);
-                        let (right, right_scoped_columns, right_returning_type) =
-                            self.inner_parse(right, None);
-                        if !left_scoped_columns.is_empty() || !right_scoped_columns.is_empty() {
-                            unimplemented!("Scoped columns not supported");
-                        }
-                        let left_returning_type =
-                            left_returning_type.expect("Left side of AND must have a type");
-                        let right_returning_type =
-                            right_returning_type.expect("Right side of AND must have a type");
-                        if left_returning_type.is_bool() && right_returning_type.is_bool() {
-                            (
-                                match (left.to_string().as_str(), right.to_string().as_str()) {
-                                    ("false", "false") => quote! { false },
-                                    ("true", _) | (_, "true") => quote! { true },
-                                    ("false", _) => quote! { #right },
-                                    (_, "false") => quote! { #left },
-                                    (_, _) => quote! { #left || #right },
-                                },
-                                Vec::new(),
-                                Some(self.workspace.bool()),
-                            )
+                        let token_stream = if matches!(op, BinaryOperator::And) {
+                            match (left.to_string().as_str(), right.to_string().as_str()) {
+                                ("true", "true") => quote! { true },
+                                ("false", _) | (_, "false") => quote! { false },
+                                ("true", _) => quote! { #right },
+                                (_, "true") => quote! { #left },
+                                (_, _) => quote! { #left && #right },
+                            }
                         } else {
-                            unimplemented!("Unsupported binary operation");
-                        }
+                            match (left.to_string().as_str(), right.to_string().as_str()) {
+                                ("false", "false") => quote! { false },
+                                ("true", _) | (_, "true") => quote! { true },
+                                ("false", _) => quote! { #right },
+                                (_, "false") => quote! { #left },
+                                (_, _) => quote! { #left || #right },
+                            }
+                        };
+                        (token_stream, Vec::new(), Some(self.workspace.bool()))
                     }
                     BinaryOperator::NotEq
                     | BinaryOperator::Eq
@@ -755,28 +985,21 @@ where
                     | BinaryOperator::Lt
                     | BinaryOperator::GtEq
                     | BinaryOperator::LtEq => {
-                        let (left, _, left_returning_type) = self.inner_parse(left, None);
-                        let left_returning_type =
-                            left_returning_type.expect("Left side of AND must have a type");
+                        let (left, _, left_returning_type) = self.inner_parse(left, None)?;
+                        let left_returning_type = typed(left_returning_type)?;
                         let (right, _, right_returning_type) =
-                            self.inner_parse(right, Some(left_returning_type));
-                        let right_returning_type =
-                            right_returning_type.expect("Right side of AND must have a type");
+                            self.inner_parse(right, Some(left_returning_type))?;
+                        let right_returning_type = typed(right_returning_type)?;
                         if left_returning_type != right_returning_type {
-                            unimplemented!(
-                                "Equality between different types not supported: {left_returning_type:?} and {right_returning_type:?}. {:?}",
-                                self.check_constraint
-                            );
+                            return Err(unsupported(CheckConstraintErrorKind::MismatchedTypes(
+                                format!(
+                                    "`{}` {op} `{}`",
+                                    left_returning_type.rust_type().to_token_stream(),
+                                    right_returning_type.rust_type().to_token_stream()
+                                ),
+                            )));
                         }
-                        let operator_symbol: syn::BinOp = match op {
-                            BinaryOperator::Eq => syn::BinOp::Eq(syn::token::EqEq::default()),
-                            BinaryOperator::NotEq => syn::BinOp::Ne(syn::token::Ne::default()),
-                            BinaryOperator::Gt => syn::BinOp::Gt(syn::token::Gt::default()),
-                            BinaryOperator::Lt => syn::BinOp::Lt(syn::token::Lt::default()),
-                            BinaryOperator::GtEq => syn::BinOp::Ge(syn::token::Ge::default()),
-                            BinaryOperator::LtEq => syn::BinOp::Le(syn::token::Le::default()),
-                            _ => unreachable!(),
-                        };
+                        let operator_symbol = syn_operator(op).map_err(unsupported)?;
                         (
                             quote! {
                                 #left #operator_symbol #right
@@ -790,125 +1013,80 @@ where
                     | BinaryOperator::Multiply
                     | BinaryOperator::Divide
                     | BinaryOperator::Modulo => {
-                        let (left, _, left_returning_type) = self.inner_parse(left, type_hint);
-                        let (right, _, right_returning_type) = self.inner_parse(right, type_hint);
-                        if left_returning_type != right_returning_type {
-                            unimplemented!(
-                                "Binary operation between different types not supported: {left_returning_type:?} and {right_returning_type:?}. {:?}",
-                                self.check_constraint
-                            );
-                        }
-                        let left_returning_type =
-                            left_returning_type.expect("Left side of binary op must have a type");
-                        let right_returning_type =
-                            right_returning_type.expect("Right side of binary op must have a type");
-                        if left_returning_type.is_numeric() && right_returning_type.is_numeric() {
-                            let operator_symbol: syn::BinOp = match op {
-                                BinaryOperator::Plus => {
-                                    syn::BinOp::Add(syn::token::Plus::default())
-                                }
-                                BinaryOperator::Minus => {
-                                    syn::BinOp::Sub(syn::token::Minus::default())
-                                }
-                                BinaryOperator::Multiply => {
-                                    syn::BinOp::Mul(syn::token::Star::default())
-                                }
-                                BinaryOperator::Divide => {
-                                    syn::BinOp::Div(syn::token::Slash::default())
-                                }
-                                BinaryOperator::Modulo => {
-                                    syn::BinOp::Rem(syn::token::Percent::default())
-                                }
-                                _ => unreachable!(),
-                            };
-                            (
-                                quote! {
-                                    #left #operator_symbol #right
-                                },
-                                Vec::new(),
-                                Some(left_returning_type),
-                            )
-                        } else {
-                            unimplemented!(
-                                "Unsupported binary operation {} between {:?} and {:?}",
-                                op,
-                                left_returning_type,
-                                right_returning_type
-                            );
+                        let (left, _, left_returning_type) = self.inner_parse(left, type_hint)?;
+                        let (right, _, right_returning_type) =
+                            self.inner_parse(right, type_hint)?;
+                        let left_returning_type = typed(left_returning_type)?;
+                        let right_returning_type = typed(right_returning_type)?;
+                        if left_returning_type != right_returning_type
+                            || !left_returning_type.is_numeric()
+                        {
+                            return Err(unsupported(CheckConstraintErrorKind::MismatchedTypes(
+                                format!(
+                                    "`{}` {op} `{}`",
+                                    left_returning_type.rust_type().to_token_stream(),
+                                    right_returning_type.rust_type().to_token_stream()
+                                ),
+                            )));
                         }
-                    }
-                    operator => {
-                        unimplemented!("Unsupported binary operator: {operator:?}");
-                    }
-                }
-            }
-            Expr::Value(value) => {
-                let (token_stream, returning_type) = self.parse_value_with_span(value, type_hint);
-                (token_stream, Vec::new(), Some(returning_type))
-            }
-            Expr::IsNull(expr) => {
-                if let Expr::Identifier(Ident { value: ident, .. }) = expr.as_ref() {
-                    let column = self.column(ident);
-                    if !column.is_nullable(self.database) {
-                        unimplemented!(
-                            "IS NULL on non-nullable column `{}` not supported. {:?}",
-                            ident,
-                            self.check_constraint
-                        );
-                    }
-                    if self.is_contextual_column(column) {
-                        (
-                            quote! {
-                                false
-                            },
-                            Vec::new(),
-                            Some(self.workspace.bool()),
-                        )
-                    } else {
-                        let column_ident = column.column_snake_ident();
+                        let operator_symbol: syn::BinOp = match op {
+                            BinaryOperator::Plus => syn::BinOp::Add(syn::token::Plus::default()),
+                            BinaryOperator::Minus => syn::BinOp::Sub(syn::token::Minus::default()),
+                            BinaryOperator::Multiply => {
+                                syn::BinOp::Mul(syn::token::Star::default())
+                            }
+                            BinaryOperator::Divide => syn::BinOp::Div(syn::token::Slash::default()),
+                            BinaryOperator::Modulo => {
+                                syn::BinOp::Rem(syn::token::Percent::default())
+                            }
+                            _ => unreachable!(),
+                        };
                         (
                             quote! {
-                                #column_ident.is_none()
+                                #left #operator_symbol #right
                             },
                             Vec::new(),
-                            Some(self.workspace.bool()),
+                            Some(left_returning_type),
                         )
                     }
-                } else {
-                    let (inner_token, _scoped_columns, _returning_type) =
-                        self.inner_parse(expr, None);
-                    (
-                        quote! {
-                            #inner_token.is_none()
-                        },
-                        Vec::new(),
-                        Some(self.workspace.bool()),
-                    )
+                    operator => {
+                        return Err(unsupported(CheckConstraintErrorKind::UnsupportedOperator(
+                            operator.to_string(),
+                        )));
+                    }
                 }
             }
-            Expr::IsNotNull(expr) => {
-                if let Expr::Identifier(Ident { value: ident, .. }) = expr.as_ref() {
-                    let column = self.column(ident);
-                    if !column.is_nullable(self.database) {
-                        unimplemented!(
-                            "IS NOT NULL on non-nullable column `{}` not supported. {:?}",
-                            ident,
-                            self.check_constraint
-                        );
+            Expr::Value(value) => {
+                let (token_stream, returning_type) =
+                    self.parse_value_with_span(value, type_hint)?;
+                (token_stream, Vec::new(), Some(returning_type))
+            }
+            Expr::IsNull(inner) | Expr::IsNotNull(inner) => {
+                let is_null = matches!(expr, Expr::IsNull(_));
+                if let Expr::Identifier(Ident { value: ident, .. }) = inner.as_ref() {
+                    let operand = self.operand(expr, ident)?;
+                    if !operand.nullable {
+                        return Err(unsupported(
+                            CheckConstraintErrorKind::NullCheckOnNonNullableColumn(ident.clone()),
+                        ));
                     }
-                    if self.is_contextual_column(column) {
+                    if operand.contextual {
                         (
-                            quote! {
-                                true
+                            if is_null {
+                                quote! { false }
+                            } else {
+                                quote! { true }
                             },
                             Vec::new(),
                             Some(self.workspace.bool()),
                         )
                     } else {
-                        let column_ident = column.column_snake_ident();
+                        let operand_ident = &operand.ident;
                         (
-                            quote! {
-                                #column_ident.is_some()
+                            if is_null {
+                                quote! { #operand_ident.is_none() }
+                            } else {
+                                quote! { #operand_ident.is_some() }
                             },
                             Vec::new(),
                             Some(self.workspace.bool()),
@@ -916,23 +1094,19 @@ where
                     }
                 } else {
                     let (inner_token, _scoped_columns, _returning_type) =
-                        self.inner_parse(expr, None);
+                        self.inner_parse(inner, None)?;
                     (
-                        quote! {
-                            #inner_token.is_some()
+                        if is_null {
+                            quote! { #inner_token.is_none() }
+                        } else {
+                            quote! { #inner_token.is_some() }
                         },
                         Vec::new(),
                         Some(self.workspace.bool()),
                     )
                 }
             }
-            _ => {
-                unimplemented!(
-                    "Unsupported expression: {:?}, from check constraint: {:?}",
-                    expr,
-                    self.check_constraint
-                )
-            }
-        }
+            _ => return Err(unsupported(CheckConstraintErrorKind::UnsupportedExpression)),
+        })
     }
 }
@@ -0,0 +1,387 @@
+//! Submodule translating the range functions and operators of the check
+//! expressions, such as `isempty(period)` or `period @> measured_at`, into
+//! the validation errors raised when they do not hold.
+//!
+//! The ranges are held as diesel's `(Bound<T>, Bound<T>)` tuples.
+
+use proc_macro2::TokenStream;
+use quote::{ToTokens, format_ident, quote};
+use sql_traits::traits::DatabaseLike;
+use sqlparser::ast::{
+    BinaryOperator, Expr, FunctionArg, FunctionArgExpr, FunctionArguments, Ident, UnaryOperator,
+    ValueWithSpan,
+};
+
+use super::{Operand, TranslateExpression, invert_operator};
+use crate::structs::{CheckConstraintErrorKind, ExternalTypeRef};
+
+/// Returns the expression evaluating whether the provided lower bound
+/// precedes the provided upper bound, both of type `Bound<&T>`, that is
+/// whether some value may lie between them.
+///
+/// # Arguments
+///
+/// * `lower` - The expression of the lower bound.
+/// * `upper` - The expression of the upper bound.
+fn precedes(lower: &TokenStream, upper: &TokenStream) -> TokenStream {
+    quote! {
+        match (#lower, #upper) {
+            (::core::ops::Bound::Unbounded, _) | (_, ::core::ops::Bound::Unbounded) => true,
+            (::core::ops::Bound::Included(lower), ::core::ops::Bound::Included(upper)) => {
+                lower <= upper
+            }
+            (
+                ::core::ops::Bound::Included(lower) | ::core::ops::Bound::Excluded(lower),
+                ::core::ops::Bound::Included(upper) | ::core::ops::Bound::Excluded(upper),
+            ) => lower < upper,
+        }
+    }
+}
+
+/// Returns the expression evaluating whether the two provided ranges
+/// overlap, that is whether neither of them is empty and each of them starts
+/// before the other ends.
+///
+/// # Arguments
+///
+/// * `left` - The ident of the reference to the first range.
+/// * `right` - The ident of the reference to the second range.
+fn overlaps(left: &syn::Ident, right: &syn::Ident) -> TokenStream {
+    let [left_lower, left_upper, right_lower, right_upper] = [
+        quote! { #left.0.as_ref() },
+        quote! { #left.1.as_ref() },
+        quote! { #right.0.as_ref() },
+        quote! { #right.1.as_ref() },
+    ];
+    let left_not_empty = precedes(&left_lower, &left_upper);
+    let right_not_empty = precedes(&right_lower, &right_upper);
+    let left_starts_before_right_ends = precedes(&left_lower, &right_upper);
+    let right_starts_before_left_ends = precedes(&right_lower, &left_upper);
+    quote! {
+        #left_not_empty
+            && #right_not_empty
+            && #left_starts_before_right_ends
+            && #right_starts_before_left_ends
+    }
+}
+
+/// Returns the provided validation, only run when the provided operands which
+/// may be null hold a value, as Postgres considers the check satisfied when
+/// any of them is null.
+///
+/// # Arguments
+///
+/// * `operands` - The operands of the validation.
+/// * `validation` - The validation, referencing the values of the operands.
+fn when_present(operands: &[&Operand<'_>], validation: TokenStream) -> TokenStream {
+    operands.iter().rev().filter(|operand| operand.nullable && !operand.contextual).fold(
+        validation,
+        |validation, operand| {
+            let ident = &operand.ident;
+            quote! {
+                if let Some(#ident) = #ident.as_ref() {
+                    #validation
+                }
+            }
+        },
+    )
+}
+
+/// Returns the expression of the unique argument of the provided function
+/// call, if the function has the provided name and a single unnamed argument.
+///
+/// # Arguments
+///
+/// * `expr` - The expression which may be the function call.
+/// * `name` - The case-insensitive name of the function.
+fn single_argument<'expr>(expr: &'expr Expr, name: &str) -> Option<&'expr Expr> {
+    let Expr::Function(function) = expr else {
+        return None;
+    };
+    if !function.name.to_string().eq_ignore_ascii_case(name) {
+        return None;
+    }
+    let FunctionArguments::List(arguments) = &function.args else {
+        return None;
+    };
+    match arguments.args.as_slice() {
+        [FunctionArg::Unnamed(FunctionArgExpr::Expr(argument))] => Some(argument),
+        _ => None,
+    }
+}
+
+/// Returns the provided expression stripped of its parentheses.
+fn unnested(mut expr: &Expr) -> &Expr {
+    while let Expr::Nested(inner) = expr {
+        expr = inner;
+    }
+    expr
+}
+
+impl<'workspace, DB> TranslateExpression<'workspace, '_, DB>
+where
+    DB: DatabaseLike,
+{
+    /// Returns the value referenced by the provided expression, alongside
+    /// the type of its bounds, if the expression is an identifier.
+    ///
+    /// # Errors
+    ///
+    /// * If the identifier does not reference a value.
+    /// * If the referenced value is not a range.
+    fn range_operand(
+        &self,
+        expr: &Expr,
+    ) -> Result<Option<(Operand<'workspace>, ExternalTypeRef<'workspace>)>, crate::Error> {
+        let Expr::Identifier(Ident { value: ident, .. }) = unnested(expr) else {
+            return Ok(None);
+        };
+        let operand = self.operand(expr, ident)?;
+        let bound_type = operand
+            .value_type
+            .range_bound_type()
+            .ok_or_else(|| self.error(expr, CheckConstraintErrorKind::NotARange(ident.clone())))?;
+        Ok(Some((operand, bound_type)))
+    }
+
+    /// Maps the provided range expression to a validation error, when
+    /// applicable. The supported expressions are:
+    ///
+    /// * `NOT isempty(range)`, raising an `empty` error;
+    /// * `lower(range)` and `upper(range)` compared with a value, which hold
+    ///   when the bound is infinite;
+    /// * `range @> value` and `value <@ range`, where the value is a column;
+    /// * `range && other` and `NOT (range && other)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `expr` - The expression to map.
+    ///
+    /// # Errors
+    ///
+    /// * If the expression applies a range function or operator to a value
+    ///   which is not a range.
+    /// * If the compared values have mismatched types.
+    pub(super) fn map_range_expr_to_validation_error(
+        &self,
+        expr: &Expr,
+    ) -> Result<Option<TokenStream>, crate::Error> {
+        let subject_name = self.subject_name();
+        let validation_error = quote! { ::validation_errors::ValidationError };
+        match expr {
+            Expr::UnaryOp { op: UnaryOperator::Not, expr: inner } => {
+                let inner = unnested(inner);
+                if let Some(argument) = single_argument(inner, "isempty") {
+                    let Some((operand, _)) = self.range_operand(argument)? else {
+                        return Ok(None);
+                    };
+                    let (ident, name) = (&operand.ident, &operand.name);
+                    let is_not_empty =
+                        precedes(&quote! { #ident.0.as_ref() }, &quote! { #ident.1.as_ref() });
+                    return Ok(Some(when_present(
+                        &[&operand],
+                        quote! {
+                            if !(#is_not_empty) {
+                                return Err(#validation_error::empty(#subject_name, #name));
+                            }
+                        },
+                    )));
+                }
+                if let Expr::BinaryOp { left, op: BinaryOperator::PGOverlap, right } = inner {
+                    let (Some((left, left_bound)), Some((right, right_bound))) =
+                        (self.range_operand(left)?, self.range_operand(right)?)
+                    else {
+                        return Ok(None);
+                    };
+                    self.same_bound_types(inner, left_bound, right_bound)?;
+                    let overlaps = overlaps(&left.ident, &right.ident);
+                    let (left_name, right_name) = (&left.name, &right.name);
+                    return Ok(Some(when_present(
+                        &[&left, &right],
+                        quote! {
+                            if #overlaps {
+                                return Err(#validation_error::strictly_smaller_than(
+                                    #subject_name,
+                                    #left_name,
+                                    #right_name
+                                ));
+                            }
+                        },
+                    )));
+                }
+                Ok(None)
+            }
+            Expr::BinaryOp { left, op: BinaryOperator::PGOverlap, right } => {
+                let (Some((left, left_bound)), Some((right, right_bound))) =
+                    (self.range_operand(left)?, self.range_operand(right)?)
+                else {
+                    return Ok(None);
+                };
+                self.same_bound_types(expr, left_bound, right_bound)?;
+                let overlaps = overlaps(&left.ident, &right.ident);
+                let (left_ident, right_ident) = (&left.ident, &right.ident);
+                let (left_name, right_name) = (&left.name, &right.name);
+                // Ranges which do not overlap lie either entirely before or
+                // entirely after one another.
+                let starts_before_end = precedes(
+                    &quote! { #left_ident.0.as_ref() },
+                    &quote! { #right_ident.1.as_ref() },
+                );
+                Ok(Some(when_present(
+                    &[&left, &right],
+                    quote! {
+                        if !(#overlaps) {
+                            if #starts_before_end {
+                                return Err(#validation_error::greater_than(
+                                    #subject_name,
+                                    #left_name,
+                                    #right_name
+                                ));
+                            }
+                            return Err(#validation_error::smaller_than(
+                                #subject_name,
+                                #left_name,
+                                #right_name
+                            ));
+                        }
+                    },
+                )))
+            }
+            Expr::BinaryOp {
+                left,
+                op: op @ (BinaryOperator::AtArrow | BinaryOperator::ArrowAt),
+                right,
+            } => {
+                let (range, value) = if matches!(op, BinaryOperator::AtArrow) {
+                    (left, right)
+                } else {
+                    (right, left)
+                };
+                let Some((range, bound_type)) = self.range_operand(range)? else {
+                    return Ok(None);
+                };
+                let Expr::Identifier(Ident { value: value_ident, .. }) = unnested(value) else {
+                    return Err(self.error(expr, CheckConstraintErrorKind::UnsupportedExpression));
+                };
+                let value = self.operand(expr, value_ident)?;
+                self.same_bound_types(expr, bound_type, value.value_type)?;
+                let (range_ident, value_ident) = (&range.ident, &value.ident);
+                let (range_name, value_name) = (&range.name, &value.name);
+                let validation = quote! {
+                    match #range_ident.0.as_ref() {
+                        ::core::ops::Bound::Included(lower) if #value_ident < lower => {
+                            return Err(#validation_error::greater_than(
+                                #subject_name,
+                                #value_name,
+                                #range_name
+                            ));
+                        }
+                        ::core::ops::Bound::Excluded(lower) if #value_ident <= lower => {
+                            return Err(#validation_error::strictly_greater_than(
+                                #subject_name,
+                                #value_name,
+                                #range_name
+                            ));
+                        }
+                        _ => {}
+                    }
+                    match #range_ident.1.as_ref() {
+                        ::core::ops::Bound::Included(upper) if #value_ident > upper => {
+                            return Err(#validation_error::smaller_than(
+                                #subject_name,
+                                #value_name,
+                                #range_name
+                            ));
+                        }
+                        ::core::ops::Bound::Excluded(upper) if #value_ident >= upper => {
+                            return Err(#validation_error::strictly_smaller_than(
+                                #subject_name,
+                                #value_name,
+                                #range_name
+                            ));
+                        }
+                        _ => {}
+                    }
+                };
+                Ok(Some(when_present(&[&range, &value], validation)))
+            }
+            Expr::BinaryOp { left, op, right } => {
+                let (function, value, inverted) = match (unnested(left), unnested(right)) {
+                    (function @ Expr::Function(_), Expr::Value(ValueWithSpan { value, .. })) => {
+                        (function, value, false)
+                    }
+                    (Expr::Value(ValueWithSpan { value, .. }), function @ Expr::Function(_)) => {
+                        (function, value, true)
+                    }
+                    _ => return Ok(None),
+                };
+                let (argument, bound_index, bound_ident) =
+                    if let Some(argument) = single_argument(function, "lower") {
+                        (argument, syn::Index::from(0), format_ident!("lower"))
+                    } else if let Some(argument) = single_argument(function, "upper") {
+                        (argument, syn::Index::from(1), format_ident!("upper"))
+                    } else {
+                        return Ok(None);
+                    };
+                let Some((range, bound_type)) = self.range_operand(argument)? else {
+                    return Ok(None);
+                };
+                let op = if inverted {
+                    invert_operator(op).map_err(|reason| self.error(expr, reason))?
+                } else {
+                    op.clone()
+                };
+                // The bound is compared as an operand named after its range,
+                // and the comparison holds for infinite bounds, which are null
+                // in Postgres.
+                let bound = Operand {
+                    ident: bound_ident.clone(),
+                    name: range.name.clone(),
+                    nullable: false,
+                    contextual: false,
+                    textual: bound_type.is_string(),
+                    value_type: bound_type,
+                };
+                let validation = self.map_value_expr_to_operand_error(expr, &bound, value, &op)?;
+                let range_ident = &range.ident;
+                Ok(Some(when_present(
+                    &[&range],
+                    quote! {
+                        if let ::core::ops::Bound::Included(#bound_ident) | ::core::ops::Bound::Excluded(#bound_ident) =
+                            #range_ident.#bound_index.as_ref()
+                        {
+                            #validation
+                        }
+                    },
+                )))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Verifies that the provided types, compared by a range operator, are
+    /// the same.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`MismatchedTypes`](CheckConstraintErrorKind::MismatchedTypes)
+    /// error if the types differ.
+    fn same_bound_types(
+        &self,
+        expr: &Expr,
+        left: ExternalTypeRef<'workspace>,
+        right: ExternalTypeRef<'workspace>,
+    ) -> Result<(), crate::Error> {
+        if left == right {
+            return Ok(());
+        }
+        Err(self.error(
+            expr,
+            CheckConstraintErrorKind::MismatchedTypes(format!(
+                "`{}` and `{}`",
+                left.rust_type().to_token_stream(),
+                right.rust_type().to_token_stream()
+            )),
+        ))
+    }
+}
@@ -17,9 +17,12 @@ use sql_traits::traits::{
 use syn::{Ident, Type};
 
 use crate::{
-    structs::{ExternalTypeRef, Workspace},
+    structs::{Directives, ExternalTypeRef, GenerationWarning, PostgresType, Workspace},
     traits::{CheckConstraintSynLike, TableSynLike},
-    utils::{is_reserved_diesel_keyword, is_reserved_rust_word},
+    utils::{
+        is_current_time, is_reserved_diesel_keyword, is_todo_comment, sanitize_identifier,
+        sanitized_ident, todo_comment,
+    },
 };
 
 /// Trait implemented by types that represent SQL columns and can be used to
@@ -120,11 +123,150 @@ pub trait ColumnSynLike: ColumnLike {
     /// # }
     /// ```
     fn column_snake_ident(&self) -> Ident {
-        let snake_name = self.column_snake_name();
-        if is_reserved_rust_word(&snake_name) {
-            Ident::new_raw(&snake_name, proc_macro2::Span::call_site())
-        } else {
-            Ident::new(&snake_name, proc_macro2::Span::call_site())
+        sanitized_ident(&self.column_snake_name())
+    }
+
+    /// Returns the name of the field representing this column, as derived by
+    /// the naming strategy of the workspace.
+    ///
+    /// The name is sanitized into a valid Rust identifier, and names clashing
+    /// with the items generated by diesel, such as `columns` or `table`, are
+    /// prefixed with a double underscore.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #  fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::GenericDialect;
+    /// use synql::prelude::*;
+    ///
+    /// let db = ParserDB::parse::<GenericDialect>(
+    ///     r#"CREATE TABLE my_table ("table" INT, "self" INT, "Größe" INT, "2fa" INT);"#,
+    /// )?;
+    /// let workspace: Workspace = Workspace::new().into();
+    /// let table = db.table(None, "my_table").unwrap();
+    /// let field_names: Vec<String> =
+    ///     table.columns(&db).map(|column| column.column_field_name(&workspace, &db)).collect();
+    /// assert_eq!(field_names, ["__table", "self_", "grosse", "_2fa"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace` - The workspace where the column is defined.
+    /// * `database` - The database where the column is defined.
+    fn column_field_name(&self, workspace: &Workspace, database: &Self::DB) -> String {
+        let table = self.table(database);
+        let field_name = sanitize_identifier(&workspace.naming_strategy().field_name(
+            table.table_schema(),
+            table.table_name(),
+            self.column_name(),
+        ));
+        if is_reserved_diesel_keyword(&field_name) { format!("__{field_name}") } else { field_name }
+    }
+
+    /// Returns the ident of the field representing this column, as derived by
+    /// the naming strategy of the workspace.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace` - The workspace where the column is defined.
+    /// * `database` - The database where the column is defined.
+    fn column_field_ident(&self, workspace: &Workspace, database: &Self::DB) -> Ident {
+        sanitized_ident(&self.column_field_name(workspace, database))
+    }
+
+    /// Returns the generation directives declared in the comment of this
+    /// column.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - The database where the column is defined.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidDirective`](crate::Error::InvalidDirective) if the
+    /// comment contains a malformed annotation.
+    fn column_directives(&self, database: &Self::DB) -> Result<Directives, crate::Error> {
+        let Some(comment) = self.column_doc(database) else {
+            return Ok(Directives::default());
+        };
+        Directives::parse(comment).map(|(directives, _)| directives).map_err(|reason| {
+            crate::Error::InvalidDirective {
+                target: format!(
+                    "column `{}` of table `{}`",
+                    self.column_name(),
+                    self.table(database).table_name()
+                ),
+                reason,
+            }
+        })
+    }
+
+    /// Returns the documentation of this column, stripped of the directive
+    /// annotations.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - The database where the column is defined.
+    fn column_documentation(&self, database: &Self::DB) -> Option<String> {
+        let comment = self.column_doc(database)?;
+        let documentation = Directives::parse(comment)
+            .map_or_else(|_| comment.to_string(), |(_, documentation)| documentation);
+        (!documentation.is_empty()).then_some(documentation)
+    }
+
+    /// Returns whether this column is skipped by its directives.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - The database where the column is defined.
+    fn is_skipped(&self, database: &Self::DB) -> bool {
+        self.column_directives(database).is_ok_and(|directives| directives.is_skipped())
+    }
+
+    /// Returns whether this column is marked as sensitive by its directives.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - The database where the column is defined.
+    fn is_sensitive(&self, database: &Self::DB) -> bool {
+        self.column_directives(database).is_ok_and(|directives| directives.is_sensitive())
+    }
+
+    /// Returns the external type overriding the Rust type of this column, as
+    /// declared with
+    /// [`column_type`](crate::structs::SynQLBuilder::column_type), if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace` - The workspace where the column is defined.
+    /// * `database` - The database where the column is defined.
+    fn external_type_override<'workspace>(
+        &self,
+        workspace: &'workspace Workspace,
+        database: &Self::DB,
+    ) -> Option<ExternalTypeRef<'workspace>> {
+        let table = self.table(database);
+        workspace.column_type(table.table_schema(), table.table_name(), self.column_name())
+    }
+
+    /// Returns the Rust type overriding the one mapped from the SQL type of
+    /// this column, if any.
+    ///
+    /// The types declared on the builder take precedence over the ones
+    /// declared with the `type` directive.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace` - The workspace where the column is defined.
+    /// * `database` - The database where the column is defined.
+    fn rust_type_override(&self, workspace: &Workspace, database: &Self::DB) -> Option<Type> {
+        match self.external_type_override(workspace, database) {
+            Some(external_type) => Some(external_type.rust_type().clone()),
+            None => self.column_directives(database).ok()?.rust_type(),
         }
     }
 
@@ -166,7 +308,20 @@ pub trait ColumnSynLike: ColumnLike {
     /// # }
     /// ```
     fn column_camel_ident(&self) -> Ident {
-        Ident::new(&self.column_camel_name(), proc_macro2::Span::call_site())
+        sanitized_ident(&self.column_camel_name())
+    }
+
+    /// Returns the normalized postgres type of this column, alongside the
+    /// modifiers it is declared with, such as the SRID of a
+    /// `geography(point, 4326)` or the precision and scale of a
+    /// `numeric(10, 2)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - The database where the column is defined.
+    fn postgres_type(&self, database: &Self::DB) -> PostgresType {
+        PostgresType::parse(&self.data_type(database).to_string())
+            .with_name(self.normalized_data_type(database))
     }
 
     /// Returns the type ref curresponding to the postgres type of this column.
@@ -179,7 +334,7 @@ pub trait ColumnSynLike: ColumnLike {
         workspace: &'workspace Workspace,
         database: &Self::DB,
     ) -> Option<ExternalTypeRef<'workspace>> {
-        workspace.external_postgres_type(self.normalized_data_type(database))
+        workspace.external_postgres_type(&self.postgres_type(database).to_string())
     }
 
     /// Returns the Diesel type of this column.
@@ -195,12 +350,40 @@ pub trait ColumnSynLike: ColumnLike {
 
     /// Returns the Rust type of this column.
     fn rust_type(&self, workspace: &Workspace, database: &Self::DB) -> Option<Type> {
-        let external_type = self.external_postgres_type(workspace, database)?;
-        let rust_type = external_type.rust_type();
+        let rust_type = match self.rust_type_override(workspace, database) {
+            Some(rust_type) => rust_type,
+            None => self.external_postgres_type(workspace, database)?.rust_type().clone(),
+        };
         if self.is_nullable(database) {
             Some(syn::parse_quote!(Option<#rust_type>))
         } else {
-            Some(rust_type.clone())
+            Some(rust_type)
+        }
+    }
+
+    /// Returns the external type of this column, accounting for the types
+    /// declared on the builder and with the `type` directive, which take
+    /// precedence in this order over the one mapped from its SQL type.
+    ///
+    /// A type declared with the `type` directive which is not provided by any
+    /// external crate has no external type, and is assumed to support no
+    /// trait.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace` - The workspace where the column is defined.
+    /// * `database` - The database where the column is defined.
+    fn resolved_external_type<'workspace>(
+        &self,
+        workspace: &'workspace Workspace,
+        database: &Self::DB,
+    ) -> Option<ExternalTypeRef<'workspace>> {
+        if let Some(external_type) = self.external_type_override(workspace, database) {
+            return Some(external_type);
+        }
+        match self.column_directives(database).ok().and_then(|directives| directives.rust_type()) {
+            Some(rust_type) => workspace.external_type(&rust_type),
+            None => self.external_postgres_type(workspace, database),
         }
     }
 
@@ -211,10 +394,8 @@ pub trait ColumnSynLike: ColumnLike {
     /// * `database` - The database connection to use to query the column type.
     /// * `workspace` - The workspace where the column is defined.
     fn supports_copy(&self, database: &Self::DB, workspace: &Workspace) -> bool {
-        match self.external_postgres_type(workspace, database) {
-            Some(external_type) => external_type.supports_copy(),
-            None => false,
-        }
+        self.resolved_external_type(workspace, database)
+            .is_some_and(|external_type| external_type.supports_copy())
     }
 
     /// Returns whether the column type supports the given core trait in Rust.
@@ -229,10 +410,8 @@ pub trait ColumnSynLike: ColumnLike {
         workspace: &Workspace,
         database: &Self::DB,
     ) -> bool {
-        match self.external_postgres_type(workspace, database) {
-            Some(external_type) => external_type.supports_trait(core_trait),
-            None => false,
-        }
+        self.resolved_external_type(workspace, database)
+            .is_some_and(|external_type| external_type.supports_trait(core_trait))
     }
 
     /// Generates the vertical same-as decorators for this column.
@@ -245,7 +424,7 @@ pub trait ColumnSynLike: ColumnLike {
         for same_as in self.dominant_vertical_same_as_columns(database) {
             let parent_table = same_as.table(database);
             let parent_table_ident = parent_table.table_snake_ident();
-            let parent_column_ident = same_as.column_snake_ident();
+            let parent_column_ident = same_as.column_field_ident(workspace, database);
             let parent_table_crate = parent_table.crate_ident(workspace);
             vertical_same_as_decorators.push(quote! {
                 #[same_as(#parent_table_crate::#parent_table_ident::#parent_column_ident)]
@@ -284,7 +463,9 @@ pub trait ColumnSynLike: ColumnLike {
                 unreachable!();
             };
 
-            if !same_as.is_horizontal_same_as_of_triangular(database) {
+            if !same_as.is_horizontal_same_as_of_triangular(database)
+                || same_as.referenced_table(database).is_soft_denied(workspace)
+            {
                 continue;
             }
 
@@ -298,11 +479,11 @@ pub trait ColumnSynLike: ColumnLike {
                     "The column must be either the key or the other column in the foreign key"
                 );
             }
-            let key_ident = key.column_snake_ident();
+            let key_ident = key.column_field_ident(workspace, database);
             let satellite_table = same_as.referenced_table(database);
             let satellite_table_ident = satellite_table.table_snake_ident();
             let satellite_table_crate = satellite_table.crate_ident(workspace);
-            let referenced_column_ident = referenced_column.column_snake_ident();
+            let referenced_column_ident = referenced_column.column_field_ident(workspace, database);
 
             horizontal_same_as_decorators.push(quote! {
                 #[same_as(#satellite_table_crate::#satellite_table_ident::#referenced_column_ident, #key_ident)]
@@ -324,6 +505,9 @@ pub trait ColumnSynLike: ColumnLike {
             };
 
             let referenced_table = foreign_key.referenced_table(database);
+            if referenced_table.is_soft_denied(workspace) {
+                continue;
+            }
             let referenced_table_ident = referenced_table.table_snake_ident();
             let referenced_table_crate = referenced_table.crate_ident(workspace);
             let referenced_table_path = quote! { #referenced_table_crate::#referenced_table_ident };
@@ -352,11 +536,11 @@ pub trait ColumnSynLike: ColumnLike {
     ///
     /// * Returns `crate::Error::ColumnTypeNotFound` if the column type cannot
     ///   be found.
-    ///
-    /// # Panics
-    ///
-    /// * Panics if the default value cannot be casted to the corresponding Rust
-    ///   type.
+    /// * Returns `crate::Error::InvalidDefaultValue` if the default value
+    ///   cannot be cast to the corresponding Rust type, such as
+    ///   `'NaN'::numeric`, or if the default value of a domain does not satisfy
+    ///   its checks or they cannot be evaluated at generation time, unless the
+    ///   workspace is lenient.
     fn generate_default_decorator(
         &self,
         workspace: &Workspace,
@@ -376,6 +560,38 @@ pub trait ColumnSynLike: ColumnLike {
                 sql_type: self.data_type(database).to_string(),
             });
         };
+        // Invalid defaults are rejected, or omitted with a warning in lenient
+        // mode.
+        let reject = |reason: String| {
+            let table_name = self.table(database).table_name().to_string();
+            let column_name = self.column_name().to_string();
+            let default_value = default_value.clone();
+            if workspace.is_lenient() {
+                workspace.warn(GenerationWarning::DroppedDefaultValue {
+                    table_name,
+                    column_name,
+                    default_value,
+                    reason,
+                });
+                Ok(quote! {})
+            } else {
+                Err(crate::Error::InvalidDefaultValue {
+                    table_name,
+                    column_name,
+                    default_value,
+                    reason,
+                })
+            }
+        };
+        // The defaults of the domains are built with the validating `TryFrom`
+        // implementation of their newtype, so that they are checked here.
+        if let Some(Err(reason)) = workspace
+            .user_defined_types()
+            .domain_of(&self.data_type(database).to_string())
+            .map(|domain| domain.validate_default(&default_value))
+        {
+            return reject(reason);
+        }
 
         let casted_default_value = match default_value.as_str() {
             candidate if candidate.starts_with("NULL::") => {
@@ -397,27 +613,39 @@ pub trait ColumnSynLike: ColumnLike {
                     ::rosetta_uuid::Uuid::utc_v7()
                 }
             }
-            "CURRENT_TIMESTAMP" if external_postgres_type.crate_name() == "chrono" => {
-                quote! { ::chrono::Utc::now() }
-            }
-            "CURRENT_TIMESTAMP" if external_postgres_type.crate_name() == "rosetta-utc" => {
-                quote! { ::rosetta_utc::TimestampUTC::default() }
+            candidate
+                if is_current_time(candidate)
+                    && external_postgres_type.current_value().is_some() =>
+            {
+                external_postgres_type.current_value().to_token_stream()
             }
             _ => {
                 let Ok(casted_default_value) = external_postgres_type.cast(&default_value) else {
-                    unimplemented!(
-                        "Default value `{}` for column `{}` in table `{}` with SQL type `{}` cannot be casted to the corresponding Rust type `{}` from crate `{}`. Please implement the `cast` method for the `ExternalTypeRef` corresponding to this SQL type.",
-                        default_value,
-                        self.column_name(),
-                        self.table(database).table_name(),
-                        self.data_type(database),
-                        external_postgres_type.rust_type().to_token_stream().to_string(),
+                    return reject(format!(
+                        "it cannot be cast to the `{}` type of the `{}` crate",
+                        external_postgres_type.rust_type().to_token_stream(),
                         external_postgres_type.crate_name(),
-                    );
+                    ));
                 };
                 casted_default_value
             }
         };
+        // Postgres rounds the decimal defaults to the scale of their column,
+        // with ties away from zero.
+        let casted_default_value = match self.postgres_type(database).scale() {
+            Some(scale) if external_postgres_type.crate_name() == "bigdecimal" => {
+                quote! {
+                    #casted_default_value
+                        .with_scale_round(#scale, ::bigdecimal::RoundingMode::HalfUp)
+                }
+            }
+            _ => casted_default_value,
+        };
+        // Defaults of overridden types are converted from the type they replace.
+        let casted_default_value = match self.rust_type_override(workspace, database) {
+            Some(rust_type) => quote! { <#rust_type>::from(#casted_default_value) },
+            None => casted_default_value,
+        };
 
         Ok(quote! {
             #[table_model(default = #casted_default_value)]
@@ -426,6 +654,9 @@ pub trait ColumnSynLike: ColumnLike {
 
     /// Generates the contextual validations for this column.
     ///
+    /// In lenient mode, the check constraints which cannot be translated are
+    /// replaced by `// TODO` placeholders.
+    ///
     /// # Errors
     ///
     /// Returns an error if validation generation fails.
@@ -455,13 +686,19 @@ pub trait ColumnSynLike: ColumnLike {
                 continue;
             }
 
-            validations.push(check_constraint.to_syn(database, workspace, &[self.borrow()]));
+            validations.push(lenient_translation(
+                check_constraint.to_syn(database, workspace, &[self.borrow()]),
+                workspace,
+            )?);
         }
         Ok(validations)
     }
 
     /// Generates the context-less validations for this column.
     ///
+    /// In lenient mode, the check constraints which cannot be translated are
+    /// replaced by `// TODO` placeholders.
+    ///
     /// # Errors
     ///
     /// Returns an error if validation generation fails.
@@ -475,7 +712,10 @@ pub trait ColumnSynLike: ColumnLike {
             if check_constraint.number_of_columns(database) > 1 {
                 continue;
             }
-            validations.push(check_constraint.to_syn(database, workspace, &[self.borrow()]));
+            validations.push(lenient_translation(
+                check_constraint.to_syn(database, workspace, &[self.borrow()]),
+                workspace,
+            )?);
         }
         Ok(validations)
     }
@@ -494,7 +734,7 @@ pub trait ColumnSynLike: ColumnLike {
         database: &Self::DB,
     ) -> Result<proc_macro2::TokenStream, crate::Error> {
         let table_ident = self.table(database).table_snake_ident();
-        let column_ident = self.column_snake_ident();
+        let column_ident = self.column_field_ident(workspace, database);
         let external_postgres_type =
             self.external_postgres_type(workspace, database).ok_or_else(|| {
                 crate::Error::ColumnTypeNotFound {
@@ -503,11 +743,21 @@ pub trait ColumnSynLike: ColumnLike {
                     sql_type: self.data_type(database).to_string(),
                 }
             })?;
-        let rust_type = external_postgres_type.rust_type();
-
-        let context_less_validations =
-            self.generate_context_less_validations(workspace, database)?;
-        let contextual_validations = self.generate_contextual_validations(workspace, database)?;
+        let rust_type = self
+            .rust_type_override(workspace, database)
+            .unwrap_or_else(|| external_postgres_type.rust_type().clone());
+
+        // The dropped check constraints are reported at the top of the impl, so
+        // that no validation method is generated for them alone.
+        let (mut todos, context_less_validations): (Vec<_>, Vec<_>) = self
+            .generate_context_less_validations(workspace, database)?
+            .into_iter()
+            .partition(is_todo_comment);
+        let (contextual_todos, contextual_validations): (Vec<_>, Vec<_>) = self
+            .generate_contextual_validations(workspace, database)?
+            .into_iter()
+            .partition(is_todo_comment);
+        todos.extend(contextual_todos);
 
         let context_less_validation = if context_less_validations.is_empty() {
             None
@@ -552,6 +802,7 @@ pub trait ColumnSynLike: ColumnLike {
             {
                 type Error = ::validation_errors::ValidationError;
 
+                #(#todos)*
                 #context_less_validation
                 #contextual_validation
             }
@@ -570,15 +821,12 @@ pub trait ColumnSynLike: ColumnLike {
     ) -> Result<proc_macro2::TokenStream, crate::Error> {
         let column_name = self.column_name();
         let table = self.table(database);
-        let (sql_name_decorator, column_ident) = if is_reserved_diesel_keyword(column_name) {
-            let ident_str = format!("__{}", self.column_snake_name());
-            (
-                Some(quote! {#[table_model(sql_name = #column_name)]}),
-                Ident::new(&ident_str, proc_macro2::Span::call_site()),
-            )
-        } else {
-            (None, self.column_snake_ident())
-        };
+        let column_ident = self.column_field_ident(workspace, database);
+        // Fields whose name differs from the one of the column, as it was
+        // normalized, sanitized or overridden by the naming strategy, are
+        // mapped to the name of the column.
+        let sql_name_decorator = (self.column_field_name(workspace, database) != column_name)
+            .then(|| quote! {#[table_model(sql_name = #column_name)]});
 
         let external_postgres_type =
             self.external_postgres_type(workspace, database).ok_or_else(|| {
@@ -588,7 +836,7 @@ pub trait ColumnSynLike: ColumnLike {
                     sql_type: self.data_type(database).to_string(),
                 }
             })?;
-        let documentation = self.column_doc(database).map_or_else(
+        let documentation = self.column_documentation(database).map_or_else(
             || {
                 format!(
                     "Field representing the `{}` column in table `{}`.",
@@ -598,10 +846,44 @@ pub trait ColumnSynLike: ColumnLike {
             },
             ToString::to_string,
         );
-        let rust_type = external_postgres_type.rust_type();
+        // The foreign keys referencing soft-denied tables are elided, and
+        // documented as such on the host columns.
+        let elided_foreign_key_notes = table
+            .foreign_keys(database)
+            .filter(|foreign_key| {
+                foreign_key.referenced_table(database).is_soft_denied(workspace)
+                    && foreign_key
+                        .host_columns(database)
+                        .map(Borrow::borrow)
+                        .any(|column: &<Self::DB as DatabaseLike>::Column| column == self.borrow())
+            })
+            .map(|foreign_key| {
+                let referenced_table = foreign_key.referenced_table(database);
+                let referenced_columns = foreign_key
+                    .referenced_columns(database)
+                    .map(|column| format!("`{}`", column.column_name()))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let note = format!(
+                    "The foreign key referencing {referenced_columns} in table `{}` was elided, as the table is not part of the workspace.",
+                    referenced_table.table_name()
+                );
+                quote! {
+                    #[doc = ""]
+                    #[doc = #note]
+                }
+            })
+            .collect::<Vec<_>>();
+        let rust_type_override = self.rust_type_override(workspace, database);
+        let rust_type = rust_type_override
+            .clone()
+            .unwrap_or_else(|| external_postgres_type.rust_type().clone());
         let diesel_type = external_postgres_type.diesel_type();
         let mut sql_type_decorator = None;
-        if !["std", "core"].contains(&external_postgres_type.crate_name()) {
+        if rust_type_override.is_some()
+            || external_postgres_type.is_range()
+            || !["std", "core"].contains(&external_postgres_type.crate_name())
+        {
             sql_type_decorator = Some(quote! {
                 #[diesel(sql_type = #diesel_type)]
             });
@@ -637,14 +919,23 @@ pub trait ColumnSynLike: ColumnLike {
             None
         };
 
-        let rust_type = if self.is_nullable(database) {
+        let rust_type: Type = if self.is_nullable(database) {
             syn::parse_quote!(Option<#rust_type>)
         } else {
-            rust_type.clone()
+            rust_type
         };
 
+        // Sensitive fields are not serialized, and are redacted from the
+        // `Debug` implementation of the struct. As they are missing from the
+        // serialized rows, they deserialize to their default value so that the
+        // rows can be deserialized back, which `check_directives` ensures to be
+        // available.
+        let sensitive_decorator =
+            self.is_sensitive(database).then(|| quote! {#[serde(skip_serializing, default)]});
+
         Ok(quote! {
             #[doc = #documentation]
+            #(#elided_foreign_key_notes)*
             #(#vertical_same_as_decorators)*
             #(#horizontal_same_as_decorators)*
             #(#triangular_same_as_decorators)*
@@ -652,9 +943,49 @@ pub trait ColumnSynLike: ColumnLike {
             #infallible_decorator
             #sql_type_decorator
             #sql_name_decorator
+            #sensitive_decorator
             #column_ident: #rust_type
         })
     }
 }
 
 impl<T: ColumnLike> ColumnSynLike for T {}
+
+/// Returns the provided check constraint translation or, in lenient mode, a
+/// `// TODO` placeholder in place of a check constraint which cannot be
+/// translated, recording the corresponding warning in the workspace.
+///
+/// # Arguments
+///
+/// * `translation` - The result of the translation of the check constraint.
+/// * `workspace` - The workspace being generated.
+///
+/// # Errors
+///
+/// Returns the translation error, unless the workspace is lenient and the
+/// check constraint is unsupported.
+fn lenient_translation(
+    translation: Result<proc_macro2::TokenStream, crate::Error>,
+    workspace: &Workspace,
+) -> Result<proc_macro2::TokenStream, crate::Error> {
+    match translation {
+        Err(crate::Error::UnsupportedCheckConstraint {
+            table_name,
+            constraint,
+            sub_expression,
+            reason,
+        }) if workspace.is_lenient() => {
+            let placeholder = todo_comment(&format!(
+                "unsupported check constraint `{constraint}`, cannot translate `{sub_expression}`: {reason}"
+            ));
+            workspace.warn(GenerationWarning::DroppedCheckConstraint {
+                table_name,
+                constraint,
+                sub_expression,
+                reason,
+            });
+            Ok(placeholder)
+        }
+        translation => translation,
+    }
+}
@@ -19,12 +19,12 @@ pub trait ForeignKeySynLike: ForeignKeyLike {
         let foreign_table_crate_ident = self.referenced_table(database).crate_ident(workspace);
         let host_column_paths = self
             .host_columns(database)
-            .map(ColumnSynLike::column_snake_ident)
+            .map(|col| col.column_field_ident(workspace, database))
             .collect::<Vec<syn::Ident>>();
         let foreign_column_paths = self
             .referenced_columns(database)
             .map(|col| {
-                let col_ident = col.column_snake_ident();
+                let col_ident = col.column_field_ident(workspace, database);
                 if self.is_self_referential(database) {
                     syn::parse_quote!(#foreign_table_ident::#col_ident)
                 } else {
@@ -0,0 +1,19 @@
+//! Submodule defining the `MaybeSync` marker trait, which requires `Sync` only
+//! when the `rayon` feature is enabled and crates are generated in parallel.
+
+#[cfg(feature = "rayon")]
+/// Marker trait for types which can be shared across the threads generating
+/// the crates of the workspace.
+pub trait MaybeSync: Sync {}
+
+#[cfg(feature = "rayon")]
+impl<T: Sync + ?Sized> MaybeSync for T {}
+
+#[cfg(not(feature = "rayon"))]
+/// Marker trait for types which can be shared across the threads generating
+/// the crates of the workspace. Without the `rayon` feature, crates are
+/// generated sequentially and every type satisfies it.
+pub trait MaybeSync {}
+
+#[cfg(not(feature = "rayon"))]
+impl<T: ?Sized> MaybeSync for T {}
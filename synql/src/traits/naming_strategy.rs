@@ -0,0 +1,106 @@
+//! Submodule defining the `NamingStrategy` trait, which determines how the
+//! names of the generated crates, structs and fields are derived from the SQL
+//! schema.
+
+use std::sync::Arc;
+
+use heck::{ToSnakeCase, ToUpperCamelCase};
+use inflection_rs::inflection::singularize;
+
+/// Trait defining how the names of the generated crates, structs and fields
+/// are derived from the names of the SQL tables and columns.
+///
+/// All methods have default implementations reproducing the default naming
+/// of SynQL, so that implementors only need to override the names they want
+/// to customize. Explicit overrides, such as naming the struct of the `data`
+/// table `Datum`, are provided by
+/// [`NamingOverrides`](crate::structs::NamingOverrides).
+pub trait NamingStrategy: std::fmt::Debug + Send + Sync {
+    /// Returns the snake-cased name of the table, used to name its crate.
+    ///
+    /// # Arguments
+    ///
+    /// * `table_schema` - The schema of the table, if any.
+    /// * `table_name` - The SQL name of the table.
+    fn table_name(&self, table_schema: Option<&str>, table_name: &str) -> String {
+        let _ = table_schema;
+        table_name.to_snake_case()
+    }
+
+    /// Returns the name of the crate of a table.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_name` - The name of the workspace.
+    /// * `table_schema` - The schema of the table, if any.
+    /// * `table_name` - The SQL name of the table.
+    /// * `qualified_name` - The name of the table, as returned by
+    ///   [`table_name`](Self::table_name) and qualified with its schema.
+    fn crate_name(
+        &self,
+        workspace_name: &str,
+        table_schema: Option<&str>,
+        table_name: &str,
+        qualified_name: &str,
+    ) -> String {
+        let _ = (table_schema, table_name);
+        format!("{workspace_name}-{qualified_name}")
+    }
+
+    /// Returns the name of the struct representing a row of a table.
+    ///
+    /// # Arguments
+    ///
+    /// * `table_schema` - The schema of the table, if any.
+    /// * `table_name` - The SQL name of the table.
+    fn struct_name(&self, table_schema: Option<&str>, table_name: &str) -> String {
+        let _ = table_schema;
+        singularize(table_name).to_upper_camel_case()
+    }
+
+    /// Returns the name of the field representing a column.
+    ///
+    /// # Arguments
+    ///
+    /// * `table_schema` - The schema of the table of the column, if any.
+    /// * `table_name` - The SQL name of the table of the column.
+    /// * `column_name` - The SQL name of the column.
+    fn field_name(
+        &self,
+        table_schema: Option<&str>,
+        table_name: &str,
+        column_name: &str,
+    ) -> String {
+        let _ = (table_schema, table_name);
+        column_name.to_snake_case()
+    }
+}
+
+impl<N: NamingStrategy + ?Sized> NamingStrategy for Arc<N> {
+    fn table_name(&self, table_schema: Option<&str>, table_name: &str) -> String {
+        self.as_ref().table_name(table_schema, table_name)
+    }
+
+    fn crate_name(
+        &self,
+        workspace_name: &str,
+        table_schema: Option<&str>,
+        table_name: &str,
+        qualified_name: &str,
+    ) -> String {
+        self.as_ref().crate_name(workspace_name, table_schema, table_name, qualified_name)
+    }
+
+    fn struct_name(&self, table_schema: Option<&str>, table_name: &str) -> String {
+        self.as_ref().struct_name(table_schema, table_name)
+    }
+
+    fn field_name(
+        &self,
+        table_schema: Option<&str>,
+        table_name: &str,
+        column_name: &str,
+    ) -> String {
+        self.as_ref().field_name(table_schema, table_name, column_name)
+    }
+}
@@ -2,10 +2,13 @@
 
 use sql_traits::traits::DatabaseLike;
 
-use crate::traits::TableSynLike;
+use crate::traits::{MaybeSync, TableSynLike};
 
 /// Trait representing a database that can be used with `SynQL`.
-pub trait SynQLDatabaseLike: DatabaseLike
+///
+/// When the `rayon` feature is enabled, the database and its tables must be
+/// `Sync`, as the crates of the workspace are generated in parallel.
+pub trait SynQLDatabaseLike: DatabaseLike<Table: MaybeSync> + MaybeSync
 where
     <Self as DatabaseLike>::Table: TableSynLike,
 {
@@ -13,7 +16,7 @@ where
 
 impl<DB> SynQLDatabaseLike for DB
 where
-    DB: DatabaseLike,
-    DB::Table: TableSynLike,
+    DB: DatabaseLike + MaybeSync,
+    DB::Table: TableSynLike + MaybeSync,
 {
 }
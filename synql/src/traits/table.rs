@@ -21,12 +21,12 @@ use strum::IntoEnumIterator;
 use syn::Ident;
 
 use crate::{
-    structs::{ExternalCrate, TomlDependency, Trait, Workspace},
+    structs::{Directives, ExternalCrate, TomlDependency, Trait, Workspace},
     traits::{
         ColumnSynLike, UniqueIndexSynLike, foreign_key::ForeignKeySynLike,
         function::FunctionSynLike,
     },
-    utils::is_reserved_rust_word,
+    utils::{sanitize_identifier, sanitized_ident},
 };
 
 /// Trait implemented by types that represent SQL tables and can be used to
@@ -42,7 +42,186 @@ where
     /// * `workspace` - The workspace where the crate is defined.
     #[must_use]
     fn crate_name(&self, workspace: &Workspace) -> String {
-        format!("{}-{}", workspace.name(), self.table_snake_name())
+        workspace.naming_strategy().crate_name(
+            workspace.name(),
+            self.table_schema(),
+            self.table_name(),
+            &self.qualified_table_snake_name(workspace),
+        )
+    }
+
+    /// Returns the snake-cased name of this table, as derived by the naming
+    /// strategy of the workspace and qualified with its schema according to
+    /// the schema naming scheme of the workspace.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace` - The workspace where the table is defined.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #  fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sql_traits::prelude::*;
+    /// use sqlparser::dialect::GenericDialect;
+    /// use synql::prelude::*;
+    ///
+    /// let db = ParserDB::parse::<GenericDialect>(
+    ///     "CREATE TABLE public.users (id INT); CREATE TABLE auth.users (id INT);",
+    /// )?;
+    /// let workspace: Workspace = Workspace::new().into();
+    /// let public_users = db.table(Some("public"), "users").unwrap();
+    /// let auth_users = db.table(Some("auth"), "users").unwrap();
+    /// assert_eq!(public_users.qualified_table_snake_name(&workspace), "users");
+    /// assert_eq!(auth_users.qualified_table_snake_name(&workspace), "auth_users");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    fn qualified_table_snake_name(&self, workspace: &Workspace) -> String {
+        workspace.schema_naming().qualify(
+            self.table_schema(),
+            &sanitize_identifier(
+                &workspace.naming_strategy().table_name(self.table_schema(), self.table_name()),
+            ),
+        )
+    }
+
+    /// Returns the snake-cased ident of this table, qualified with its schema
+    /// according to the schema naming scheme of the workspace.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace` - The workspace where the table is defined.
+    #[must_use]
+    fn qualified_table_snake_ident(&self, workspace: &Workspace) -> Ident {
+        sanitized_ident(&self.qualified_table_snake_name(workspace))
+    }
+
+    /// Returns the ident of the struct representing a row of this table, as
+    /// derived by the naming strategy of the workspace.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace` - The workspace where the table is defined.
+    #[must_use]
+    fn struct_ident(&self, workspace: &Workspace) -> Ident {
+        sanitized_ident(
+            &workspace.naming_strategy().struct_name(self.table_schema(), self.table_name()),
+        )
+    }
+
+    /// Returns the ident of the struct representing a row of this table,
+    /// qualified with its schema according to the schema naming scheme of the
+    /// workspace.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace` - The workspace where the table is defined.
+    #[must_use]
+    fn qualified_struct_ident(&self, workspace: &Workspace) -> Ident {
+        let struct_name =
+            workspace.naming_strategy().struct_name(self.table_schema(), self.table_name());
+        let qualified_name = match workspace.schema_naming().qualifier(self.table_schema()) {
+            Some(schema) => format!("{}{struct_name}", schema.to_upper_camel_case()),
+            None => struct_name,
+        };
+        sanitized_ident(&qualified_name)
+    }
+
+    /// Returns whether this table is soft-denied in the provided workspace,
+    /// meaning that its crate is not generated and that the foreign keys
+    /// referencing it are elided.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace` - The workspace being generated.
+    #[must_use]
+    fn is_soft_denied(&self, workspace: &Workspace) -> bool {
+        workspace.is_soft_denied(self.table_schema(), self.table_name())
+    }
+
+    /// Returns the generation directives declared in the comment of this
+    /// table.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - The database where the table is defined.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidDirective`](crate::Error::InvalidDirective) if the
+    /// comment contains a malformed annotation.
+    fn table_directives(&self, database: &Self::DB) -> Result<Directives, crate::Error> {
+        let Some(comment) = self.table_doc(database) else {
+            return Ok(Directives::default());
+        };
+        Directives::parse(comment).map(|(directives, _)| directives).map_err(|reason| {
+            crate::Error::InvalidDirective {
+                target: format!("table `{}`", self.table_name()),
+                reason,
+            }
+        })
+    }
+
+    /// Returns the documentation of this table, stripped of the directive
+    /// annotations.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - The database where the table is defined.
+    fn table_documentation(&self, database: &Self::DB) -> Option<String> {
+        let comment = self.table_doc(database)?;
+        let documentation = Directives::parse(comment)
+            .map_or_else(|_| comment.to_string(), |(_, documentation)| documentation);
+        (!documentation.is_empty()).then_some(documentation)
+    }
+
+    /// Iterates over the columns of this table which are not skipped by their
+    /// directives.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - The database where the table is defined.
+    fn generated_columns<'db>(
+        &'db self,
+        database: &'db Self::DB,
+    ) -> impl Iterator<Item = &'db <Self::DB as DatabaseLike>::Column> + 'db {
+        self.columns(database).filter(move |column| !column.is_skipped(database))
+    }
+
+    /// Generates a `Debug` implementation for the struct of this table which
+    /// redacts the fields of the sensitive columns.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace` - The workspace where the table is defined.
+    /// * `database` - The database where the table is defined.
+    fn generate_redacted_debug_impl(
+        &self,
+        workspace: &Workspace,
+        database: &Self::DB,
+    ) -> proc_macro2::TokenStream {
+        let struct_ident = self.struct_ident(workspace);
+        let struct_name = struct_ident.to_string();
+        let fields = self.generated_columns(database).map(|column| {
+            let field_ident = column.column_field_ident(workspace, database);
+            let field_name = column.column_field_name(workspace, database);
+            if column.is_sensitive(database) {
+                quote! { .field(#field_name, &"[REDACTED]") }
+            } else {
+                quote! { .field(#field_name, &self.#field_ident) }
+            }
+        });
+        quote! {
+            impl ::core::fmt::Debug for #struct_ident {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    f.debug_struct(#struct_name)
+                        #(#fields)*
+                        .finish()
+                }
+            }
+        }
     }
 
     /// Returns the ident of the crate associated with this table.
@@ -52,7 +231,7 @@ where
     /// * `workspace` - The workspace where the crate is defined.
     #[must_use]
     fn crate_ident(&self, workspace: &Workspace) -> Ident {
-        Ident::new(&self.crate_name(workspace).replace('-', "_"), proc_macro2::Span::call_site())
+        sanitized_ident(&self.crate_name(workspace).replace('-', "_"))
     }
 
     /// Returns the absolute path of the crate associated with this table.
@@ -91,14 +270,10 @@ where
             .expect("Should be able to set path")
     }
 
-    /// Returns the ident of this table, with no normalization applied.
+    /// Returns the ident of this table, with no normalization applied besides
+    /// its sanitization into a valid Rust identifier.
     fn table_ident(&self) -> Ident {
-        let table_name = self.table_name();
-        if is_reserved_rust_word(table_name) {
-            Ident::new_raw(table_name, proc_macro2::Span::call_site())
-        } else {
-            Ident::new(table_name, proc_macro2::Span::call_site())
-        }
+        sanitized_ident(self.table_name())
     }
 
     /// Returns the snake-cased name of this table.
@@ -185,12 +360,7 @@ where
     /// # }
     /// ```
     fn table_snake_ident(&self) -> Ident {
-        let snake_name = self.table_snake_name();
-        if is_reserved_rust_word(&snake_name) {
-            Ident::new_raw(&snake_name, proc_macro2::Span::call_site())
-        } else {
-            Ident::new(&snake_name, proc_macro2::Span::call_site())
-        }
+        sanitized_ident(&self.table_snake_name())
     }
 
     /// Returns the singular snake-cased ident of this table.
@@ -210,12 +380,7 @@ where
     /// # }
     /// ```
     fn table_singular_snake_ident(&self) -> Ident {
-        let snake_name = self.table_singular_snake_name();
-        if is_reserved_rust_word(&snake_name) {
-            Ident::new_raw(&snake_name, proc_macro2::Span::call_site())
-        } else {
-            Ident::new(&snake_name, proc_macro2::Span::call_site())
-        }
+        sanitized_ident(&self.table_singular_snake_name())
     }
 
     /// Returns the camel-cased name of this table.
@@ -275,12 +440,7 @@ where
     /// # }
     /// ```
     fn table_camel_ident(&self) -> Ident {
-        let camel_name = self.table_camel_name();
-        if is_reserved_rust_word(&camel_name) {
-            Ident::new_raw(&camel_name, proc_macro2::Span::call_site())
-        } else {
-            Ident::new(&camel_name, proc_macro2::Span::call_site())
-        }
+        sanitized_ident(&self.table_camel_name())
     }
 
     /// Returns the singular camel-cased ident of this table.
@@ -299,20 +459,17 @@ where
     /// # }
     /// ```
     fn table_singular_camel_ident(&self) -> Ident {
-        let camel_name = self.table_singular_camel_name();
-        if is_reserved_rust_word(&camel_name) {
-            Ident::new_raw(&camel_name, proc_macro2::Span::call_site())
-        } else {
-            Ident::new(&camel_name, proc_macro2::Span::call_site())
-        }
+        sanitized_ident(&self.table_singular_camel_name())
     }
 
     /// Iterates over the identifiers of the primary key columns of this table.
     fn primary_key_idents<'db>(
         &'db self,
+        workspace: &'db Workspace,
         database: &'db Self::DB,
     ) -> impl Iterator<Item = Ident> + 'db {
-        self.primary_key_columns(database).map(ColumnSynLike::column_snake_ident)
+        self.primary_key_columns(database)
+            .map(move |column| column.column_field_ident(workspace, database))
     }
 
     /// Returns the sorted unique external crates required by this table,
@@ -327,6 +484,9 @@ where
             if let Some(postgres_type) = column.external_postgres_type(workspace, database) {
                 crates.push(postgres_type.external_crate());
             }
+            if let Some(overriding_type) = column.external_type_override(workspace, database) {
+                crates.push(overriding_type.external_crate());
+            }
         }
         for check_constraint in self.check_constraints(database) {
             for function in check_constraint.functions(database) {
@@ -348,7 +508,10 @@ where
     fn supported_core_derives(&self, database: &Self::DB, workspace: &Workspace) -> Vec<syn::Path> {
         let mut derives = Vec::new();
         for core_trait in Trait::iter() {
-            if self.columns(database).all(|col| col.supports(core_trait, workspace, database)) {
+            if self
+                .generated_columns(database)
+                .all(|col| col.supports(core_trait, workspace, database))
+            {
                 derives.push(core_trait.path());
             }
         }
@@ -371,7 +534,7 @@ where
         database: &Self::DB,
     ) -> Result<Vec<proc_macro2::TokenStream>, crate::Error> {
         let mut attributes = Vec::new();
-        for column in self.columns(database) {
+        for column in self.generated_columns(database) {
             let attr_tokens = column.generate_struct_field(workspace, database)?;
             attributes.push(attr_tokens);
         }
@@ -384,12 +547,17 @@ where
     /// # Arguments
     ///
     /// * `database` - The database where the table is defined.
+    /// * `workspace` - The workspace where the table is defined.
     ///
     /// # Errors
     ///
     /// Returns an error if the external type of any column cannot be
     /// determined.
-    fn unique_indices_macros(&self, database: &Self::DB) -> Vec<proc_macro2::TokenStream> {
+    fn unique_indices_macros(
+        &self,
+        database: &Self::DB,
+        workspace: &Workspace,
+    ) -> Vec<proc_macro2::TokenStream> {
         let mut indices = Vec::new();
         for unique_index in self.unique_indices(database) {
             // Primary keys already automatically have unique constraints macro generated,
@@ -397,7 +565,7 @@ where
             if unique_index.is_primary_key(database) {
                 continue;
             }
-            indices.push(unique_index.to_syn(database));
+            indices.push(unique_index.to_syn(database, workspace));
         }
         indices
     }
@@ -421,7 +589,7 @@ where
             table_root
                 .columns_referring_to_table_lists(database)
                 .map(|col| {
-                    let col_ident = col.column_snake_ident();
+                    let col_ident = col.column_field_ident(workspace, database);
                     quote! {
                         #[table_model(default(#root_table_crate::#root_table_ident::#col_ident, #current_table_name))]
                     }
@@ -433,7 +601,8 @@ where
     }
 
     /// Returns the decorators for the foreign keys of this table, excluding
-    /// those which are not already handled by same-as relations.
+    /// those which are not already handled by same-as relations and those
+    /// referencing soft-denied tables.
     ///
     /// # Arguments
     ///
@@ -449,6 +618,7 @@ where
             if foreign_key.is_vertical_same_as(database)
                 || foreign_key.is_horizontal_same_as(database)
                 || foreign_key.is_triangular_same_as(database)
+                || foreign_key.referenced_table(database).is_soft_denied(workspace)
             {
                 continue;
             }
@@ -480,7 +650,8 @@ where
     }
 
     /// Generates the `#[diesel(belongs_to(Foo, foreign_key = mykey))]`
-    /// decorators for this table.
+    /// decorators for this table, skipping the foreign keys referencing
+    /// soft-denied tables.
     fn generate_belonging_to_decorators(
         &self,
         database: &Self::DB,
@@ -488,21 +659,24 @@ where
     ) -> Vec<proc_macro2::TokenStream> {
         let mut decorators = Vec::new();
         for foreign_key in self.foreign_keys(database) {
-            if foreign_key.is_composite(database) || !foreign_key.is_singleton(database) {
+            if foreign_key.is_composite(database)
+                || !foreign_key.is_singleton(database)
+                || foreign_key.referenced_table(database).is_soft_denied(workspace)
+            {
                 continue;
             }
             let host_column = foreign_key
                 .host_columns(database)
                 .next()
                 .expect("Expected at least one host column for non-composite foreign key")
-                .column_snake_ident();
+                .column_field_ident(workspace, database);
             let referenced_table = foreign_key.referenced_table(database);
             let foreign_crate_ident = if foreign_key.is_self_referential(database) {
                 None
             } else {
                 Some(referenced_table.crate_ident(workspace))
             };
-            let foreign_table_ident = referenced_table.table_singular_camel_ident();
+            let foreign_table_ident = referenced_table.struct_ident(workspace);
             decorators.push(quote! {
                 #[diesel(belongs_to(#foreign_crate_ident::#foreign_table_ident, foreign_key = #host_column))]
             });
@@ -534,12 +708,12 @@ where
         self.ancestral_extended_tables(database).into_iter().flat_map(move |t| {
             t.primary_key_columns(database).zip(self.primary_key_columns(database)).map(
                 move |(ancestor_pk_col, self_pk_col)| {
-                    let struct_ident = self.table_singular_camel_ident();
+                    let struct_ident = self.struct_ident(workspace);
                     let table_ident = self.table_snake_ident();
                     let ancestor_table_ident = t.table_snake_ident();
                     let ancestor_table_crate_ident = t.crate_ident(workspace);
-                    let ancestor_pk_col_ident = ancestor_pk_col.column_snake_ident();
-                    let self_pk_col_ident = self_pk_col.column_snake_ident();
+                    let ancestor_pk_col_ident = ancestor_pk_col.column_field_ident(workspace, database);
+                    let self_pk_col_ident = self_pk_col.column_field_ident(workspace, database);
                     quote! {
                         impl ::diesel_builders::GetColumn<#ancestor_table_crate_ident::#ancestor_table_ident::#ancestor_pk_col_ident> for #struct_ident {
                             fn get_column_ref(&self) -> &<#table_ident::#self_pk_col_ident as ::diesel_builders::ColumnTyped>::ColumnType {
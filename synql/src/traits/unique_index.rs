@@ -5,17 +5,22 @@ use proc_macro2::TokenStream;
 use quote::quote;
 use sql_traits::traits::UniqueIndexLike;
 
-use crate::traits::{ColumnSynLike, TableSynLike};
+use crate::{
+    structs::Workspace,
+    traits::{ColumnSynLike, TableSynLike},
+};
 
 /// Trait extending `UniqueIndexLike` to provide methods for generating
 /// Rust code representations of SQL unique indexes.
 pub trait UniqueIndexSynLike: UniqueIndexLike {
     /// Converts the unique index to its Syn representation,
     /// using the `diesel-builders` crate.
-    fn to_syn(&self, database: &Self::DB) -> TokenStream {
+    fn to_syn(&self, database: &Self::DB, workspace: &Workspace) -> TokenStream {
         let table_ident = self.table(database).table_ident();
-        let column_idents =
-            self.columns(database).map(ColumnSynLike::column_snake_ident).map(|ident| {
+        let column_idents = self
+            .columns(database)
+            .map(|column| column.column_field_ident(workspace, database))
+            .map(|ident| {
                 quote! {#table_ident::#ident}
             });
         quote! {
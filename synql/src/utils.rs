@@ -1,3 +1,10 @@
 //! Utility functions for code generation and string manipulation.
 mod is_reserved_rust_word;
 pub use is_reserved_rust_word::*;
+mod sanitize_identifier;
+pub use sanitize_identifier::*;
+mod format_rust_code;
+pub use format_rust_code::format_rust_code;
+pub(crate) use format_rust_code::{is_todo_comment, todo_comment};
+mod is_current_time;
+pub use is_current_time::is_current_time;
@@ -0,0 +1,128 @@
+//! Submodule providing a function to pretty-print generated Rust code.
+
+use proc_macro2::{Ident, Literal, Span, TokenStream, TokenTree};
+use quote::quote;
+use syn::visit_mut::VisitMut;
+
+/// Name of the macro marking where a `// TODO` comment is placed in the
+/// generated code, as comments cannot be represented in a token stream.
+const TODO_MARKER: &str = "__synql_todo__";
+
+/// Pretty-prints the provided Rust code, which must represent a whole file.
+///
+/// The code is formatted in-process with `prettyplease`, so that the generated
+/// files are readable and diffable without requiring `rustfmt`. The
+/// placeholders created by [`todo_comment`] are replaced by the corresponding
+/// `// TODO` comments.
+///
+/// # Arguments
+///
+/// * `code` - The token stream representing the contents of a Rust file.
+///
+/// # Errors
+///
+/// * Returns an error if the provided code cannot be parsed as a Rust file.
+/// * Returns an error if a placeholder does not hold a string literal.
+pub fn format_rust_code(code: TokenStream) -> Result<String, syn::Error> {
+    let mut file: syn::File = syn::parse2(code)?;
+    let mut todos = TodoMessages::default();
+    todos.visit_file_mut(&mut file);
+    if let Some(error) = todos.error {
+        return Err(error);
+    }
+    Ok(todos.splice(&prettyplease::unparse(&file)))
+}
+
+#[derive(Default)]
+/// Collects the messages of the placeholders created by [`todo_comment`],
+/// replacing each of them by a placeholder holding the index of its message,
+/// so that the placeholders are formatted on a single line whatever their
+/// message, and are then spliced into `// TODO` comments.
+struct TodoMessages {
+    /// The messages of the placeholders, in the order they were visited.
+    messages: Vec<String>,
+    /// The error raised by a placeholder which does not hold a string literal.
+    error: Option<syn::Error>,
+}
+
+impl VisitMut for TodoMessages {
+    fn visit_macro_mut(&mut self, placeholder: &mut syn::Macro) {
+        if !placeholder.path.is_ident(TODO_MARKER) {
+            syn::visit_mut::visit_macro_mut(self, placeholder);
+            return;
+        }
+        match placeholder.parse_body::<syn::LitStr>() {
+            Ok(message) => {
+                let index = Literal::usize_unsuffixed(self.messages.len());
+                self.messages.push(message.value());
+                placeholder.tokens = quote! { #index };
+            }
+            Err(error) => {
+                self.error.get_or_insert(error);
+            }
+        }
+    }
+}
+
+impl TodoMessages {
+    /// Replaces the indexed placeholders in the formatted code with the
+    /// `// TODO` comments of their messages, spread over several comment
+    /// lines when the messages contain line breaks.
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - The formatted code.
+    fn splice(&self, code: &str) -> String {
+        if self.messages.is_empty() {
+            return code.to_owned();
+        }
+        let mut formatted = String::with_capacity(code.len());
+        for line in code.lines() {
+            let trimmed = line.trim_start();
+            let message = trimmed
+                .strip_prefix(TODO_MARKER)
+                .and_then(|placeholder| placeholder.strip_prefix("!("))
+                .and_then(|placeholder| placeholder.strip_suffix(");"))
+                .and_then(|index| index.parse::<usize>().ok())
+                .and_then(|index| self.messages.get(index));
+            let Some(message) = message else {
+                formatted.push_str(line);
+                formatted.push('\n');
+                continue;
+            };
+            let indentation = &line[..line.len() - trimmed.len()];
+            for (number, message_line) in message.split('\n').enumerate() {
+                let prefix = if number == 0 { "// TODO: " } else { "//       " };
+                let comment = format!("{indentation}{prefix}{message_line}");
+                formatted.push_str(comment.trim_end());
+                formatted.push('\n');
+            }
+        }
+        formatted
+    }
+}
+
+/// Returns a placeholder, valid in item or statement position, which is
+/// replaced by a `// TODO` comment with the provided message when the code is
+/// formatted with [`format_rust_code`].
+///
+/// # Arguments
+///
+/// * `message` - The message of the comment.
+pub(crate) fn todo_comment(message: &str) -> TokenStream {
+    let marker = Ident::new(TODO_MARKER, Span::call_site());
+    quote! { #marker!(#message); }
+}
+
+/// Returns whether the provided code is a placeholder created by
+/// [`todo_comment`].
+///
+/// # Arguments
+///
+/// * `code` - The token stream to check.
+pub(crate) fn is_todo_comment(code: &TokenStream) -> bool {
+    code.clone()
+        .into_iter()
+        .next()
+        .is_some_and(|token| matches!(token, TokenTree::Ident(ident) if ident == TODO_MARKER))
+}
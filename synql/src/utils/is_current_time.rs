@@ -0,0 +1,43 @@
+//! Submodule providing a function to recognize the SQL expressions evaluating
+//! to the current date or time.
+
+/// SQL functions evaluating to the current date or time, sorted.
+const CURRENT_TIME_FUNCTIONS: [&str; 9] = [
+    "clock_timestamp",
+    "current_date",
+    "current_time",
+    "current_timestamp",
+    "localtime",
+    "localtimestamp",
+    "now",
+    "statement_timestamp",
+    "transaction_timestamp",
+];
+
+/// Returns whether the provided SQL expression evaluates to the current date
+/// or time, such as `CURRENT_TIMESTAMP`, `now()` or `CURRENT_TIME(3)`.
+///
+/// # Arguments
+///
+/// * `expression` - The SQL expression, possibly followed by a cast.
+///
+/// # Example
+///
+/// ```rust
+/// use synql::utils::is_current_time;
+///
+/// assert!(is_current_time("CURRENT_TIMESTAMP"));
+/// assert!(is_current_time("now()"));
+/// assert!(is_current_time("CURRENT_DATE::date"));
+/// assert!(!is_current_time("'2024-01-01'::date"));
+/// ```
+#[must_use]
+pub fn is_current_time(expression: &str) -> bool {
+    debug_assert!(
+        CURRENT_TIME_FUNCTIONS.windows(2).all(|w| w[0] < w[1]),
+        "CURRENT_TIME_FUNCTIONS must be sorted"
+    );
+    let expression = expression.split("::").next().unwrap_or(expression);
+    let name = expression.split('(').next().unwrap_or(expression).trim().to_lowercase();
+    CURRENT_TIME_FUNCTIONS.binary_search(&name.as_str()).is_ok()
+}
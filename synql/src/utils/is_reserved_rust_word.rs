@@ -1,12 +1,12 @@
 //! Utility functions and constants for code generation and string manipulation.
 
 /// Reserved Rust words that cannot be used as identifiers.
-pub const RESERVED_RUST_WORDS: [&str; 49] = [
+pub const RESERVED_RUST_WORDS: [&str; 51] = [
     "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate",
-    "do", "else", "enum", "extern", "false", "final", "fn", "for", "if", "impl", "in", "let",
-    "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return",
-    "self", "static", "struct", "super", "trait", "true", "try", "type", "typeof", "unsafe",
-    "unsized", "use", "virtual", "where", "while", "yield",
+    "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl",
+    "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
+    "return", "self", "static", "struct", "super", "trait", "true", "try", "type", "typeof",
+    "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
 ];
 
 /// Returns whether the provided name is a reserved Rust word.
@@ -20,8 +20,9 @@ pub fn is_reserved_rust_word(name: &str) -> bool {
     RESERVED_RUST_WORDS.binary_search(&name).is_ok()
 }
 
-/// Reserved Diesel keywords that cannot be used as identifiers.
-pub const RESERVED_DIESEL_KEYWORDS: [&str; 1] = ["columns"];
+/// Reserved Diesel keywords that cannot be used as identifiers, as they
+/// clash with the items generated by the `table!` macro.
+pub const RESERVED_DIESEL_KEYWORDS: [&str; 4] = ["all_columns", "columns", "star", "table"];
 
 #[inline]
 #[must_use]
@@ -0,0 +1,82 @@
+//! Utility functions sanitizing SQL names into valid Rust identifiers.
+
+use deunicode::deunicode;
+use proc_macro2::Ident;
+
+use crate::utils::is_reserved_rust_word;
+
+/// Rust words which cannot be used as identifiers, not even as raw ones.
+pub const NON_RAW_RUST_WORDS: [&str; 4] = ["Self", "crate", "self", "super"];
+
+/// Returns the provided name sanitized into a valid Rust identifier.
+///
+/// * Non-ASCII characters are transliterated to ASCII.
+/// * Characters which cannot appear in identifiers, such as spaces or hyphens,
+///   are replaced with underscores.
+/// * Names starting with a digit are prefixed with an underscore.
+/// * Words which cannot be raw identifiers, such as `self` or `crate`, are
+///   suffixed with an underscore.
+///
+/// Reserved words which can be raw identifiers, such as `type`, are left
+/// untouched, as they are escaped by [`sanitized_ident`].
+///
+/// # Arguments
+///
+/// * `name` - The name to sanitize.
+///
+/// # Example
+///
+/// ```rust
+/// use synql::utils::sanitize_identifier;
+///
+/// assert_eq!(sanitize_identifier("größe"), "grosse");
+/// assert_eq!(sanitize_identifier("unit price"), "unit_price");
+/// assert_eq!(sanitize_identifier("2fa_enabled"), "_2fa_enabled");
+/// assert_eq!(sanitize_identifier("self"), "self_");
+/// assert_eq!(sanitize_identifier("type"), "type");
+/// ```
+#[must_use]
+pub fn sanitize_identifier(name: &str) -> String {
+    let mut sanitized: String = deunicode(name)
+        .trim()
+        .chars()
+        .map(|character| if character.is_ascii_alphanumeric() { character } else { '_' })
+        .collect();
+    if sanitized.is_empty() || sanitized == "_" {
+        return "unnamed".to_owned();
+    }
+    if sanitized.starts_with(|character: char| character.is_ascii_digit()) {
+        sanitized.insert(0, '_');
+    }
+    if NON_RAW_RUST_WORDS.contains(&sanitized.as_str()) {
+        sanitized.push('_');
+    }
+    sanitized
+}
+
+/// Returns the ident of the provided name, sanitized with
+/// [`sanitize_identifier`] and escaped as a raw identifier if it is a
+/// reserved Rust word.
+///
+/// # Arguments
+///
+/// * `name` - The name of the ident.
+///
+/// # Example
+///
+/// ```rust
+/// use synql::utils::sanitized_ident;
+///
+/// assert_eq!(sanitized_ident("type").to_string(), "r#type");
+/// assert_eq!(sanitized_ident("crate").to_string(), "crate_");
+/// assert_eq!(sanitized_ident("1st place").to_string(), "_1st_place");
+/// ```
+#[must_use]
+pub fn sanitized_ident(name: &str) -> Ident {
+    let sanitized = sanitize_identifier(name);
+    if is_reserved_rust_word(&sanitized) {
+        Ident::new_raw(&sanitized, proc_macro2::Span::call_site())
+    } else {
+        Ident::new(&sanitized, proc_macro2::Span::call_site())
+    }
+}
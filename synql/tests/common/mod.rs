@@ -0,0 +1,417 @@
+//! Helpers shared by the integration tests, which inspect the generated
+//! sources through their syntax tree rather than through their formatting.
+
+#![allow(dead_code)]
+
+use std::{path::Path, process::Command};
+
+use sql_traits::prelude::ParserDB;
+use sqlparser::dialect::GenericDialect;
+use synql::prelude::*;
+
+/// Generates in memory the workspace of the provided schema, whose
+/// user-defined types are parsed from the schema itself, after configuring
+/// the builder with the provided closure.
+///
+/// # Arguments
+///
+/// * `schema` - The SQL schema of the database.
+/// * `name` - The name of the workspace.
+/// * `configure` - The closure configuring the builder.
+///
+/// # Errors
+///
+/// * Returns the error of the generation, or of the parsing of the user-defined
+///   types of the schema.
+///
+/// # Panics
+///
+/// * Panics if the schema cannot be parsed.
+pub fn generate_in_memory<F>(
+    schema: &str,
+    name: &str,
+    configure: F,
+) -> Result<GeneratedWorkspace, synql::Error>
+where
+    F: for<'db> FnOnce(SynQLBuilder<'db, ParserDB>) -> SynQLBuilder<'db, ParserDB>,
+{
+    let db = ParserDB::parse::<GenericDialect>(schema)
+        .unwrap_or_else(|error| panic!("Invalid schema: {error}\n{schema}"));
+    let builder = SynQL::new(&db, Path::new("in-memory"))
+        .name(name)
+        .user_defined_types(UserDefinedTypes::parse::<GenericDialect>(schema)?);
+    let synql: SynQL<ParserDB> = configure(builder).into();
+    synql.generate_in_memory()
+}
+
+/// Generates on disk the workspace of the provided schema, as
+/// [`generate_in_memory`] does, and checks that it compiles.
+///
+/// # Arguments
+///
+/// * `schema` - The SQL schema of the database.
+/// * `name` - The name of the workspace.
+/// * `configure` - The closure configuring the builder.
+///
+/// # Errors
+///
+/// * Returns the error of the generation, or of the parsing of the user-defined
+///   types of the schema.
+///
+/// # Panics
+///
+/// * Panics if the schema cannot be parsed or the workspace does not compile.
+pub fn check_workspace<F>(schema: &str, name: &str, configure: F) -> Result<(), synql::Error>
+where
+    F: for<'db> FnOnce(SynQLBuilder<'db, ParserDB>) -> SynQLBuilder<'db, ParserDB>,
+{
+    let db = ParserDB::parse::<GenericDialect>(schema)
+        .unwrap_or_else(|error| panic!("Invalid schema: {error}\n{schema}"));
+    let temp_dir = tempfile::tempdir().expect("Unable to create a temporary directory");
+    let workspace_path = temp_dir.path().join(name);
+    let builder = SynQL::new(&db, &workspace_path)
+        .name(name)
+        .user_defined_types(UserDefinedTypes::parse::<GenericDialect>(schema)?)
+        .generate_workspace_toml();
+    let synql: SynQL<ParserDB> = configure(builder).into();
+    synql.generate()?;
+    cargo_check(&workspace_path);
+    Ok(())
+}
+
+/// Returns the items defined in the provided source.
+///
+/// # Panics
+///
+/// * Panics if the source cannot be parsed.
+fn items(source: &str) -> Vec<syn::Item> {
+    syn::parse_file(source)
+        .unwrap_or_else(|error| panic!("Invalid generated source: {error}\n{source}"))
+        .items
+}
+
+/// Returns the struct with the provided name defined in the provided source.
+///
+/// # Arguments
+///
+/// * `source` - The generated Rust source.
+/// * `struct_name` - The name of the struct.
+///
+/// # Panics
+///
+/// * Panics if the source cannot be parsed or does not define the struct.
+pub fn item_struct(source: &str, struct_name: &str) -> syn::ItemStruct {
+    items(source)
+        .into_iter()
+        .find_map(|item| {
+            match item {
+                syn::Item::Struct(item_struct) if item_struct.ident == struct_name => {
+                    Some(item_struct)
+                }
+                _ => None,
+            }
+        })
+        .unwrap_or_else(|| panic!("The struct `{struct_name}` is not defined:\n{source}"))
+}
+
+/// Returns the enum with the provided name defined in the provided source.
+///
+/// # Arguments
+///
+/// * `source` - The generated Rust source.
+/// * `enum_name` - The name of the enum.
+///
+/// # Panics
+///
+/// * Panics if the source cannot be parsed or does not define the enum.
+pub fn item_enum(source: &str, enum_name: &str) -> syn::ItemEnum {
+    items(source)
+        .into_iter()
+        .find_map(|item| {
+            match item {
+                syn::Item::Enum(item_enum) if item_enum.ident == enum_name => Some(item_enum),
+                _ => None,
+            }
+        })
+        .unwrap_or_else(|| panic!("The enum `{enum_name}` is not defined:\n{source}"))
+}
+
+/// Returns the implementation of the provided trait for the provided type
+/// defined in the provided source.
+///
+/// # Arguments
+///
+/// * `source` - The generated Rust source.
+/// * `trait_path` - The path of the trait, as written in the source.
+/// * `self_ty` - The implementing type, as written in the source.
+///
+/// # Panics
+///
+/// * Panics if the source cannot be parsed or does not define the
+///   implementation.
+pub fn item_impl(source: &str, trait_path: &syn::Path, self_ty: &syn::Type) -> syn::ItemImpl {
+    items(source)
+        .into_iter()
+        .find_map(|item| {
+            match item {
+                syn::Item::Impl(item_impl)
+                    if item_impl.self_ty.as_ref() == self_ty
+                        && item_impl
+                            .trait_
+                            .as_ref()
+                            .is_some_and(|(_, path, _)| path == trait_path) =>
+                {
+                    Some(item_impl)
+                }
+                _ => None,
+            }
+        })
+        .unwrap_or_else(|| {
+            panic!(
+                "The implementation of `{}` for `{}` is not defined:\n{source}",
+                quote::ToTokens::to_token_stream(trait_path),
+                quote::ToTokens::to_token_stream(self_ty)
+            )
+        })
+}
+
+/// Returns the provided field of the provided struct defined in the provided
+/// source.
+///
+/// # Arguments
+///
+/// * `source` - The generated Rust source.
+/// * `struct_name` - The name of the struct.
+/// * `field_name` - The name of the field.
+///
+/// # Panics
+///
+/// * Panics if the source cannot be parsed or does not define the field.
+pub fn field(source: &str, struct_name: &str, field_name: &str) -> syn::Field {
+    item_struct(source, struct_name)
+        .fields
+        .into_iter()
+        .find(|field| field.ident.as_ref().is_some_and(|ident| ident == field_name))
+        .unwrap_or_else(|| {
+            panic!("The field `{struct_name}::{field_name}` is not defined:\n{source}")
+        })
+}
+
+/// Returns the type of the provided field of the provided struct defined in
+/// the provided source.
+///
+/// # Arguments
+///
+/// * `source` - The generated Rust source.
+/// * `struct_name` - The name of the struct.
+/// * `field_name` - The name of the field.
+///
+/// # Panics
+///
+/// * Panics if the source cannot be parsed or does not define the field.
+pub fn field_type(source: &str, struct_name: &str, field_name: &str) -> syn::Type {
+    field(source, struct_name, field_name).ty
+}
+
+/// Returns the default value of the provided field of the provided struct
+/// defined in the provided source, as declared by its `table_model`
+/// attribute, if any.
+///
+/// # Arguments
+///
+/// * `source` - The generated Rust source.
+/// * `struct_name` - The name of the struct.
+/// * `field_name` - The name of the field.
+///
+/// # Panics
+///
+/// * Panics if the source cannot be parsed or does not define the field.
+pub fn field_default(source: &str, struct_name: &str, field_name: &str) -> Option<syn::Expr> {
+    field(source, struct_name, field_name)
+        .attrs
+        .iter()
+        .filter(|attribute| attribute.path().is_ident("table_model"))
+        .filter_map(|attribute| attribute.parse_args::<syn::MetaNameValue>().ok())
+        .find(|argument| argument.path.is_ident("default"))
+        .map(|argument| argument.value)
+}
+
+/// Returns the Diesel SQL type of the provided field of the provided struct
+/// defined in the provided source, as declared by its `diesel` attribute, if
+/// any.
+///
+/// # Arguments
+///
+/// * `source` - The generated Rust source.
+/// * `struct_name` - The name of the struct.
+/// * `field_name` - The name of the field.
+///
+/// # Panics
+///
+/// * Panics if the source cannot be parsed or does not define the field.
+pub fn field_sql_type(source: &str, struct_name: &str, field_name: &str) -> Option<syn::Type> {
+    field(source, struct_name, field_name)
+        .attrs
+        .iter()
+        .filter(|attribute| attribute.path().is_ident("diesel"))
+        .find_map(|attribute| {
+            attribute
+                .parse_args_with(|input: syn::parse::ParseStream<'_>| {
+                    let path: syn::Path = input.parse()?;
+                    input.parse::<syn::Token![=]>()?;
+                    let sql_type: syn::Type = input.parse()?;
+                    Ok(path.is_ident("sql_type").then_some(sql_type))
+                })
+                .ok()
+                .flatten()
+        })
+}
+
+/// Collects the conditions of the `if` expressions, the function calls and
+/// the arms of the `match` expressions it visits.
+#[derive(Default)]
+struct Expressions {
+    conditions: Vec<syn::Expr>,
+    calls: Vec<syn::ExprCall>,
+    arms: Vec<syn::Arm>,
+}
+
+impl syn::visit_mut::VisitMut for Expressions {
+    fn visit_expr_if_mut(&mut self, expr_if: &mut syn::ExprIf) {
+        self.conditions.push(expr_if.cond.as_ref().clone());
+        syn::visit_mut::visit_expr_if_mut(self, expr_if);
+    }
+
+    fn visit_expr_call_mut(&mut self, expr_call: &mut syn::ExprCall) {
+        self.calls.push(expr_call.clone());
+        syn::visit_mut::visit_expr_call_mut(self, expr_call);
+    }
+
+    fn visit_arm_mut(&mut self, arm: &mut syn::Arm) {
+        self.arms.push(arm.clone());
+        syn::visit_mut::visit_arm_mut(self, arm);
+    }
+}
+
+/// Returns the expressions collected over the provided source.
+///
+/// # Panics
+///
+/// * Panics if the source cannot be parsed.
+fn expressions(source: &str) -> Expressions {
+    let mut file = syn::parse_file(source)
+        .unwrap_or_else(|error| panic!("Invalid generated source: {error}\n{source}"));
+    let mut expressions = Expressions::default();
+    syn::visit_mut::visit_file_mut(&mut expressions, &mut file);
+    expressions
+}
+
+/// Returns the conditions of the `if` expressions of the provided source,
+/// such as `value.is_empty()`.
+///
+/// # Arguments
+///
+/// * `source` - The generated Rust source.
+///
+/// # Panics
+///
+/// * Panics if the source cannot be parsed.
+pub fn if_conditions(source: &str) -> Vec<syn::Expr> {
+    expressions(source).conditions
+}
+
+/// Returns the `let` conditions of the `if let` expressions of the provided
+/// source, such as `let Some(value) = value.as_ref()`.
+///
+/// # Arguments
+///
+/// * `source` - The generated Rust source.
+///
+/// # Panics
+///
+/// * Panics if the source cannot be parsed.
+pub fn if_let_conditions(source: &str) -> Vec<syn::ExprLet> {
+    if_conditions(source)
+        .into_iter()
+        .filter_map(|condition| {
+            match condition {
+                syn::Expr::Let(condition) => Some(condition),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+/// Returns the paths of the functions called in the provided source, such as
+/// `::validation_errors::ValidationError::empty`.
+///
+/// # Arguments
+///
+/// * `source` - The generated Rust source.
+///
+/// # Panics
+///
+/// * Panics if the source cannot be parsed.
+pub fn called_functions(source: &str) -> Vec<syn::Path> {
+    calls(source)
+        .into_iter()
+        .filter_map(|call| {
+            match *call.func {
+                syn::Expr::Path(function) => Some(function.path),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+/// Returns the function calls of the provided source, such as
+/// `<::bigdecimal::BigDecimal as ::core::str::FromStr>::from_str("0.5")`.
+///
+/// # Arguments
+///
+/// * `source` - The generated Rust source.
+///
+/// # Panics
+///
+/// * Panics if the source cannot be parsed.
+pub fn calls(source: &str) -> Vec<syn::ExprCall> {
+    expressions(source).calls
+}
+
+/// Returns the arms of the `match` expressions of the provided source, such
+/// as `Self::Happy => "happy",`.
+///
+/// # Arguments
+///
+/// * `source` - The generated Rust source.
+///
+/// # Panics
+///
+/// * Panics if the source cannot be parsed.
+pub fn match_arms(source: &str) -> Vec<syn::Arm> {
+    expressions(source).arms
+}
+
+/// Runs `cargo check` over the workspace generated at the provided path.
+///
+/// # Arguments
+///
+/// * `workspace_path` - The path of the generated workspace.
+///
+/// # Panics
+///
+/// * Panics with the output of `cargo check` if it fails.
+pub fn cargo_check(workspace_path: &Path) {
+    let output = Command::new("cargo")
+        .arg("check")
+        .current_dir(workspace_path)
+        .output()
+        .expect("Unable to run cargo check");
+    assert!(
+        output.status.success(),
+        "cargo check failed for the workspace generated at `{}`:\n{}\n{}",
+        workspace_path.display(),
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
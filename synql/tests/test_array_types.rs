@@ -0,0 +1,107 @@
+//! Test module to verify that the arrays of any type are mapped through their
+//! element type.
+
+mod common;
+
+use syn::parse_quote;
+use synql::prelude::*;
+
+const SCHEMA: &str = r"
+CREATE TYPE mood AS ENUM ('happy', 'sad');
+CREATE TABLE samples (
+    id SERIAL PRIMARY KEY,
+    tags TEXT[] NOT NULL,
+    identifiers UUID[] NOT NULL,
+    measured_at TIMESTAMPTZ[],
+    moods mood[] NOT NULL,
+    grid INTEGER[3] NOT NULL
+);
+";
+
+#[test]
+fn test_array_types() -> Result<(), Box<dyn std::error::Error>> {
+    let generated = common::generate_in_memory(SCHEMA, "arrays", |builder| builder)?;
+
+    let samples_lib = generated.get("arrays-samples/src/lib.rs").unwrap();
+    let expected_types: [(&str, syn::Type); 5] = [
+        ("tags", parse_quote!(Vec<String>)),
+        ("identifiers", parse_quote!(Vec<::rosetta_uuid::Uuid>)),
+        ("measured_at", parse_quote!(Option<Vec<::rosetta_utc::TimestampUTC>>)),
+        ("moods", parse_quote!(Vec<::arrays_types::Mood>)),
+        ("grid", parse_quote!(Vec<i32>)),
+    ];
+    for (field_name, expected_type) in expected_types {
+        assert_eq!(common::field_type(samples_lib, "Sample", field_name), expected_type);
+    }
+    let samples_toml = generated.get("arrays-samples/Cargo.toml").unwrap();
+    assert!(samples_toml.contains("rosetta-uuid"), "{samples_toml}");
+
+    Ok(())
+}
+
+#[test]
+fn test_nullable_array_elements() -> Result<(), Box<dyn std::error::Error>> {
+    let generated =
+        common::generate_in_memory(SCHEMA, "arrays", |builder| builder.nullable_array_elements())?;
+
+    let samples_lib = generated.get("arrays-samples/src/lib.rs").unwrap();
+    assert_eq!(
+        common::field_type(samples_lib, "Sample", "tags"),
+        parse_quote!(Vec<Option<String>>)
+    );
+    assert_eq!(common::field_type(samples_lib, "Sample", "grid"), parse_quote!(Vec<Option<i32>>));
+    assert_eq!(
+        common::field_sql_type(samples_lib, "Sample", "identifiers"),
+        Some(parse_quote!(
+            diesel::sql_types::Array<
+                diesel::sql_types::Nullable<::rosetta_uuid::diesel_impls::Uuid>,
+            >
+        ))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_multi_dimensional_arrays() -> Result<(), Box<dyn std::error::Error>> {
+    let schema = r"
+CREATE TABLE samples (id SERIAL PRIMARY KEY, grid INTEGER[][] NOT NULL);
+CREATE TABLE labels (id SERIAL PRIMARY KEY, tags TEXT[] NOT NULL);
+";
+    assert!(matches!(
+        common::generate_in_memory(schema, "arrays", |builder| builder),
+        Err(synql::Error::MultiDimensionalArray { table_name, column_name, .. })
+            if table_name == "samples" && column_name == "grid"
+    ));
+
+    let generated = common::generate_in_memory(schema, "arrays", |builder| builder.lenient())?;
+    assert!(!generated.contains("arrays-samples/src/lib.rs"));
+    assert!(generated.contains("arrays-labels/src/lib.rs"));
+    assert!(matches!(
+        generated.warnings(),
+        [GenerationWarning::MultiDimensionalArray { table_name, column_name, .. }]
+            if table_name == "samples" && column_name == "grid"
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn test_internal_array_types() -> Result<(), Box<dyn std::error::Error>> {
+    let schema = r"
+CREATE TABLE catalog (
+    id SERIAL PRIMARY KEY,
+    types _regtype NOT NULL,
+    privileges _aclitem NOT NULL,
+    positions _cardinal_number NOT NULL
+);
+";
+    let generated = common::generate_in_memory(schema, "arrays", |builder| builder)?;
+
+    let catalog_lib = generated.get("arrays-catalog/src/lib.rs").unwrap();
+    assert_eq!(common::field_type(catalog_lib, "Catalog", "types"), parse_quote!(Vec<u32>));
+    assert_eq!(common::field_type(catalog_lib, "Catalog", "privileges"), parse_quote!(Vec<String>));
+    assert_eq!(common::field_type(catalog_lib, "Catalog", "positions"), parse_quote!(Vec<i32>));
+
+    Ok(())
+}
@@ -22,29 +22,20 @@ fn test_callback_generation() -> Result<(), Box<dyn std::error::Error>> {
         .toml_callback(|_table, _db| Ok(Some(TomlDependency::new("extra-dep").version("1.0.0")?)))
         .into();
 
-    synql.generate()?;
-
-    let mut lib_rs_path = workspace_path.join("unknown_catalog-users").join("src").join("lib.rs");
-
-    if !lib_rs_path.exists() {
-        // Fallback: look for directory ending with users
-        for entry in std::fs::read_dir(&workspace_path)? {
-            let path = entry?.path();
-            if path.is_dir()
-                && path.file_name().unwrap_or_default().to_string_lossy().ends_with("users")
-            {
-                lib_rs_path = path.join("src").join("lib.rs");
-                break;
-            }
-        }
-    }
-
-    let content = std::fs::read_to_string(lib_rs_path.clone())?;
+    let generated = synql.generate_in_memory()?;
+
+    let content = generated
+        .get("unknown_catalog-users/src/lib.rs")
+        .expect("The users crate should have a lib.rs file");
     assert!(content.contains("pub fn hello_world"));
 
-    let cargo_toml_path = lib_rs_path.parent().unwrap().parent().unwrap().join("Cargo.toml");
-    let content = std::fs::read_to_string(cargo_toml_path)?;
+    let content = generated
+        .get("unknown_catalog-users/Cargo.toml")
+        .expect("The users crate should have a Cargo.toml file");
     assert!(content.contains("extra-dep = { version = \"1.0.0\" }"));
 
+    // Nothing should have been written to disk.
+    assert!(!workspace_path.exists());
+
     Ok(())
 }
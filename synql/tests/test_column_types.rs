@@ -0,0 +1,96 @@
+//! Test module to verify that the Rust types of specific columns can be
+//! overridden with types provided by external crates.
+
+use sql_traits::prelude::ParserDB;
+use sqlparser::dialect::GenericDialect;
+use synql::prelude::*;
+
+/// Returns an external crate providing the provided type, mapped to the
+/// provided diesel type.
+fn newtype_crate(
+    name: &str,
+    diesel_type: syn::Type,
+    rust_type: syn::Type,
+) -> Result<ExternalCrate, Box<dyn std::error::Error>> {
+    Ok(ExternalCrate::new(name)
+        .unwrap()
+        .version("0.1.0")?
+        .types([ExternalType::new(diesel_type, rust_type)
+            .supports_debug()
+            .supports_copy()
+            .supports_ord()
+            .supports_hash()
+            .into()])
+        .unwrap()
+        .into())
+}
+
+#[test]
+fn test_column_types() -> Result<(), Box<dyn std::error::Error>> {
+    let db = ParserDB::parse::<GenericDialect>(
+        r"
+        CREATE TABLE users (id SERIAL PRIMARY KEY, email TEXT NOT NULL, name TEXT NOT NULL);
+        CREATE TABLE products (id SERIAL PRIMARY KEY, price_cents INTEGER NOT NULL DEFAULT 0);
+",
+    )?;
+    let temp_dir = tempfile::tempdir()?;
+
+    let synql: SynQL<ParserDB> = SynQL::new(&db, temp_dir.path())
+        .name("types")
+        .external_crate(newtype_crate(
+            "email",
+            syn::parse_quote!(::diesel::sql_types::Text),
+            syn::parse_quote!(::email::Email),
+        )?)
+        .external_crate(newtype_crate(
+            "money",
+            syn::parse_quote!(::diesel::sql_types::Integer),
+            syn::parse_quote!(::money::Money),
+        )?)
+        .column_type("users", "email", "::email::Email")
+        .column_type("products", "price_cents", "::money::Money")
+        .into();
+    let generated = synql.generate_in_memory()?;
+
+    let users_lib = generated.get("types-users/src/lib.rs").unwrap();
+    assert!(users_lib.contains("email: ::email::Email"), "{users_lib}");
+    assert!(users_lib.contains("name: String"), "{users_lib}");
+    let users_toml = generated.get("types-users/Cargo.toml").unwrap();
+    assert!(users_toml.contains("email.workspace = true"), "{users_toml}");
+    assert!(!users_toml.contains("money.workspace = true"), "{users_toml}");
+
+    let products_lib = generated.get("types-products/src/lib.rs").unwrap().replace(' ', "");
+    assert!(products_lib.contains("price_cents:::money::Money"), "{products_lib}");
+    assert!(products_lib.contains("default=<::money::Money>::from(0i32)"), "{products_lib}");
+    let products_toml = generated.get("types-products/Cargo.toml").unwrap();
+    assert!(products_toml.contains("money.workspace = true"), "{products_toml}");
+
+    Ok(())
+}
+
+#[test]
+fn test_invalid_column_types() -> Result<(), Box<dyn std::error::Error>> {
+    let db = ParserDB::parse::<GenericDialect>(
+        "CREATE TABLE users (id SERIAL PRIMARY KEY, email TEXT NOT NULL);",
+    )?;
+    for (column_name, rust_type, expected_reason) in [
+        ("email", "::email::Email", "not provided by any of the external crates"),
+        ("mail", "String", "does not exist"),
+        ("email", "not a type", "not a valid Rust type"),
+    ] {
+        let temp_dir = tempfile::tempdir()?;
+        let synql: SynQL<ParserDB> = SynQL::new(&db, temp_dir.path())
+            .name("types")
+            .column_type("users", column_name, rust_type)
+            .into();
+        match synql.generate_in_memory() {
+            Err(synql::Error::InvalidColumnType { reason, .. }) => {
+                assert!(reason.contains(expected_reason), "Unexpected reason: {reason}");
+            }
+            Err(other) => panic!("Unexpected error: {other}"),
+            Ok(_) => panic!("The type `{rust_type}` of column `{column_name}` should be rejected"),
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,100 @@
+//! Test module to verify that the `@synql(...)` directives declared in the
+//! comments of tables and columns customize the generation.
+
+use std::path::Path;
+
+use sql_traits::prelude::ParserDB;
+use sqlparser::dialect::GenericDialect;
+use synql::prelude::*;
+
+#[test]
+fn test_directives() -> Result<(), Box<dyn std::error::Error>> {
+    let db = ParserDB::parse::<GenericDialect>(
+        r#"
+        CREATE TABLE data (
+            id SERIAL PRIMARY KEY,
+            value TEXT NOT NULL,
+            legacy TEXT,
+            password TEXT NOT NULL
+        );
+        COMMENT ON TABLE data IS 'Measured data points. @synql(rename = "Datum", derive(Hash))';
+        COMMENT ON COLUMN data.value IS 'The measured value. @synql(rename = "reading")';
+        COMMENT ON COLUMN data.legacy IS '@synql(skip)';
+        COMMENT ON COLUMN data.password IS 'Hashed password. @synql(sensitive)';
+        CREATE TABLE scratch (id SERIAL PRIMARY KEY);
+        COMMENT ON TABLE scratch IS 'Temporary data. @synql(skip)';
+"#,
+    )?;
+    let temp_dir = tempfile::tempdir()?;
+
+    let synql: SynQL<ParserDB> = SynQL::new(&db, temp_dir.path()).name("directives").into();
+    let generated = synql.generate_in_memory()?;
+
+    assert!(!generated.contains("directives-scratch/src/lib.rs"));
+    let lib = generated.get("directives-data/src/lib.rs").unwrap();
+    assert!(lib.contains("pub struct Datum"), "{lib}");
+    assert!(lib.contains("Measured data points."), "{lib}");
+    assert!(lib.contains("The measured value."), "{lib}");
+    assert!(!lib.contains("@synql"), "{lib}");
+    assert!(lib.contains("reading: String"), "{lib}");
+    assert!(lib.contains("#[table_model(sql_name = \"value\")]"), "{lib}");
+    assert!(!lib.contains("legacy"), "{lib}");
+    assert!(lib.contains("#[serde(skip_serializing, default)]"), "{lib}");
+    assert!(lib.contains("\"[REDACTED]\""), "{lib}");
+
+    Ok(())
+}
+
+#[test]
+fn test_invalid_directives() -> Result<(), Box<dyn std::error::Error>> {
+    for (comment, expected_reason) in [
+        ("COMMENT ON TABLE data IS '@synql(colour)'", "unknown directive `colour`"),
+        ("COMMENT ON TABLE data IS '@synql(sensitive)'", "only applies to columns"),
+        ("COMMENT ON COLUMN data.value IS '@synql(skip)'", "cannot be skipped"),
+        (
+            r#"COMMENT ON COLUMN data.value IS '@synql(sensitive, type = "std::time::SystemTime")'"#,
+            "must be nullable or of a type implementing `Default`",
+        ),
+    ] {
+        let db = ParserDB::parse::<GenericDialect>(&format!(
+            "CREATE TABLE data (id SERIAL PRIMARY KEY, value TEXT NOT NULL); {comment};"
+        ))?;
+        let temp_dir = tempfile::tempdir()?;
+
+        let synql: SynQL<ParserDB> = SynQL::new(&db, temp_dir.path()).name("directives").into();
+        match synql.generate_in_memory() {
+            Err(synql::Error::InvalidDirective { reason, .. }) => {
+                assert!(reason.contains(expected_reason), "Unexpected reason: {reason}");
+            }
+            Err(other) => panic!("Unexpected error: {other}"),
+            Ok(_) => panic!("The directive `{comment}` should be rejected"),
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_directive_type_traits() -> Result<(), Box<dyn std::error::Error>> {
+    let db = ParserDB::parse::<GenericDialect>(
+        r#"
+        CREATE TABLE events (id SERIAL PRIMARY KEY, happened_at TIMESTAMP NOT NULL);
+        COMMENT ON COLUMN events.happened_at IS '@synql(type = "std::time::SystemTime")';
+"#,
+    )?;
+
+    let synql: SynQL<ParserDB> = SynQL::new(&db, Path::new("in-memory")).name("directives").into();
+    let generated = synql.generate_in_memory()?;
+
+    // The derives of the struct follow the traits of the type declared with
+    // the directive rather than the ones of the type it replaces.
+    let lib = generated.get("directives-events/src/lib.rs").unwrap();
+    assert!(lib.contains("happened_at: std::time::SystemTime"), "{lib}");
+    for (start, _) in lib.match_indices("#[derive(") {
+        let derives = &lib[start..];
+        let derives = &derives[..derives.find(")]").unwrap()];
+        assert!(!derives.contains("Copy") && !derives.contains("Default"), "{lib}");
+    }
+
+    Ok(())
+}
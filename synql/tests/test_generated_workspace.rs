@@ -0,0 +1,51 @@
+//! Test module to verify that the in-memory generation matches the files
+//! written to disk by `SynQL::generate`.
+
+use sql_traits::prelude::ParserDB;
+use sqlparser::dialect::GenericDialect;
+use synql::prelude::*;
+
+#[test]
+fn test_generated_workspace() -> Result<(), Box<dyn std::error::Error>> {
+    let db = ParserDB::parse::<GenericDialect>(
+        "
+        CREATE TABLE users (id SERIAL PRIMARY KEY, name TEXT NOT NULL);
+        CREATE TABLE comments (id SERIAL PRIMARY KEY, user_id INT REFERENCES users(id));
+",
+    )?;
+    let temp_dir = tempfile::tempdir()?;
+    let workspace_path = temp_dir.path().join("synql_generated_workspace");
+
+    let synql: SynQL<ParserDB> = SynQL::new(&db, &workspace_path)
+        .name("synql-generated")
+        .generate_workspace_toml()
+        .generate_rustfmt()
+        .sink_crate("sink")
+        .into();
+
+    let generated = synql.generate_in_memory()?;
+
+    let paths = generated.paths().map(|path| path.display().to_string()).collect::<Vec<_>>();
+    assert_eq!(
+        paths,
+        [
+            "Cargo.toml",
+            "rustfmt.toml",
+            "sink/Cargo.toml",
+            "sink/src/lib.rs",
+            "synql-generated-comments/Cargo.toml",
+            "synql-generated-comments/src/lib.rs",
+            "synql-generated-users/Cargo.toml",
+            "synql-generated-users/src/lib.rs",
+        ]
+    );
+
+    synql.generate()?;
+
+    for (relative_path, contents) in generated.files() {
+        let on_disk = std::fs::read_to_string(workspace_path.join(relative_path))?;
+        assert_eq!(on_disk, contents, "Mismatch in {}", relative_path.display());
+    }
+
+    Ok(())
+}
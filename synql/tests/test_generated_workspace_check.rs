@@ -0,0 +1,127 @@
+//! Test module to verify that the workspaces generated for the user-defined
+//! Postgres types and with each of the date and time backends pass a
+//! workspace-level `cargo check`, as the generated types, defaults and
+//! comparisons must compile against the crates they are mapped to.
+
+mod common;
+
+use synql::prelude::*;
+
+const ENUM_SCHEMA: &str = r"
+CREATE TYPE mood AS ENUM ('sad', 'ok', 'happy');
+CREATE TABLE people (
+    id SERIAL PRIMARY KEY,
+    current_mood mood NOT NULL DEFAULT 'happy'::mood,
+    previous_mood mood
+);
+";
+
+const DOMAIN_SCHEMA: &str = r"
+CREATE DOMAIN positive_int AS integer CHECK (VALUE > 0);
+CREATE DOMAIN label AS text CHECK (VALUE <> '' AND length(VALUE) <= 32);
+CREATE TABLE items (
+    id SERIAL PRIMARY KEY,
+    quantity positive_int NOT NULL DEFAULT 1,
+    name label NOT NULL DEFAULT 'unnamed',
+    nickname label
+);
+";
+
+const COMPOSITE_SCHEMA: &str = r"
+CREATE TYPE address AS (street text, city varchar(64), zip integer);
+CREATE TABLE customers (
+    id SERIAL PRIMARY KEY,
+    home address NOT NULL,
+    previous address[] NOT NULL,
+    billing address
+);
+";
+
+const NUMERIC_SCHEMA: &str = r"
+CREATE TABLE invoices (
+    id SERIAL PRIMARY KEY,
+    total NUMERIC(10, 2) NOT NULL DEFAULT 1.005 CHECK (total >= 0.5),
+    rate DECIMAL
+);
+";
+
+const RANGE_SCHEMA: &str = r"
+CREATE TABLE bookings (
+    id SERIAL PRIMARY KEY,
+    seats INT4RANGE NOT NULL CHECK (NOT isempty(seats)),
+    first_seat INTEGER NOT NULL,
+    period TSTZRANGE,
+    starts_at TIMESTAMPTZ NOT NULL,
+    closures DATEMULTIRANGE,
+    CHECK (lower(seats) >= 1),
+    CHECK (seats @> first_seat),
+    CHECK (period @> starts_at)
+);
+";
+
+const TEMPORAL_SCHEMA: &str = r"
+CREATE TABLE events (
+    id SERIAL PRIMARY KEY,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    scheduled_at TIMESTAMP NOT NULL DEFAULT now(),
+    day DATE NOT NULL DEFAULT CURRENT_DATE,
+    hour TIME NOT NULL DEFAULT CURRENT_TIME,
+    CHECK (created_at <= NOW())
+);
+";
+
+/// Generates the workspace of the temporal schema with the provided backend,
+/// and checks that it compiles.
+fn check_temporal_backend(
+    temporal_backend: TemporalBackend,
+) -> Result<(), Box<dyn std::error::Error>> {
+    common::check_workspace(TEMPORAL_SCHEMA, "temporal", |builder| {
+        builder.temporal_backend(temporal_backend)
+    })?;
+    Ok(())
+}
+
+#[test]
+fn test_rosetta_utc_backend_check() -> Result<(), Box<dyn std::error::Error>> {
+    check_temporal_backend(TemporalBackend::RosettaUtc)
+}
+
+#[test]
+fn test_chrono_backend_check() -> Result<(), Box<dyn std::error::Error>> {
+    check_temporal_backend(TemporalBackend::Chrono)
+}
+
+#[test]
+fn test_time_backend_check() -> Result<(), Box<dyn std::error::Error>> {
+    check_temporal_backend(TemporalBackend::Time)
+}
+
+#[test]
+fn test_enum_check() -> Result<(), Box<dyn std::error::Error>> {
+    common::check_workspace(ENUM_SCHEMA, "enums", |builder| builder)?;
+    Ok(())
+}
+
+#[test]
+fn test_domain_check() -> Result<(), Box<dyn std::error::Error>> {
+    common::check_workspace(DOMAIN_SCHEMA, "domains", |builder| builder)?;
+    Ok(())
+}
+
+#[test]
+fn test_composite_check() -> Result<(), Box<dyn std::error::Error>> {
+    common::check_workspace(COMPOSITE_SCHEMA, "composites", |builder| builder)?;
+    Ok(())
+}
+
+#[test]
+fn test_numeric_check() -> Result<(), Box<dyn std::error::Error>> {
+    common::check_workspace(NUMERIC_SCHEMA, "numeric", |builder| builder)?;
+    Ok(())
+}
+
+#[test]
+fn test_range_check() -> Result<(), Box<dyn std::error::Error>> {
+    common::check_workspace(RANGE_SCHEMA, "ranges", |builder| builder)?;
+    Ok(())
+}
@@ -0,0 +1,61 @@
+//! Test module to verify that regenerating a workspace refuses to overwrite
+//! generated files which were modified by hand, unless explicitly forced.
+
+use sql_traits::prelude::ParserDB;
+use sqlparser::dialect::GenericDialect;
+use synql::prelude::*;
+
+#[test]
+fn test_hand_edited_files() -> Result<(), Box<dyn std::error::Error>> {
+    let temp_dir = tempfile::tempdir()?;
+    let workspace_path = temp_dir.path().join("synql_hand_edited");
+
+    let db = ParserDB::parse::<GenericDialect>(
+        "
+        CREATE TABLE users (id SERIAL PRIMARY KEY, name TEXT NOT NULL);
+        CREATE TABLE comments (id SERIAL PRIMARY KEY, user_id INT REFERENCES users(id));
+",
+    )?;
+    let synql: SynQL<ParserDB> =
+        SynQL::new(&db, &workspace_path).name("synql-hand-edited").generate_workspace_toml().into();
+    synql.generate()?;
+
+    let users_lib = workspace_path.join("synql-hand-edited-users").join("src").join("lib.rs");
+    let mut patched = std::fs::read_to_string(&users_lib)?;
+    patched.push_str("\n// Hotfix applied by hand.\n");
+    std::fs::write(&users_lib, &patched)?;
+
+    let error = synql.generate().expect_err("Hand-edited files must not be overwritten");
+    match error {
+        synql::Error::HandEditedFiles(files) => {
+            assert_eq!(
+                files,
+                vec![std::path::Path::new("synql-hand-edited-users").join("src").join("lib.rs")]
+            );
+        }
+        other => panic!("Unexpected error: {other}"),
+    }
+    assert_eq!(std::fs::read_to_string(&users_lib)?, patched, "The hotfix must be preserved");
+
+    // Forcing the overwrite discards the manual changes.
+    let synql: SynQL<ParserDB> = SynQL::new(&db, &workspace_path)
+        .name("synql-hand-edited")
+        .generate_workspace_toml()
+        .force_overwrite()
+        .into();
+    let statistics = synql.generate()?.file_statistics();
+    assert_eq!(statistics.written(), 1);
+    assert!(!std::fs::read_to_string(&users_lib)?.contains("Hotfix"));
+
+    // Clearing the existing workspace discards the manual changes as well.
+    std::fs::write(&users_lib, &patched)?;
+    let synql: SynQL<ParserDB> = SynQL::new(&db, &workspace_path)
+        .name("synql-hand-edited")
+        .generate_workspace_toml()
+        .clear_existing()
+        .into();
+    synql.generate()?;
+    assert!(!std::fs::read_to_string(&users_lib)?.contains("Hotfix"));
+
+    Ok(())
+}
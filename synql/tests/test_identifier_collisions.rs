@@ -0,0 +1,129 @@
+//! Test module to verify that the items of the schema which would be
+//! generated with the same identifier are reported, or disambiguated.
+
+use sql_traits::prelude::ParserDB;
+use sqlparser::dialect::GenericDialect;
+use synql::prelude::*;
+
+const SCHEMA: &str = "
+    CREATE TABLE readings (id SERIAL PRIMARY KEY, MyColumn TEXT NOT NULL, my_column TEXT NOT NULL);
+";
+
+#[test]
+fn test_column_collision() -> Result<(), Box<dyn std::error::Error>> {
+    let db = ParserDB::parse::<GenericDialect>(SCHEMA)?;
+    let temp_dir = tempfile::tempdir()?;
+
+    let synql: SynQL<ParserDB> = SynQL::new(&db, temp_dir.path()).name("collisions").into();
+    match synql.generate_in_memory() {
+        Err(synql::Error::IdentifierCollision { identifier, first, second }) => {
+            assert_eq!(identifier, "my_column");
+            assert_eq!(first, "column `MyColumn` of table `readings`");
+            assert_eq!(second, "column `my_column` of table `readings`");
+        }
+        Err(other) => panic!("Unexpected error: {other}"),
+        Ok(_) => panic!("The column collision should be reported"),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_disambiguated_column_collision() -> Result<(), Box<dyn std::error::Error>> {
+    let db = ParserDB::parse::<GenericDialect>(SCHEMA)?;
+    let temp_dir = tempfile::tempdir()?;
+    let workspace_path = temp_dir.path().join("collisions");
+
+    let synql: SynQL<ParserDB> =
+        SynQL::new(&db, &workspace_path).name("collisions").disambiguate_identifiers().into();
+    let report = synql.generate()?;
+
+    let readings_lib = std::fs::read_to_string(
+        workspace_path.join("collisions-readings").join("src").join("lib.rs"),
+    )?;
+    assert!(readings_lib.contains("my_column: String"));
+    assert!(readings_lib.contains("#[table_model(sql_name = \"my_column\")]"));
+    assert!(readings_lib.contains("my_column_2: String"));
+
+    assert_eq!(
+        report.warnings(),
+        [GenerationWarning::DisambiguatedIdentifier {
+            renamed: "column `my_column` of table `readings`".to_string(),
+            other: "column `MyColumn` of table `readings`".to_string(),
+            identifier: "my_column".to_string(),
+            disambiguated: "my_column_2".to_string(),
+        }]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_external_crate_collision() -> Result<(), Box<dyn std::error::Error>> {
+    let db = ParserDB::parse::<GenericDialect>("CREATE TABLE serde (id SERIAL PRIMARY KEY);")?;
+    let temp_dir = tempfile::tempdir()?;
+
+    let synql: SynQL<ParserDB> =
+        SynQL::new(&db, temp_dir.path()).name("collisions").disambiguate_identifiers().into();
+    match synql.generate_in_memory() {
+        Err(synql::Error::IdentifierCollision { identifier, first, second }) => {
+            assert_eq!(identifier, "serde");
+            assert_eq!(first, "external crate `serde`");
+            assert_eq!(second, "diesel module of table `serde`");
+        }
+        Err(other) => panic!("Unexpected error: {other}"),
+        Ok(_) => panic!("The external crate collision should be reported"),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_disambiguated_struct_collision_across_schemas() -> Result<(), Box<dyn std::error::Error>> {
+    // The structs of `auth.users` and `public.auth_user` are both exported as
+    // `AuthUser` by the sink crate, while `public.users` and `auth.auth_user`
+    // are their homonyms in the other schema, which must not be renamed.
+    let db = ParserDB::parse::<GenericDialect>(
+        "
+        CREATE TABLE public.users (id SERIAL PRIMARY KEY);
+        CREATE TABLE auth.users (id SERIAL PRIMARY KEY);
+        CREATE TABLE public.auth_user (id SERIAL PRIMARY KEY);
+        CREATE TABLE auth.auth_user (id SERIAL PRIMARY KEY);
+",
+    )?;
+    let temp_dir = tempfile::tempdir()?;
+
+    let synql: SynQL<ParserDB> = SynQL::new(&db, temp_dir.path())
+        .name("collisions")
+        .sink_crate("collisions-sink")
+        .disambiguate_identifiers()
+        .into();
+    let generated = synql.generate_in_memory()?;
+
+    let warnings = generated.warnings();
+    assert_eq!(warnings.len(), 1, "Unexpected warnings: {warnings:?}");
+    let GenerationWarning::DisambiguatedIdentifier { identifier, disambiguated, .. } = &warnings[0]
+    else {
+        panic!("Unexpected warning: {:?}", warnings[0]);
+    };
+    assert_eq!(identifier, "AuthUser");
+    assert_eq!(disambiguated, "AuthUser2");
+
+    let renamed_libs = [
+        generated.get("collisions-auth_users/src/lib.rs").unwrap(),
+        generated.get("collisions-auth_user/src/lib.rs").unwrap(),
+    ];
+    assert_eq!(
+        renamed_libs
+            .iter()
+            .filter(|lib| lib.contains("pub struct User2") || lib.contains("pub struct AuthUser2"))
+            .count(),
+        1
+    );
+    let public_users_lib = generated.get("collisions-users/src/lib.rs").unwrap();
+    assert!(!public_users_lib.contains("pub struct User2"), "{public_users_lib}");
+    let auth_auth_user_lib = generated.get("collisions-auth_auth_user/src/lib.rs").unwrap();
+    assert!(!auth_auth_user_lib.contains("pub struct AuthUser2"), "{auth_auth_user_lib}");
+
+    Ok(())
+}
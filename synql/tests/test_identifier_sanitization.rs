@@ -0,0 +1,50 @@
+//! Test module to verify that the names of tables and columns which are not
+//! valid Rust identifiers are sanitized, and mapped to their SQL names.
+
+use sql_traits::prelude::ParserDB;
+use sqlparser::dialect::GenericDialect;
+use synql::prelude::*;
+
+#[test]
+fn test_identifier_sanitization() -> Result<(), Box<dyn std::error::Error>> {
+    let db = ParserDB::parse::<GenericDialect>(
+        r#"
+        CREATE TABLE "order items" (
+            id SERIAL PRIMARY KEY,
+            "self" INT NOT NULL,
+            "table" TEXT NOT NULL,
+            "Größe" INT NOT NULL,
+            "2fa" BOOLEAN NOT NULL,
+            type TEXT NOT NULL,
+            "gen" INT NOT NULL,
+            "dyn" INT NOT NULL
+        );
+"#,
+    )?;
+    let temp_dir = tempfile::tempdir()?;
+
+    let synql: SynQL<ParserDB> = SynQL::new(&db, temp_dir.path()).name("sanitized").into();
+    let generated = synql.generate_in_memory()?;
+
+    let lib = generated.get("sanitized-order_items/src/lib.rs").unwrap();
+    assert!(lib.contains("#[diesel(table_name = order_items)]"), "{lib}");
+    assert!(lib.contains("#[table_model(sql_name = \"order items\")]"), "{lib}");
+    assert!(lib.contains("pub struct OrderItem"), "{lib}");
+    for (sql_name, field) in [
+        ("self", "self_: i32"),
+        ("table", "__table: String"),
+        ("Größe", "grosse: i32"),
+        ("2fa", "_2fa: bool"),
+    ] {
+        assert!(lib.contains(&format!("#[table_model(sql_name = \"{sql_name}\")]")), "{lib}");
+        assert!(lib.contains(field), "{lib}");
+    }
+    for (sql_name, field) in
+        [("type", "r#type: String"), ("gen", "r#gen: i32"), ("dyn", "r#dyn: i32")]
+    {
+        assert!(lib.contains(field), "{lib}");
+        assert!(!lib.contains(&format!("sql_name = \"{sql_name}\"")), "{lib}");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,51 @@
+//! Test module to verify that regenerating a workspace only rewrites the files
+//! whose contents changed.
+
+use sql_traits::prelude::ParserDB;
+use sqlparser::dialect::GenericDialect;
+use synql::prelude::*;
+
+#[test]
+fn test_incremental_generation() -> Result<(), Box<dyn std::error::Error>> {
+    let temp_dir = tempfile::tempdir()?;
+    let workspace_path = temp_dir.path().join("synql_incremental");
+
+    let db = ParserDB::parse::<GenericDialect>(
+        "
+        CREATE TABLE users (id SERIAL PRIMARY KEY, name TEXT NOT NULL);
+        CREATE TABLE comments (id SERIAL PRIMARY KEY, user_id INT REFERENCES users(id));
+",
+    )?;
+    let synql: SynQL<ParserDB> =
+        SynQL::new(&db, &workspace_path).name("synql-incremental").generate_workspace_toml().into();
+
+    let first = synql.generate()?.file_statistics();
+    assert_eq!(first.written(), 5);
+    assert_eq!(first.unchanged(), 0);
+    assert_eq!(first.deleted(), 0);
+
+    let users_lib = workspace_path.join("synql-incremental-users").join("src").join("lib.rs");
+    let users_modified = std::fs::metadata(&users_lib)?.modified()?;
+
+    let second = synql.generate()?.file_statistics();
+    assert_eq!(second.written(), 0);
+    assert_eq!(second.unchanged(), 5);
+    assert_eq!(std::fs::metadata(&users_lib)?.modified()?, users_modified);
+
+    // Changing a single table only rewrites the files of its crate.
+    let db = ParserDB::parse::<GenericDialect>(
+        "
+        CREATE TABLE users (id SERIAL PRIMARY KEY, name TEXT NOT NULL);
+        CREATE TABLE comments (id SERIAL PRIMARY KEY, user_id INT REFERENCES users(id), body TEXT);
+",
+    )?;
+    let synql: SynQL<ParserDB> =
+        SynQL::new(&db, &workspace_path).name("synql-incremental").generate_workspace_toml().into();
+
+    let third = synql.generate()?.file_statistics();
+    assert_eq!(third.written(), 1);
+    assert_eq!(third.unchanged(), 4);
+    assert_eq!(std::fs::metadata(&users_lib)?.modified()?, users_modified);
+
+    Ok(())
+}
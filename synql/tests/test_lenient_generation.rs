@@ -0,0 +1,73 @@
+//! Test module to verify that the lenient generation mode skips the
+//! unsupported constructs, reporting them as warnings.
+
+use sql_traits::prelude::ParserDB;
+use sqlparser::dialect::GenericDialect;
+use synql::prelude::*;
+
+#[test]
+fn test_lenient_generation() -> Result<(), Box<dyn std::error::Error>> {
+    let db = ParserDB::parse::<GenericDialect>(
+        "
+        CREATE TABLE users (
+            id SERIAL PRIMARY KEY,
+            name TEXT NOT NULL CHECK (name <> '' AND name LIKE 'user_%')
+        );
+        CREATE TABLE gadgets (id SERIAL PRIMARY KEY, shape widget_shape NOT NULL);
+        CREATE TABLE gadget_owners (
+            id SERIAL PRIMARY KEY,
+            gadget_id INT NOT NULL REFERENCES gadgets(id),
+            user_id INT NOT NULL REFERENCES users(id)
+        );
+",
+    )?;
+    let temp_dir = tempfile::tempdir()?;
+    let workspace_path = temp_dir.path().join("synql_lenient");
+
+    let synql: SynQL<ParserDB> = SynQL::new(&db, &workspace_path)
+        .name("synql-lenient")
+        .generate_workspace_toml()
+        .lenient()
+        .into();
+    let report = synql.generate()?;
+
+    assert!(workspace_path.join("synql-lenient-users").exists());
+    assert!(!workspace_path.join("synql-lenient-gadgets").exists());
+    assert!(!workspace_path.join("synql-lenient-gadget_owners").exists());
+    let workspace_toml = std::fs::read_to_string(workspace_path.join("Cargo.toml"))?;
+    assert!(!workspace_toml.contains("gadget"), "Excluded tables must not be members");
+
+    let users_lib = std::fs::read_to_string(
+        workspace_path.join("synql-lenient-users").join("src").join("lib.rs"),
+    )?;
+    assert!(
+        users_lib
+            .lines()
+            .any(|line| line.trim_start().starts_with("// TODO: ") && line.contains("LIKE")),
+        "The dropped check constraint must be marked with a TODO comment:\n{users_lib}"
+    );
+    assert!(!users_lib.contains("__synql_todo__"));
+
+    let warnings = report.warnings();
+    assert_eq!(warnings.len(), 3, "Unexpected warnings: {warnings:?}");
+    assert!(warnings.contains(&GenerationWarning::UnmappableColumnType {
+        table_name: "gadgets".to_string(),
+        column_name: "shape".to_string(),
+        sql_type: "widget_shape".to_string(),
+    }));
+    assert!(warnings.contains(&GenerationWarning::ExcludedDependency {
+        table_name: "gadget_owners".to_string(),
+        dependency: "gadgets".to_string(),
+    }));
+    assert!(warnings.iter().any(|warning| matches!(
+        warning,
+        GenerationWarning::DroppedCheckConstraint { table_name, reason, .. }
+            if table_name == "users" && *reason == CheckConstraintErrorKind::UnsupportedExpression
+    )));
+
+    let generated = synql.generate_in_memory()?;
+    assert_eq!(generated.warnings(), warnings, "In-memory generation must report the warnings");
+    assert_eq!(generated.get("synql-lenient-users/src/lib.rs"), Some(users_lib.as_str()));
+
+    Ok(())
+}
@@ -0,0 +1,109 @@
+//! Test module to verify that the naming strategy set on the builder
+//! determines the names of the generated crates, structs and fields.
+
+use std::path::Path;
+
+use sql_traits::prelude::ParserDB;
+use sqlparser::dialect::GenericDialect;
+use synql::prelude::*;
+
+const SCHEMA: &str = "
+    CREATE TABLE data (id SERIAL PRIMARY KEY, value TEXT NOT NULL);
+    CREATE TABLE users (id SERIAL PRIMARY KEY, first_name TEXT NOT NULL, datum_id INT NOT NULL REFERENCES data(id));
+";
+
+#[test]
+fn test_naming_overrides() -> Result<(), Box<dyn std::error::Error>> {
+    let db = ParserDB::parse::<GenericDialect>(SCHEMA)?;
+    let temp_dir = tempfile::tempdir()?;
+
+    let naming = NamingOverrides::default()
+        .rename_struct(None, "data", "Datum")
+        .rename_table(None, "users", "accounts")
+        .rename_field(None, "users", "first_name", "given_name");
+    let synql: SynQL<ParserDB> =
+        SynQL::new(&db, temp_dir.path()).name("naming").naming_strategy(naming).into();
+    let generated = synql.generate_in_memory()?;
+
+    let data_lib = generated.get("naming-data/src/lib.rs").unwrap();
+    assert!(data_lib.contains("pub struct Datum"));
+
+    let users_lib = generated.get("naming-accounts/src/lib.rs").unwrap();
+    assert!(users_lib.contains("pub struct User"));
+    assert!(users_lib.contains("#[table_model(sql_name = \"first_name\")]"));
+    assert!(users_lib.contains("given_name: String"));
+    assert!(users_lib.contains("belongs_to(naming_data::Datum"));
+
+    Ok(())
+}
+
+#[test]
+fn test_schema_naming_overrides() -> Result<(), Box<dyn std::error::Error>> {
+    let db = ParserDB::parse::<GenericDialect>(
+        "
+        CREATE TABLE public.users (id SERIAL PRIMARY KEY, first_name TEXT NOT NULL);
+        CREATE TABLE auth.users (id SERIAL PRIMARY KEY, first_name TEXT NOT NULL);
+",
+    )?;
+    let temp_dir = tempfile::tempdir()?;
+
+    let naming = NamingOverrides::default()
+        .rename_struct(Some("auth"), "users", "Account")
+        .rename_field(Some("auth"), "users", "first_name", "login");
+    let synql: SynQL<ParserDB> =
+        SynQL::new(&db, temp_dir.path()).name("naming").naming_strategy(naming).into();
+    let generated = synql.generate_in_memory()?;
+
+    let public_users_lib = generated.get("naming-users/src/lib.rs").unwrap();
+    assert!(public_users_lib.contains("pub struct User"), "{public_users_lib}");
+    assert!(public_users_lib.contains("first_name: String"), "{public_users_lib}");
+
+    let auth_users_lib = generated.get("naming-auth_users/src/lib.rs").unwrap();
+    assert!(auth_users_lib.contains("pub struct Account"), "{auth_users_lib}");
+    assert!(auth_users_lib.contains("login: String"), "{auth_users_lib}");
+
+    Ok(())
+}
+
+#[test]
+fn test_schema_crate_name_overrides() -> Result<(), Box<dyn std::error::Error>> {
+    let db = ParserDB::parse::<GenericDialect>(
+        "
+        CREATE TABLE public.users (id SERIAL PRIMARY KEY);
+        CREATE TABLE auth.users (id SERIAL PRIMARY KEY);
+",
+    )?;
+
+    let naming = NamingOverrides::default().rename_crate(Some("auth"), "users", "naming-accounts");
+    let synql: SynQL<ParserDB> =
+        SynQL::new(&db, Path::new("in-memory")).name("naming").naming_strategy(naming).into();
+    let generated = synql.generate_in_memory()?;
+
+    assert!(generated.get("naming-users/src/lib.rs").is_some());
+    assert!(generated.get("naming-accounts/src/lib.rs").is_some());
+    assert!(generated.get("naming-auth_users/src/lib.rs").is_none());
+
+    Ok(())
+}
+
+#[test]
+fn test_invalid_crate_name() -> Result<(), Box<dyn std::error::Error>> {
+    let db = ParserDB::parse::<GenericDialect>(SCHEMA)?;
+
+    for invalid_name in ["2fa-users", "naming.users", "type"] {
+        let naming = NamingOverrides::default().rename_crate(None, "users", invalid_name);
+        let synql: SynQL<ParserDB> =
+            SynQL::new(&db, Path::new("in-memory")).name("naming").naming_strategy(naming).into();
+        match synql.generate_in_memory() {
+            Err(synql::Error::InvalidCrateName { crate_name, origin }) => {
+                assert_eq!(crate_name, invalid_name);
+                assert_eq!(origin, "table `users`");
+            }
+            other => {
+                panic!("Expected an invalid crate name error for `{invalid_name}`, got {other:?}")
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,87 @@
+//! Test module to verify that the `numeric` columns are mapped to the
+//! `bigdecimal` crate, alongside their defaults and checks.
+
+mod common;
+
+use std::str::FromStr;
+
+use bigdecimal::{BigDecimal, RoundingMode};
+use syn::parse_quote;
+use synql::prelude::*;
+
+#[test]
+fn test_numeric_types() -> Result<(), Box<dyn std::error::Error>> {
+    let schema = r"
+CREATE TABLE invoices (
+    id SERIAL PRIMARY KEY,
+    total NUMERIC(10, 2) NOT NULL DEFAULT 0 CHECK (total >= 0.5),
+    rate DECIMAL
+);
+";
+    let generated = common::generate_in_memory(schema, "numeric", |builder| builder)?;
+
+    let invoices_lib = generated.get("numeric-invoices/src/lib.rs").unwrap();
+    assert_eq!(
+        common::field_type(invoices_lib, "Invoice", "total"),
+        parse_quote!(::bigdecimal::BigDecimal)
+    );
+    assert_eq!(
+        common::field_type(invoices_lib, "Invoice", "rate"),
+        parse_quote!(Option<::bigdecimal::BigDecimal>)
+    );
+    let expected_check: syn::ExprCall =
+        parse_quote!(<::bigdecimal::BigDecimal as ::core::str::FromStr>::from_str("0.5"));
+    assert!(common::calls(invoices_lib).contains(&expected_check), "{invoices_lib}");
+    let expected: syn::Expr = parse_quote! {
+        <::bigdecimal::BigDecimal as ::core::str::FromStr>::from_str("0")
+            .expect("The literal must be a valid decimal")
+            .with_scale_round(2i64, ::bigdecimal::RoundingMode::HalfUp)
+    };
+    assert_eq!(common::field_default(invoices_lib, "Invoice", "total"), Some(expected));
+    let invoices_toml = generated.get("numeric-invoices/Cargo.toml").unwrap();
+    assert!(invoices_toml.contains("bigdecimal"), "{invoices_toml}");
+
+    Ok(())
+}
+
+#[test]
+fn test_numeric_default_rounding() -> Result<(), Box<dyn std::error::Error>> {
+    let schema = "CREATE TABLE prices (id SERIAL PRIMARY KEY, amount NUMERIC(10, 2) NOT NULL DEFAULT 1.005);";
+    let generated = common::generate_in_memory(schema, "numeric", |builder| builder)?;
+
+    let prices_lib = generated.get("numeric-prices/src/lib.rs").unwrap();
+    let expected: syn::Expr = parse_quote! {
+        <::bigdecimal::BigDecimal as ::core::str::FromStr>::from_str("1.005")
+            .expect("The literal must be a valid decimal")
+            .with_scale_round(2i64, ::bigdecimal::RoundingMode::HalfUp)
+    };
+    assert_eq!(common::field_default(prices_lib, "Price", "amount"), Some(expected));
+    // The generated default rounds as Postgres does, rather than truncating.
+    assert_eq!(
+        BigDecimal::from_str("1.005")?.with_scale_round(2, RoundingMode::HalfUp),
+        BigDecimal::from_str("1.01")?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_uncastable_numeric_default() -> Result<(), Box<dyn std::error::Error>> {
+    let schema = "CREATE TABLE readings (id SERIAL PRIMARY KEY, value NUMERIC NOT NULL DEFAULT 'NaN'::numeric);";
+
+    assert!(matches!(
+        common::generate_in_memory(schema, "numeric", |builder| builder),
+        Err(synql::Error::InvalidDefaultValue { table_name, column_name, .. })
+            if table_name == "readings" && column_name == "value"
+    ));
+
+    let generated = common::generate_in_memory(schema, "numeric", |builder| builder.lenient())?;
+    let readings_lib = generated.get("numeric-readings/src/lib.rs").unwrap();
+    assert_eq!(common::field_default(readings_lib, "Reading", "value"), None);
+    assert!(matches!(
+        generated.warnings(),
+        [GenerationWarning::DroppedDefaultValue { column_name, .. }] if column_name == "value"
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+//! Test module to verify that the generation of the table crates is complete
+//! and deterministic, including when the crates are generated in parallel
+//! with the `rayon` feature.
+
+use sql_traits::prelude::ParserDB;
+use sqlparser::dialect::GenericDialect;
+use synql::prelude::*;
+
+#[test]
+fn test_parallel_generation() -> Result<(), Box<dyn std::error::Error>> {
+    let temp_dir = tempfile::tempdir()?;
+    let workspace_path = temp_dir.path().join("synql_parallel");
+
+    let mut sql =
+        String::from("CREATE TABLE node_0 (id SERIAL PRIMARY KEY, name TEXT NOT NULL);\n");
+    for i in 1..24 {
+        sql.push_str(&format!(
+            "CREATE TABLE node_{i} (id SERIAL PRIMARY KEY, parent_id INT REFERENCES node_{}(id), name TEXT NOT NULL);\n",
+            i - 1
+        ));
+    }
+    let db = ParserDB::parse::<GenericDialect>(&sql)?;
+    let synql: SynQL<ParserDB> = SynQL::new(&db, &workspace_path)
+        .name("synql-parallel")
+        .generate_workspace_toml()
+        .callback(|table, _db, _workspace| {
+            let name = table.table_snake_name();
+            Ok(Some(quote::quote! {
+                /// Returns the name of the table.
+                pub fn table_name() -> &'static str { #name }
+            }))
+        })
+        .into();
+
+    let first = synql.generate_in_memory()?;
+    let second = synql.generate_in_memory()?;
+    assert_eq!(first, second, "The generation must be deterministic");
+
+    for i in 0..24 {
+        let lib = first
+            .get(format!("synql-parallel-node_{i}/src/lib.rs"))
+            .unwrap_or_else(|| panic!("Missing crate for node_{i}"));
+        assert!(lib.contains(&format!("\"node_{i}\"")));
+        assert!(first.contains(format!("synql-parallel-node_{i}/Cargo.toml")));
+    }
+    assert_eq!(first.len(), 24 * 2 + 1);
+
+    Ok(())
+}
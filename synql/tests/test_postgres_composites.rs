@@ -0,0 +1,81 @@
+//! Test module to verify that the Postgres composite types are generated as
+//! structs in the types crate of the workspace.
+
+mod common;
+
+use syn::parse_quote;
+
+const SCHEMA: &str = r"
+CREATE TYPE address AS (street text, city text, zip integer);
+CREATE TABLE customers (
+    id SERIAL PRIMARY KEY,
+    home address NOT NULL,
+    previous address[] NOT NULL
+);
+";
+
+#[test]
+fn test_postgres_composites() -> Result<(), Box<dyn std::error::Error>> {
+    let generated = common::generate_in_memory(SCHEMA, "composites", |builder| {
+        builder.generate_workspace_toml()
+    })?;
+
+    let types_lib = generated.get("composites-types/src/lib.rs").unwrap();
+    let street = common::field(types_lib, "Address", "street");
+    assert_eq!(street.vis, parse_quote!(pub));
+    assert_eq!(street.ty, parse_quote!(Option<String>));
+    assert_eq!(common::field_type(types_lib, "Address", "zip"), parse_quote!(Option<i32>));
+    assert_eq!(common::item_struct(types_lib, "AddressSqlType").fields, syn::Fields::Unit);
+    let traits: [syn::Path; 2] = [
+        parse_quote!(::diesel::serialize::ToSql<AddressSqlType, ::diesel::pg::Pg>),
+        parse_quote!(::diesel::deserialize::FromSql<AddressSqlType, ::diesel::pg::Pg>),
+    ];
+    for trait_path in &traits {
+        common::item_impl(types_lib, trait_path, &parse_quote!(Address));
+    }
+
+    let customers_lib = generated.get("composites-customers/src/lib.rs").unwrap();
+    assert_eq!(
+        common::field_type(customers_lib, "Customer", "home"),
+        parse_quote!(::composites_types::Address)
+    );
+    assert_eq!(
+        common::field_type(customers_lib, "Customer", "previous"),
+        parse_quote!(Vec<::composites_types::Address>)
+    );
+    let customers_toml = generated.get("composites-customers/Cargo.toml").unwrap();
+    assert!(customers_toml.contains("composites-types.workspace = true"), "{customers_toml}");
+
+    Ok(())
+}
+
+#[test]
+fn test_composite_attribute_type_not_found() {
+    let schema = r"
+CREATE TYPE reading AS (value unknown_type);
+CREATE TABLE sensors (id SERIAL PRIMARY KEY);
+";
+    assert!(matches!(
+        common::generate_in_memory(schema, "composites", |builder| builder),
+        Err(synql::Error::CompositeAttributeTypeNotFound { composite, attribute, .. })
+            if composite == "reading" && attribute == "value"
+    ));
+}
+
+#[test]
+fn test_composite_attribute_type_modifiers() -> Result<(), Box<dyn std::error::Error>> {
+    let schema = r"
+CREATE TYPE shift AS (tags varchar(20)[], starts_at time(0) with time zone);
+CREATE TABLE rotas (id SERIAL PRIMARY KEY, shift shift NOT NULL);
+";
+    let generated = common::generate_in_memory(schema, "composites", |builder| builder)?;
+
+    let types_lib = generated.get("composites-types/src/lib.rs").unwrap();
+    assert_eq!(common::field_type(types_lib, "Shift", "tags"), parse_quote!(Option<Vec<String>>));
+    assert_eq!(
+        common::field_type(types_lib, "Shift", "starts_at"),
+        parse_quote!(Option<std::time::SystemTime>)
+    );
+
+    Ok(())
+}
@@ -0,0 +1,183 @@
+//! Test module to verify that the Postgres domains are generated as validated
+//! newtypes in the types crate of the workspace.
+
+mod common;
+
+use sqlparser::dialect::GenericDialect;
+use syn::parse_quote;
+use synql::prelude::*;
+
+const SCHEMA: &str = r"
+CREATE DOMAIN positive_int AS integer CHECK (VALUE > 0);
+CREATE DOMAIN label AS text CHECK (VALUE <> '');
+CREATE TABLE items (
+    id SERIAL PRIMARY KEY,
+    quantity positive_int NOT NULL DEFAULT 1,
+    name label NOT NULL
+);
+";
+
+#[test]
+fn test_postgres_domains() -> Result<(), Box<dyn std::error::Error>> {
+    let generated =
+        common::generate_in_memory(SCHEMA, "domains", |builder| builder.generate_workspace_toml())?;
+
+    let types_lib = generated.get("domains-types/src/lib.rs").unwrap();
+    for (domain, base_type) in [("PositiveInt", "i32"), ("Label", "String")] {
+        let base_type: syn::Type = syn::parse_str(base_type)?;
+        let expected: syn::FieldsUnnamed = parse_quote!((#base_type));
+        assert_eq!(common::item_struct(types_lib, domain).fields, syn::Fields::Unnamed(expected));
+        common::item_impl(
+            types_lib,
+            &parse_quote!(::core::convert::TryFrom<#base_type>),
+            &syn::parse_str(domain)?,
+        );
+    }
+    assert!(
+        common::called_functions(types_lib).contains(&parse_quote!(
+            ::validation_errors::ValidationError::strictly_greater_than_value
+        )),
+        "{types_lib}"
+    );
+    assert!(
+        common::if_conditions(types_lib).contains(&parse_quote!(value.is_empty())),
+        "{types_lib}"
+    );
+    let types_toml = generated.get("domains-types/Cargo.toml").unwrap();
+    assert!(types_toml.contains("validation-errors.workspace = true"), "{types_toml}");
+
+    let items_lib = generated.get("domains-items/src/lib.rs").unwrap();
+    assert_eq!(
+        common::field_type(items_lib, "Item", "quantity"),
+        parse_quote!(::domains_types::PositiveInt)
+    );
+    assert_eq!(common::field_type(items_lib, "Item", "name"), parse_quote!(::domains_types::Label));
+    assert_eq!(
+        common::field_default(items_lib, "Item", "quantity"),
+        Some(parse_quote!(
+            <::domains_types::PositiveInt>::try_from(1i32)
+                .expect("The default value is validated at generation time")
+        ))
+    );
+    let items_toml = generated.get("domains-items/Cargo.toml").unwrap();
+    assert!(items_toml.contains("domains-types.workspace = true"), "{items_toml}");
+
+    Ok(())
+}
+
+#[test]
+fn test_unsupported_domain_check() -> Result<(), Box<dyn std::error::Error>> {
+    let schema = r"
+CREATE DOMAIN code AS text CHECK (VALUE <> '' AND VALUE LIKE 'C%');
+CREATE TABLE products (id SERIAL PRIMARY KEY, code code NOT NULL);
+";
+    assert!(matches!(
+        common::generate_in_memory(schema, "domains", |builder| builder),
+        Err(synql::Error::UnsupportedDomainCheck { domain, .. }) if domain == "code"
+    ));
+
+    let generated = common::generate_in_memory(schema, "domains", |builder| builder.lenient())?;
+    let types_lib = generated.get("domains-types/src/lib.rs").unwrap();
+    assert!(
+        common::if_conditions(types_lib).contains(&parse_quote!(value.is_empty())),
+        "{types_lib}"
+    );
+    assert!(
+        types_lib
+            .lines()
+            .any(|line| line.trim_start().starts_with("// TODO: ") && line.contains("LIKE")),
+        "The dropped check must be marked with a TODO comment:\n{types_lib}"
+    );
+    assert!(generated.warnings().iter().any(|warning| {
+        matches!(
+            warning,
+            GenerationWarning::DroppedDomainCheck { domain, .. } if domain == "code"
+        )
+    }));
+
+    Ok(())
+}
+
+#[test]
+fn test_invalid_domain_default() -> Result<(), Box<dyn std::error::Error>> {
+    let schema = r"
+CREATE DOMAIN positive_int AS integer CHECK (VALUE > 0);
+CREATE DOMAIN code AS text CHECK (is_valid_code(VALUE));
+CREATE TABLE items (
+    id SERIAL PRIMARY KEY,
+    quantity positive_int NOT NULL DEFAULT 0,
+    code code NOT NULL DEFAULT 'C1'
+);
+";
+    assert!(matches!(
+        common::generate_in_memory(schema, "domains", |builder| builder),
+        Err(synql::Error::InvalidDefaultValue { column_name, reason, .. })
+            if column_name == "quantity" && reason.contains("VALUE > 0")
+    ));
+
+    let generated = common::generate_in_memory(schema, "domains", |builder| builder.lenient())?;
+    let items_lib = generated.get("domains-items/src/lib.rs").unwrap();
+    assert_eq!(common::field_default(items_lib, "Item", "quantity"), None);
+    assert_eq!(common::field_default(items_lib, "Item", "code"), None);
+    let dropped_defaults = generated
+        .warnings()
+        .iter()
+        .filter_map(|warning| {
+            match warning {
+                GenerationWarning::DroppedDefaultValue { column_name, reason, .. } => {
+                    Some((column_name.as_str(), reason.as_str()))
+                }
+                _ => None,
+            }
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        dropped_defaults,
+        [
+            ("quantity", "it does not satisfy the check `VALUE > 0` of the domain"),
+            (
+                "code",
+                "the check `is_valid_code(VALUE)` of the domain cannot be evaluated at generation time"
+            ),
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_domain_literal_default() -> Result<(), Box<dyn std::error::Error>> {
+    let schema = r"
+CREATE DOMAIN path AS text CHECK (length(VALUE) BETWEEN 1 AND 16);
+CREATE TABLE modules (
+    id SERIAL PRIMARY KEY,
+    path path NOT NULL DEFAULT 'crate::it''s'::path
+);
+";
+    let generated = common::generate_in_memory(schema, "domains", |builder| builder)?;
+
+    let modules_lib = generated.get("domains-modules/src/lib.rs").unwrap();
+    assert_eq!(
+        common::field_default(modules_lib, "Module", "path"),
+        Some(parse_quote!(
+            <::domains_types::Path>::try_from(::std::string::String::from("crate::it's"))
+                .expect("The default value is validated at generation time")
+        ))
+    );
+    assert!(generated.warnings().is_empty(), "{:?}", generated.warnings());
+
+    Ok(())
+}
+
+#[test]
+fn test_domain_type_modifiers() -> Result<(), Box<dyn std::error::Error>> {
+    let schema = r"
+CREATE DOMAIN instant AS timestamp(3) with time zone;
+CREATE DOMAIN prices AS numeric(10,2)[];
+";
+    let types = UserDefinedTypes::parse::<GenericDialect>(schema)?;
+    let data_types = types.domains().map(PostgresDomain::data_type).collect::<Vec<_>>();
+    assert_eq!(data_types, ["timestamp with time zone", "numeric[]"]);
+
+    Ok(())
+}
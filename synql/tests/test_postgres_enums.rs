@@ -0,0 +1,161 @@
+//! Test module to verify that the Postgres enumerated types are generated as
+//! Rust enums in the types crate of the workspace.
+
+mod common;
+
+use sqlparser::dialect::GenericDialect;
+use syn::parse_quote;
+use synql::prelude::*;
+
+const SCHEMA: &str = r"
+CREATE TYPE mood AS ENUM ('sad', 'ok', 'happy');
+CREATE TABLE people (
+    id SERIAL PRIMARY KEY,
+    current_mood mood NOT NULL DEFAULT 'happy'::mood
+);
+";
+
+#[test]
+fn test_postgres_enums() -> Result<(), Box<dyn std::error::Error>> {
+    let generated =
+        common::generate_in_memory(SCHEMA, "enums", |builder| builder.generate_workspace_toml())?;
+
+    let types_lib = generated.get("enums-types/src/lib.rs").unwrap();
+    let mood = common::item_enum(types_lib, "Mood");
+    let variants =
+        mood.variants.iter().map(|variant| variant.ident.to_string()).collect::<Vec<_>>();
+    assert_eq!(variants, ["Sad", "Ok", "Happy"]);
+    assert!(mood.variants[0].attrs.iter().any(|attribute| attribute.path().is_ident("default")));
+    let expected: syn::Attribute = parse_quote!(#[diesel(postgres_type(name = "mood"))]);
+    assert!(common::item_struct(types_lib, "MoodSqlType").attrs.contains(&expected));
+    let traits: [syn::Path; 4] = [
+        parse_quote!(::core::str::FromStr),
+        parse_quote!(::core::fmt::Display),
+        parse_quote!(::diesel::serialize::ToSql<MoodSqlType, ::diesel::pg::Pg>),
+        parse_quote!(::diesel::deserialize::FromSql<MoodSqlType, ::diesel::pg::Pg>),
+    ];
+    for trait_path in &traits {
+        common::item_impl(types_lib, trait_path, &parse_quote!(Mood));
+    }
+
+    let people_lib = generated.get("enums-people/src/lib.rs").unwrap();
+    assert_eq!(
+        common::field_type(people_lib, "Person", "current_mood"),
+        parse_quote!(::enums_types::Mood)
+    );
+    assert_eq!(
+        common::field_default(people_lib, "Person", "current_mood"),
+        Some(parse_quote!(::enums_types::Mood::Happy))
+    );
+    let people_toml = generated.get("enums-people/Cargo.toml").unwrap();
+    assert!(people_toml.contains("enums-types.workspace = true"), "{people_toml}");
+
+    let workspace_toml = generated.get("Cargo.toml").unwrap();
+    assert!(workspace_toml.contains("\"./enums-types\""), "{workspace_toml}");
+    assert!(
+        workspace_toml.contains("enums-types = { path = \"./enums-types\""),
+        "{workspace_toml}"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_unknown_enum_type() {
+    assert!(matches!(
+        common::generate_in_memory(SCHEMA, "enums", |builder| {
+            builder.user_defined_types(UserDefinedTypes::default())
+        }),
+        Err(synql::Error::ColumnTypeNotFound { column_name, .. }) if column_name == "current_mood"
+    ));
+}
+
+#[test]
+fn test_duplicate_types() -> Result<(), Box<dyn std::error::Error>> {
+    for (user_defined_types, expected_first, expected_second) in [
+        (
+            UserDefinedTypes::parse::<GenericDialect>(
+                r#"
+                CREATE TYPE "Mood" AS ENUM ('sad', 'happy');
+                CREATE TYPE mood AS ENUM ('ok');
+"#,
+            )?,
+            "enum `Mood`",
+            "enum `mood`",
+        ),
+        (
+            UserDefinedTypes::default()
+                .enum_type(PostgresEnum::new("mood", ["sad", "happy"]))
+                .domain(PostgresDomain::new("mood", "TEXT")),
+            "enum `mood`",
+            "domain `mood`",
+        ),
+    ] {
+        match common::generate_in_memory(
+            "CREATE TABLE people (id SERIAL PRIMARY KEY);",
+            "enums",
+            |builder| builder.user_defined_types(user_defined_types),
+        ) {
+            Err(synql::Error::DuplicateType { postgres_type, first, second }) => {
+                assert_eq!(postgres_type, "mood");
+                assert_eq!(first, expected_first);
+                assert_eq!(second, expected_second);
+            }
+            other => panic!("Expected a duplicate type error, got {other:?}"),
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_variant_collision() {
+    let schema = r"
+CREATE TYPE mood AS ENUM ('happy', 'sad', 'Happy');
+CREATE TABLE people (id SERIAL PRIMARY KEY);
+";
+
+    match common::generate_in_memory(schema, "enums", |builder| builder) {
+        Err(synql::Error::IdentifierCollision { identifier, first, second }) => {
+            assert_eq!(identifier, "Happy");
+            assert_eq!(first, "label `happy` of type `mood`");
+            assert_eq!(second, "label `Happy` of type `mood`");
+        }
+        other => panic!("Expected an identifier collision, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_disambiguated_variant_collision() -> Result<(), Box<dyn std::error::Error>> {
+    let schema = r"
+CREATE TYPE mood AS ENUM ('a b', 'a_b');
+CREATE TABLE people (
+    id SERIAL PRIMARY KEY,
+    current_mood mood NOT NULL DEFAULT 'a_b'::mood
+);
+";
+    let generated =
+        common::generate_in_memory(schema, "enums", |builder| builder.disambiguate_identifiers())?;
+
+    let types_lib = generated.get("enums-types/src/lib.rs").unwrap();
+    let arms = common::match_arms(types_lib);
+    assert!(arms.contains(&parse_quote!(Self::AB => "a b",)), "{types_lib}");
+    assert!(arms.contains(&parse_quote!(Self::AB2 => "a_b",)), "{types_lib}");
+    let people_lib = generated.get("enums-people/src/lib.rs").unwrap();
+    assert_eq!(
+        common::field_default(people_lib, "Person", "current_mood"),
+        Some(parse_quote!(::enums_types::Mood::AB2))
+    );
+
+    assert_eq!(
+        generated.warnings(),
+        [GenerationWarning::DisambiguatedIdentifier {
+            renamed: "label `a_b` of type `mood`".to_string(),
+            other: "label `a b` of type `mood`".to_string(),
+            identifier: "AB".to_string(),
+            disambiguated: "AB2".to_string(),
+        }]
+    );
+
+    Ok(())
+}
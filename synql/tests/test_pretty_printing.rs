@@ -0,0 +1,44 @@
+//! Test module to verify that the generated Rust sources are pretty-printed
+//! in-process, without requiring `rustfmt`.
+
+use sql_traits::prelude::ParserDB;
+use sqlparser::dialect::GenericDialect;
+use synql::prelude::*;
+
+#[test]
+fn test_pretty_printing() -> Result<(), Box<dyn std::error::Error>> {
+    let db = ParserDB::parse::<GenericDialect>(
+        "
+        CREATE TABLE users (id SERIAL PRIMARY KEY, name TEXT NOT NULL);
+        CREATE TABLE comments (id SERIAL PRIMARY KEY, user_id INT REFERENCES users(id));
+",
+    )?;
+    let temp_dir = tempfile::tempdir()?;
+    let workspace_path = temp_dir.path().join("synql_pretty_printing");
+
+    let synql: SynQL<ParserDB> =
+        SynQL::new(&db, &workspace_path).name("synql-pretty").sink_crate("sink").into();
+    let generated = synql.generate_in_memory()?;
+
+    for (relative_path, contents) in generated.files() {
+        if relative_path.extension().is_none_or(|extension| extension != "rs") {
+            continue;
+        }
+        assert!(
+            contents.lines().count() > 1,
+            "{} should be spread over several lines",
+            relative_path.display()
+        );
+        assert_eq!(
+            prettyplease::unparse(&syn::parse_file(contents)?),
+            contents,
+            "{} should already be pretty-printed",
+            relative_path.display()
+        );
+    }
+
+    let users_lib = generated.get("synql-pretty-users/src/lib.rs").expect("Missing users crate");
+    assert!(users_lib.lines().any(|line| line.starts_with("pub struct User {")));
+
+    Ok(())
+}
@@ -0,0 +1,68 @@
+//! Test module to verify that the range and multirange columns are mapped to
+//! diesel's bound tuples, alongside the translation of their checks.
+
+mod common;
+
+use syn::parse_quote;
+
+#[test]
+fn test_range_types() -> Result<(), Box<dyn std::error::Error>> {
+    let schema = r"
+CREATE TABLE bookings (
+    id SERIAL PRIMARY KEY,
+    seats INT4RANGE NOT NULL CHECK (NOT isempty(seats)),
+    first_seat INTEGER NOT NULL,
+    period TSTZRANGE,
+    closures DATEMULTIRANGE,
+    CHECK (lower(seats) >= 1),
+    CHECK (seats @> first_seat)
+);
+";
+    let generated = common::generate_in_memory(schema, "ranges", |builder| builder)?;
+
+    let bookings_lib = generated.get("ranges-bookings/src/lib.rs").unwrap();
+    assert_eq!(
+        common::field_type(bookings_lib, "Booking", "seats"),
+        parse_quote!((::core::ops::Bound<i32>, ::core::ops::Bound<i32>))
+    );
+    assert_eq!(
+        common::field_sql_type(bookings_lib, "Booking", "seats"),
+        Some(parse_quote!(diesel::sql_types::Range<::diesel::sql_types::Integer>))
+    );
+    assert_eq!(
+        common::field_sql_type(bookings_lib, "Booking", "closures"),
+        Some(parse_quote!(diesel::sql_types::Multirange<::diesel::sql_types::Date>))
+    );
+    let called_functions = common::called_functions(bookings_lib);
+    let expected_functions: [syn::Path; 3] = [
+        parse_quote!(::validation_errors::ValidationError::empty),
+        parse_quote!(::validation_errors::ValidationError::greater_than),
+        parse_quote!(::validation_errors::ValidationError::smaller_than),
+    ];
+    for expected_function in &expected_functions {
+        assert!(called_functions.contains(expected_function), "{bookings_lib}");
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_nullable_range_operands() -> Result<(), Box<dyn std::error::Error>> {
+    let schema = r"
+CREATE TABLE shifts (
+    id SERIAL PRIMARY KEY,
+    period TSTZRANGE,
+    starts_at TIMESTAMPTZ NOT NULL,
+    CHECK (period @> starts_at)
+);
+";
+    let generated = common::generate_in_memory(schema, "ranges", |builder| builder)?;
+
+    // The nullable range is only checked when it holds a value, as the check
+    // holds in Postgres when it is null.
+    let shifts_lib = generated.get("ranges-shifts/src/lib.rs").unwrap();
+    let expected: syn::ExprLet = parse_quote!(let Some(period) = period.as_ref());
+    assert!(common::if_let_conditions(shifts_lib).contains(&expected), "{shifts_lib}");
+
+    Ok(())
+}
@@ -0,0 +1,97 @@
+//! Test module to verify that the crates of tables from different schemas
+//! are qualified with their schema, and that collisions are reported.
+
+use sql_traits::prelude::ParserDB;
+use sqlparser::dialect::GenericDialect;
+use synql::prelude::*;
+
+const SCHEMA: &str = "
+    CREATE TABLE public.users (id SERIAL PRIMARY KEY, name TEXT NOT NULL);
+    CREATE TABLE auth.users (id SERIAL PRIMARY KEY, email TEXT NOT NULL);
+    CREATE TABLE auth.sessions (id SERIAL PRIMARY KEY, user_id INT NOT NULL REFERENCES auth.users(id));
+";
+
+#[test]
+fn test_default_schema_naming() -> Result<(), Box<dyn std::error::Error>> {
+    let db = ParserDB::parse::<GenericDialect>(SCHEMA)?;
+    let temp_dir = tempfile::tempdir()?;
+
+    let synql: SynQL<ParserDB> =
+        SynQL::new(&db, temp_dir.path()).name("schemas").sink_crate("schemas-sink").into();
+    let generated = synql.generate_in_memory()?;
+
+    assert!(generated.contains("schemas-users/src/lib.rs"));
+    assert!(generated.contains("schemas-auth_users/src/lib.rs"));
+    assert!(generated.contains("schemas-auth_sessions/src/lib.rs"));
+
+    let sessions_toml = generated.get("schemas-auth_sessions/Cargo.toml").unwrap();
+    assert!(sessions_toml.contains("schemas-auth_users.workspace = true"));
+
+    let sink_lib = generated.get("schemas-sink/src/lib.rs").unwrap();
+    assert!(sink_lib.contains("pub use schemas_users::users;"));
+    assert!(sink_lib.contains("pub use schemas_auth_users::users as auth_users;"));
+    assert!(sink_lib.contains("pub use schemas_auth_users::User as AuthUser;"));
+
+    Ok(())
+}
+
+#[test]
+fn test_qualified_schema_naming() -> Result<(), Box<dyn std::error::Error>> {
+    let db = ParserDB::parse::<GenericDialect>(SCHEMA)?;
+    let temp_dir = tempfile::tempdir()?;
+
+    let synql: SynQL<ParserDB> = SynQL::new(&db, temp_dir.path())
+        .name("schemas")
+        .schema_naming(SchemaNaming::Qualified)
+        .into();
+    let generated = synql.generate_in_memory()?;
+
+    assert!(generated.contains("schemas-public_users/src/lib.rs"));
+    assert!(generated.contains("schemas-auth_users/src/lib.rs"));
+
+    Ok(())
+}
+
+#[test]
+fn test_crate_name_collision() -> Result<(), Box<dyn std::error::Error>> {
+    let db = ParserDB::parse::<GenericDialect>(SCHEMA)?;
+    let temp_dir = tempfile::tempdir()?;
+
+    let synql: SynQL<ParserDB> = SynQL::new(&db, temp_dir.path())
+        .name("schemas")
+        .schema_naming(SchemaNaming::Unqualified)
+        .into();
+    match synql.generate_in_memory() {
+        Err(synql::Error::CrateNameCollision { crate_name, first, second }) => {
+            assert_eq!(crate_name, "schemas-users");
+            let mut origins = [first, second];
+            origins.sort();
+            assert_eq!(origins, ["table `auth.users`", "table `public.users`"]);
+        }
+        Err(other) => panic!("Unexpected error: {other}"),
+        Ok(_) => panic!("The crate name collision should be reported"),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_dag_sinks_of_homonymous_tables() -> Result<(), Box<dyn std::error::Error>> {
+    let db = ParserDB::parse::<GenericDialect>(SCHEMA)?;
+    let temp_dir = tempfile::tempdir()?;
+
+    let synql: SynQL<ParserDB> =
+        SynQL::new(&db, temp_dir.path()).name("schemas").dag_sink_crate_prefix("sink_").into();
+    let generated = synql.generate_in_memory()?;
+
+    let public_sink_toml = generated.get("sink_users/Cargo.toml").unwrap();
+    assert!(public_sink_toml.contains("schemas-users.workspace = true"), "{public_sink_toml}");
+    assert!(!public_sink_toml.contains("schemas-auth_users"), "{public_sink_toml}");
+
+    let auth_sink_toml = generated.get("sink_auth_users/Cargo.toml").unwrap();
+    assert!(auth_sink_toml.contains("schemas-auth_users.workspace = true"), "{auth_sink_toml}");
+    assert!(auth_sink_toml.contains("schemas-auth_sessions.workspace = true"), "{auth_sink_toml}");
+    assert!(!auth_sink_toml.contains("schemas-users.workspace"), "{auth_sink_toml}");
+
+    Ok(())
+}
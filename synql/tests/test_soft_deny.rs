@@ -0,0 +1,59 @@
+//! Test module to verify that soft-denied tables are excluded while their
+//! dependents are kept, with the foreign keys referencing them elided.
+
+use sql_traits::prelude::*;
+use sqlparser::dialect::GenericDialect;
+use synql::prelude::*;
+
+const SCHEMA: &str = "
+    CREATE TABLE users (id SERIAL PRIMARY KEY, name TEXT NOT NULL);
+    CREATE TABLE audit_events (id SERIAL PRIMARY KEY, user_id INT NOT NULL REFERENCES users(id));
+    CREATE TABLE notes (
+        id SERIAL PRIMARY KEY,
+        author_id INT NOT NULL REFERENCES users(id),
+        audit_event_id INT NOT NULL REFERENCES audit_events(id)
+    );
+";
+
+#[test]
+fn test_soft_deny() -> Result<(), Box<dyn std::error::Error>> {
+    let db = ParserDB::parse::<GenericDialect>(SCHEMA)?;
+    let audit_events = db.table(None, "audit_events").unwrap();
+    let temp_dir = tempfile::tempdir()?;
+
+    let synql: SynQL<ParserDB> = SynQL::new(&db, temp_dir.path())
+        .name("soft-deny")
+        .generate_workspace_toml()
+        .soft_deny(audit_events)
+        .into();
+    let generated = synql.generate_in_memory()?;
+
+    assert!(generated.contains("soft-deny-users/src/lib.rs"));
+    assert!(generated.contains("soft-deny-notes/src/lib.rs"));
+    assert!(!generated.paths().any(|path| path.starts_with("soft-deny-audit_events")));
+    assert!(!generated.get("Cargo.toml").unwrap().contains("audit_events"));
+
+    let notes_toml = generated.get("soft-deny-notes/Cargo.toml").unwrap();
+    assert!(notes_toml.contains("soft-deny-users.workspace = true"));
+    assert!(!notes_toml.contains("audit_events"), "Unexpected dependency:\n{notes_toml}");
+
+    let notes_lib = generated.get("soft-deny-notes/src/lib.rs").unwrap();
+    assert!(notes_lib.contains("soft_deny_users"), "The other foreign keys must be kept");
+    assert!(!notes_lib.contains("soft_deny_audit_events"), "Unexpected reference:\n{notes_lib}");
+    assert!(notes_lib.contains("audit_event_id: i32"));
+    assert!(
+        notes_lib.contains(
+            "The foreign key referencing `id` in table `audit_events` was elided, as the table is not part of the workspace."
+        ),
+        "The elided foreign key must be documented:\n{notes_lib}"
+    );
+
+    // A hard deny instead excludes the dependent tables as well.
+    let synql: SynQL<ParserDB> =
+        SynQL::new(&db, temp_dir.path()).name("soft-deny").deny(audit_events).into();
+    let generated = synql.generate_in_memory()?;
+    assert!(generated.contains("soft-deny-users/src/lib.rs"));
+    assert!(!generated.contains("soft-deny-notes/src/lib.rs"));
+
+    Ok(())
+}
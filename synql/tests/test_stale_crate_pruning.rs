@@ -0,0 +1,78 @@
+//! Test module to verify that regenerating a workspace prunes the crates of
+//! tables which were dropped from the schema, leaving foreign files alone.
+
+use sql_traits::prelude::ParserDB;
+use sqlparser::dialect::GenericDialect;
+use synql::prelude::*;
+
+#[test]
+fn test_stale_crate_pruning() -> Result<(), Box<dyn std::error::Error>> {
+    let temp_dir = tempfile::tempdir()?;
+    let workspace_path = temp_dir.path().join("synql_pruning");
+
+    let db = ParserDB::parse::<GenericDialect>(
+        "
+        CREATE TABLE users (id SERIAL PRIMARY KEY, name TEXT NOT NULL);
+        CREATE TABLE comments (id SERIAL PRIMARY KEY, user_id INT REFERENCES users(id));
+",
+    )?;
+    let synql: SynQL<ParserDB> =
+        SynQL::new(&db, &workspace_path).name("synql-pruning").generate_workspace_toml().into();
+    synql.generate()?;
+
+    let comments_crate = workspace_path.join("synql-pruning-comments");
+    assert!(comments_crate.join("src").join("lib.rs").exists());
+    assert!(workspace_path.join(".synql-manifest").exists());
+
+    // Files which were not generated by SynQL must survive the pruning, even
+    // when they live inside a generated crate.
+    let foreign_file = workspace_path.join("README.md");
+    std::fs::write(&foreign_file, "Hand-written notes.")?;
+    let foreign_crate_file = workspace_path.join("synql-pruning-users").join("NOTES.md");
+    std::fs::write(&foreign_crate_file, "Hand-written notes.")?;
+
+    let db = ParserDB::parse::<GenericDialect>(
+        "CREATE TABLE users (id SERIAL PRIMARY KEY, name TEXT NOT NULL);",
+    )?;
+    let synql: SynQL<ParserDB> =
+        SynQL::new(&db, &workspace_path).name("synql-pruning").generate_workspace_toml().into();
+    let statistics = synql.generate()?.file_statistics();
+
+    assert_eq!(statistics.written(), 1, "Only the workspace Cargo.toml should change");
+    assert_eq!(statistics.unchanged(), 2);
+    assert_eq!(statistics.deleted(), 2);
+    assert!(!comments_crate.exists(), "The crate of the dropped table should be removed");
+    assert!(workspace_path.join("synql-pruning-users").join("src").join("lib.rs").exists());
+    assert!(foreign_file.exists());
+    assert!(foreign_crate_file.exists());
+
+    Ok(())
+}
+
+#[test]
+fn test_tampered_manifest() -> Result<(), Box<dyn std::error::Error>> {
+    let temp_dir = tempfile::tempdir()?;
+    let workspace_path = temp_dir.path().join("synql_tampered");
+    let outside_file = temp_dir.path().join("outside.txt");
+    std::fs::write(&outside_file, "Not generated by SynQL.")?;
+
+    let db = ParserDB::parse::<GenericDialect>(
+        "CREATE TABLE users (id SERIAL PRIMARY KEY, name TEXT NOT NULL);",
+    )?;
+    let synql: SynQL<ParserDB> = SynQL::new(&db, &workspace_path).name("synql-tampered").into();
+    synql.generate()?;
+
+    let manifest_path = workspace_path.join(".synql-manifest");
+    let manifest = std::fs::read_to_string(&manifest_path)?;
+    for tampered_path in ["../outside.txt", &outside_file.display().to_string()] {
+        std::fs::write(&manifest_path, format!("{manifest}{tampered_path}\n"))?;
+        let synql: SynQL<ParserDB> = SynQL::new(&db, &workspace_path).name("synql-tampered").into();
+        assert!(matches!(
+            synql.generate(),
+            Err(synql::Error::InvalidManifestPath(path)) if path.to_string_lossy() == tampered_path
+        ));
+        assert!(outside_file.exists(), "Files outside of the workspace must not be deleted");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,101 @@
+//! Test module to verify that the allow list generates only the selected
+//! tables and the tables they depend on.
+
+use sql_traits::prelude::ParserDB;
+use sqlparser::dialect::GenericDialect;
+use synql::prelude::*;
+
+const SCHEMA: &str = "
+    CREATE TABLE users (id SERIAL PRIMARY KEY, name TEXT NOT NULL);
+    CREATE TABLE posts (id SERIAL PRIMARY KEY, author_id INT NOT NULL REFERENCES users(id));
+    CREATE TABLE comments (id SERIAL PRIMARY KEY, post_id INT NOT NULL REFERENCES posts(id));
+    CREATE TABLE audit_logins (id SERIAL PRIMARY KEY, user_id INT NOT NULL REFERENCES users(id));
+    CREATE TABLE audit_logouts (id SERIAL PRIMARY KEY, user_id INT NOT NULL REFERENCES users(id));
+    CREATE TABLE billing.invoices (id SERIAL PRIMARY KEY, amount INT NOT NULL);
+    CREATE TABLE billing.payments (id SERIAL PRIMARY KEY, invoice_id INT NOT NULL REFERENCES billing.invoices(id));
+";
+
+/// Returns the names of the table crates generated with the provided builder
+/// customization.
+fn generated_crates<'db>(
+    db: &'db ParserDB,
+    path: &'db std::path::Path,
+    customize: impl FnOnce(SynQLBuilder<'db, ParserDB>) -> SynQLBuilder<'db, ParserDB>,
+) -> Result<Vec<String>, synql::Error> {
+    let synql: SynQL<ParserDB> = customize(SynQL::new(db, path).name("selection")).into();
+    let generated = synql.generate_in_memory()?;
+    let mut crates: Vec<String> = generated
+        .paths()
+        .filter(|path| path.ends_with("Cargo.toml"))
+        .filter_map(|path| path.parent()?.file_name()?.to_str())
+        .filter_map(|crate_name| crate_name.strip_prefix("selection-"))
+        .map(ToString::to_string)
+        .collect();
+    crates.sort();
+    Ok(crates)
+}
+
+#[test]
+fn test_allow_by_name_includes_dependencies() -> Result<(), Box<dyn std::error::Error>> {
+    let db = ParserDB::parse::<GenericDialect>(SCHEMA)?;
+    let temp_dir = tempfile::tempdir()?;
+
+    let crates = generated_crates(&db, temp_dir.path(), |builder| builder.allow("comments"))?;
+    assert_eq!(crates, vec!["comments", "posts", "users"]);
+
+    Ok(())
+}
+
+#[test]
+fn test_allow_by_pattern() -> Result<(), Box<dyn std::error::Error>> {
+    let db = ParserDB::parse::<GenericDialect>(SCHEMA)?;
+    let temp_dir = tempfile::tempdir()?;
+
+    let crates = generated_crates(&db, temp_dir.path(), |builder| builder.allow_glob("audit_*"))?;
+    assert_eq!(crates, vec!["audit_logins", "audit_logouts", "users"]);
+
+    let crates = generated_crates(&db, temp_dir.path(), |builder| {
+        builder.allow_regex("audit_log(ins|outs)").unwrap().allow("posts")
+    })?;
+    assert_eq!(crates, vec!["audit_logins", "audit_logouts", "posts", "users"]);
+
+    // The regex must match the whole table name.
+    let error =
+        generated_crates(&db, temp_dir.path(), |builder| builder.allow_regex("audit").unwrap())
+            .expect_err("The regex must not match table names partially");
+    assert!(matches!(error, synql::Error::UnmatchedTableSelector(_)));
+
+    Ok(())
+}
+
+#[test]
+fn test_allow_by_schema() -> Result<(), Box<dyn std::error::Error>> {
+    let db = ParserDB::parse::<GenericDialect>(SCHEMA)?;
+    let temp_dir = tempfile::tempdir()?;
+
+    let crates = generated_crates(&db, temp_dir.path(), |builder| builder.allow_schema("billing"))?;
+    assert_eq!(crates, vec!["billing_invoices", "billing_payments"]);
+
+    let crates =
+        generated_crates(&db, temp_dir.path(), |builder| builder.allow("billing.payments"))?;
+    assert_eq!(crates, vec!["billing_invoices", "billing_payments"]);
+
+    Ok(())
+}
+
+#[test]
+fn test_invalid_table_selectors() -> Result<(), Box<dyn std::error::Error>> {
+    let db = ParserDB::parse::<GenericDialect>(SCHEMA)?;
+    let temp_dir = tempfile::tempdir()?;
+
+    let Err(error) = SynQL::new(&db, temp_dir.path()).allow_regex("audit_(") else {
+        panic!("Invalid regular expressions must be rejected");
+    };
+    assert!(matches!(error, synql::Error::InvalidTableSelector { .. }));
+
+    let error = generated_crates(&db, temp_dir.path(), |builder| builder.allow("comment"))
+        .expect_err("Selectors matching no table must be reported");
+    assert_eq!(error.to_string(), "The table `comment` does not match any table of the database");
+
+    Ok(())
+}
@@ -0,0 +1,97 @@
+//! Test module to verify that the date and time columns are mapped to the
+//! selected temporal backend, alongside their current time defaults and
+//! checks.
+
+mod common;
+
+use syn::parse_quote;
+use synql::prelude::*;
+
+const SCHEMA: &str = r"
+CREATE TABLE events (
+    id SERIAL PRIMARY KEY,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    scheduled_at TIMESTAMP NOT NULL DEFAULT now(),
+    day DATE NOT NULL DEFAULT CURRENT_DATE,
+    hour TIME NOT NULL DEFAULT CURRENT_TIME,
+    CHECK (created_at <= NOW())
+);
+";
+
+/// Asserts that the `in_the_future` check of the `created_at` column of the
+/// provided library compares it with the provided current time.
+fn assert_in_the_future_check(events_lib: &str, current_time: &syn::Expr) {
+    assert!(
+        common::called_functions(events_lib)
+            .contains(&parse_quote!(::validation_errors::ValidationError::in_the_future)),
+        "{events_lib}"
+    );
+    assert!(
+        common::if_conditions(events_lib).iter().any(|condition| {
+            matches!(
+                condition,
+                syn::Expr::Binary(comparison)
+                    if matches!(comparison.op, syn::BinOp::Gt(_))
+                        && comparison.right.as_ref() == current_time
+            )
+        }),
+        "{events_lib}"
+    );
+}
+
+#[test]
+fn test_default_temporal_backend() -> Result<(), Box<dyn std::error::Error>> {
+    let generated = common::generate_in_memory(SCHEMA, "temporal", |builder| builder)?;
+
+    let events_lib = generated.get("temporal-events/src/lib.rs").unwrap();
+    assert_eq!(
+        common::field_type(events_lib, "Event", "created_at"),
+        parse_quote!(::rosetta_utc::TimestampUTC)
+    );
+    let expected_defaults: [(&str, syn::Expr); 4] = [
+        ("created_at", parse_quote!(::rosetta_utc::TimestampUTC::now())),
+        ("scheduled_at", parse_quote!(::chrono::Utc::now().naive_utc())),
+        ("day", parse_quote!(::chrono::Utc::now().date_naive())),
+        ("hour", parse_quote!(::chrono::Utc::now().time())),
+    ];
+    for (field_name, expected_default) in expected_defaults {
+        assert_eq!(common::field_default(events_lib, "Event", field_name), Some(expected_default));
+    }
+    assert_in_the_future_check(events_lib, &parse_quote!(::rosetta_utc::TimestampUTC::now()));
+    let events_toml = generated.get("temporal-events/Cargo.toml").unwrap();
+    assert!(events_toml.contains("rosetta-utc.workspace = true"), "{events_toml}");
+    assert!(events_toml.contains("chrono.workspace = true"), "{events_toml}");
+
+    Ok(())
+}
+
+#[test]
+fn test_time_temporal_backend() -> Result<(), Box<dyn std::error::Error>> {
+    let generated = common::generate_in_memory(SCHEMA, "temporal", |builder| {
+        builder.temporal_backend(TemporalBackend::Time)
+    })?;
+
+    let events_lib = generated.get("temporal-events/src/lib.rs").unwrap();
+    assert_eq!(
+        common::field_type(events_lib, "Event", "created_at"),
+        parse_quote!(::time::OffsetDateTime)
+    );
+    assert_eq!(
+        common::field_type(events_lib, "Event", "scheduled_at"),
+        parse_quote!(::time::PrimitiveDateTime)
+    );
+    let expected_defaults: [(&str, syn::Expr); 2] = [
+        ("created_at", parse_quote!(::time::OffsetDateTime::now_utc())),
+        ("day", parse_quote!(::time::OffsetDateTime::now_utc().date())),
+    ];
+    for (field_name, expected_default) in expected_defaults {
+        assert_eq!(common::field_default(events_lib, "Event", field_name), Some(expected_default));
+    }
+    assert_in_the_future_check(events_lib, &parse_quote!(::time::OffsetDateTime::now_utc()));
+    assert!(!events_lib.contains("chrono"), "{events_lib}");
+    let events_toml = generated.get("temporal-events/Cargo.toml").unwrap();
+    assert!(events_toml.contains("time.workspace = true"), "{events_toml}");
+    assert!(!events_toml.contains("chrono"), "{events_toml}");
+
+    Ok(())
+}
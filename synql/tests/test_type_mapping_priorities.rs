@@ -0,0 +1,132 @@
+//! Test module to verify that the postgres types provided by several crates
+//! are reported, and mapped according to the priorities of the crates and to
+//! the pinned types.
+
+mod common;
+
+use syn::parse_quote;
+use synql::prelude::*;
+
+const SCHEMA: &str = r"
+CREATE TABLE tasks (
+    id SERIAL PRIMARY KEY,
+    duration INTERVAL NOT NULL
+);
+";
+
+/// Returns an external crate providing the `interval` type, with the provided
+/// priority.
+fn duration_crate(priority: i16) -> ExternalCrate {
+    ExternalCrate::new("duration")
+        .unwrap()
+        .version("0.1.0")
+        .unwrap()
+        .priority(priority)
+        .types([ExternalType::new(
+            parse_quote!(::diesel::sql_types::Interval),
+            parse_quote!(::duration::Duration),
+        )
+        .postgres_type("interval")
+        .unwrap()
+        .supports_debug()
+        .supports_copy()
+        .into()])
+        .unwrap()
+        .into()
+}
+
+#[test]
+fn test_ambiguous_type_mapping() -> Result<(), Box<dyn std::error::Error>> {
+    assert!(matches!(
+        common::generate_in_memory(SCHEMA, "tasks", |builder| {
+            builder.external_crate(duration_crate(0))
+        }),
+        Err(synql::Error::AmbiguousPostgresType { postgres_type, crates })
+            if postgres_type == "interval" && crates == ["duration", "chrono"]
+    ));
+
+    let generated = common::generate_in_memory(SCHEMA, "tasks", |builder| {
+        builder.external_crate(duration_crate(0)).lenient()
+    })?;
+    assert!(generated.warnings().iter().any(|warning| {
+        matches!(
+            warning,
+            GenerationWarning::AmbiguousPostgresType { postgres_type, .. }
+                if postgres_type == "interval"
+        )
+    }));
+
+    Ok(())
+}
+
+/// Returns an external crate providing the `numeric` types matched by the
+/// provided pattern, with a higher priority than the default crates.
+fn decimal_crate(name: &str, pattern: &str) -> ExternalCrate {
+    ExternalCrate::new(name)
+        .unwrap()
+        .version("0.1.0")
+        .unwrap()
+        .priority(1)
+        .types([ExternalType::new(
+            parse_quote!(::diesel::sql_types::Numeric),
+            parse_quote!(::decimal::Decimal),
+        )
+        .postgres_type(pattern)
+        .unwrap()
+        .supports_debug()
+        .into()])
+        .unwrap()
+        .into()
+}
+
+#[test]
+fn test_ambiguous_type_pattern() {
+    // Neither pattern matches the other, but both match the type of the column.
+    let schema = "CREATE TABLE prices (id SERIAL PRIMARY KEY, amount NUMERIC(10, 2) NOT NULL);";
+
+    assert!(matches!(
+        common::generate_in_memory(schema, "prices", |builder| {
+            builder
+                .external_crate(decimal_crate("precise", "numeric(10, *)"))
+                .external_crate(decimal_crate("scaled", "numeric(*, 2)"))
+        }),
+        Err(synql::Error::AmbiguousPostgresType { postgres_type, crates })
+            if postgres_type == "numeric(10, 2)" && crates == ["precise", "scaled"]
+    ));
+}
+
+#[test]
+fn test_type_mapping_priorities() -> Result<(), Box<dyn std::error::Error>> {
+    // By default, chrono has precedence over the `PgInterval` of diesel.
+    let generated = common::generate_in_memory(SCHEMA, "tasks", |builder| builder)?;
+    let tasks_lib = generated.get("tasks-tasks/src/lib.rs").unwrap();
+    assert_eq!(common::field_type(tasks_lib, "Task", "duration"), parse_quote!(::chrono::Duration));
+
+    let generated = common::generate_in_memory(SCHEMA, "tasks", |builder| {
+        builder.external_crate(duration_crate(1))
+    })?;
+    let tasks_lib = generated.get("tasks-tasks/src/lib.rs").unwrap();
+    assert_eq!(
+        common::field_type(tasks_lib, "Task", "duration"),
+        parse_quote!(::duration::Duration)
+    );
+
+    let generated = common::generate_in_memory(SCHEMA, "tasks", |builder| {
+        builder.external_crate(duration_crate(0)).pin_postgres_type("interval", "diesel")
+    })?;
+    let tasks_lib = generated.get("tasks-tasks/src/lib.rs").unwrap();
+    assert_eq!(
+        common::field_type(tasks_lib, "Task", "duration"),
+        parse_quote!(::diesel::pg::data_types::PgInterval)
+    );
+
+    assert!(matches!(
+        common::generate_in_memory(SCHEMA, "tasks", |builder| {
+            builder.pin_postgres_type("interval", "serde_json")
+        }),
+        Err(synql::Error::InvalidPostgresTypePin { postgres_type, crate_name })
+            if postgres_type == "interval" && crate_name == "serde_json"
+    ));
+
+    Ok(())
+}
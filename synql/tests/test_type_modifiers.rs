@@ -0,0 +1,95 @@
+//! Test module to verify that the postgres types of the external types are
+//! matched as patterns over the parsed types, and that the matched modifiers
+//! are exposed to the code generation.
+
+mod common;
+
+use syn::parse_quote;
+use synql::prelude::*;
+
+#[test]
+fn test_postgres_type_modifiers() {
+    let point = PostgresType::parse("Geography(Point, 3857)");
+    assert_eq!(point.name(), "geography");
+    assert_eq!(point.modifiers(), ["point", "3857"]);
+    assert_eq!(point.srid(), Some(3857));
+    assert_eq!(PostgresType::parse("geometry(point)").srid(), None);
+    assert!(PostgresType::parse("geometry").modifiers().is_empty());
+
+    assert_eq!(PostgresType::parse("character varying(255)").max_length(), Some(255));
+    assert_eq!(PostgresType::parse("varchar").max_length(), None);
+    let numeric = PostgresType::parse("numeric(10, 2)");
+    assert_eq!((numeric.precision(), numeric.scale()), (Some(10), Some(2)));
+    assert_eq!(PostgresType::parse("decimal(12)").scale(), Some(0));
+    assert_eq!(PostgresType::parse("numeric").scale(), None);
+
+    assert_eq!(
+        PostgresType::parse("TIMESTAMP(3) WITH TIME ZONE").to_string(),
+        "timestamp(3) with time zone"
+    );
+    assert_eq!(PostgresType::parse("varchar(255)[]").to_string(), "varchar(255)[]");
+}
+
+#[test]
+fn test_parameterized_type_matching() {
+    let postgis = ExternalCrate::postgis_diesel();
+    let point: syn::Type = parse_quote!(::postgis_diesel::types::Point);
+    let line_string: syn::Type = parse_quote!(::postgis_diesel::types::LineString);
+    let container: syn::Type =
+        parse_quote!(postgis_diesel::types::GeometryContainer<postgis_diesel::types::Point>);
+    for (postgres_type, rust_type) in [
+        ("geography(Point, 4326)", &point),
+        ("geometry(point, 3857)", &point),
+        ("geometry(point)", &point),
+        ("geometry(LineString, 2056)", &line_string),
+        ("geometry(pointz, 4326)", &container),
+        ("geometry", &container),
+    ] {
+        let external_type = postgis.external_postgres_type(postgres_type).unwrap();
+        assert_eq!(external_type.rust_type(), rust_type, "`{postgres_type}` is mapped wrongly");
+    }
+    let point = postgis.external_postgres_type("geography(point, 3857)").unwrap();
+    assert_eq!(point.matched_modifiers("geography(point, 3857)"), Some(vec!["3857".to_owned()]));
+
+    let flag: ExternalType =
+        ExternalType::new(parse_quote!(::diesel::sql_types::Bool), parse_quote!(bool))
+            .postgres_type("bit(1)")
+            .unwrap()
+            .into();
+    assert!(flag.is_compatible_with("BIT(1)"));
+    assert!(!flag.is_compatible_with("bit(8)"));
+    assert!(!flag.is_compatible_with("bit"));
+    assert_eq!(flag.matched_modifiers("bit(1)"), Some(Vec::new()));
+
+    let builder =
+        || ExternalType::new(parse_quote!(::diesel::sql_types::Text), parse_quote!(String));
+    assert!(builder().postgres_type("varchar(*").is_err());
+    assert!(builder().postgres_type("varchar(2*)").is_err());
+    assert!(
+        builder().postgres_type("varchar(255)").unwrap().postgres_type("varchar( 255 )").is_err()
+    );
+}
+
+#[test]
+fn test_geometric_columns() -> Result<(), Box<dyn std::error::Error>> {
+    let schema = r"
+CREATE TABLE stations (
+    id SERIAL PRIMARY KEY,
+    location geometry(Point, 3857) NOT NULL,
+    track geography(LineString, 4326)
+);
+";
+    let generated = common::generate_in_memory(schema, "geo", |builder| builder)?;
+
+    let stations_lib = generated.get("geo-stations/src/lib.rs").unwrap();
+    assert_eq!(
+        common::field_type(stations_lib, "Station", "location"),
+        parse_quote!(::postgis_diesel::types::Point)
+    );
+    assert_eq!(
+        common::field_type(stations_lib, "Station", "track"),
+        parse_quote!(Option<::postgis_diesel::types::LineString>)
+    );
+
+    Ok(())
+}
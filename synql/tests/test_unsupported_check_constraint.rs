@@ -0,0 +1,43 @@
+//! Test module to verify that check constraints which cannot be translated
+//! into Rust code are reported as errors instead of aborting the generation.
+
+use sql_traits::prelude::ParserDB;
+use sqlparser::dialect::GenericDialect;
+use synql::prelude::*;
+
+#[test]
+fn test_unsupported_check_constraint() -> Result<(), Box<dyn std::error::Error>> {
+    let db = ParserDB::parse::<GenericDialect>(
+        "
+        CREATE TABLE users (
+            id SERIAL PRIMARY KEY,
+            name TEXT NOT NULL CHECK (name <> '' AND name LIKE 'user_%')
+        );
+",
+    )?;
+    let temp_dir = tempfile::tempdir()?;
+    let workspace_path = temp_dir.path().join("synql_unsupported_check");
+
+    let synql: SynQL<ParserDB> =
+        SynQL::new(&db, &workspace_path).name("synql-unsupported-check").into();
+
+    match synql.generate_in_memory() {
+        Err(synql::Error::UnsupportedCheckConstraint {
+            table_name,
+            constraint,
+            sub_expression,
+            reason,
+        }) => {
+            assert_eq!(table_name, "users");
+            assert!(constraint.contains("name <> ''"), "Unexpected constraint: {constraint}");
+            assert!(sub_expression.contains("LIKE"), "Unexpected sub-expression: {sub_expression}");
+            assert!(!sub_expression.contains("<>"), "Unexpected sub-expression: {sub_expression}");
+            assert_eq!(reason, CheckConstraintErrorKind::UnsupportedExpression);
+        }
+        Err(other) => panic!("Unexpected error: {other}"),
+        Ok(_) => panic!("The unsupported check constraint should be reported"),
+    }
+    assert!(!workspace_path.exists());
+
+    Ok(())
+}